@@ -4,6 +4,7 @@
 
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Result type alias for crawler operations.
@@ -28,6 +29,10 @@ pub enum AppError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// YAML serialization/deserialization failed
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     /// TOML parsing failed
     #[error("TOML parse error: {0}")]
     Toml(#[from] toml::de::Error),
@@ -40,6 +45,11 @@ pub enum AppError {
     #[error("URL parse error: {0}")]
     Url(#[from] url::ParseError),
 
+    /// Excel workbook generation failed
+    #[cfg(feature = "xlsx")]
+    #[error("Excel error: {0}")]
+    Xlsx(#[from] rust_xlsxwriter::XlsxError),
+
     /// CSS selector parsing failed
     #[error("Invalid selector '{selector}': {message}")]
     Selector { selector: String, message: String },
@@ -68,6 +78,14 @@ pub enum AppError {
     #[error("Upstream HTTP {status} for {url}")]
     UpstreamHttp { url: String, status: u16 },
 
+    /// Upstream returned 429 Too Many Requests
+    #[error("Upstream rate limited (HTTP 429) for {url}")]
+    UpstreamRateLimited {
+        url: String,
+        /// Wait requested by the `Retry-After` header, if any
+        retry_after: Option<std::time::Duration>,
+    },
+
     /// Upstream returned 304 Not Modified
     #[error("Upstream not modified for {url}")]
     UpstreamNotModified { url: String },
@@ -83,6 +101,23 @@ pub enum AppError {
         bytes: u64,
         max_bytes: u64,
     },
+
+    /// A board failed; `kind` classifies why
+    #[error("Board {board_id} failed ({kind}) at {url}: {message}")]
+    Board {
+        board_id: String,
+        url: String,
+        kind: BoardErrorKind,
+        message: String,
+    },
+
+    /// Upstream body suspiciously short (likely a bot-blocking placeholder)
+    #[error("Suspiciously short response for {url}: {bytes} < {min_bytes} bytes")]
+    UpstreamBodyTooShort {
+        url: String,
+        bytes: usize,
+        min_bytes: usize,
+    },
 }
 
 impl AppError {
@@ -117,6 +152,39 @@ impl AppError {
         }
     }
 
+    /// Wrap this error as a [`AppError::Board`] failure of `board_id`.
+    ///
+    /// Errors that are already board failures are returned unchanged.
+    pub fn into_board_error(self, board_id: impl Into<String>, url: impl Into<String>) -> Self {
+        if matches!(self, AppError::Board { .. }) {
+            return self;
+        }
+        Self::Board {
+            board_id: board_id.into(),
+            url: url.into(),
+            kind: self.board_error_kind(),
+            message: self.to_string(),
+        }
+    }
+
+    /// Classify this error as the cause of a board failure.
+    pub fn board_error_kind(&self) -> BoardErrorKind {
+        match self {
+            AppError::Board { kind, .. } => *kind,
+            AppError::Http(e) if e.is_timeout() => BoardErrorKind::Timeout,
+            AppError::Http(e) => match e.status() {
+                Some(status) => BoardErrorKind::Status(status.as_u16()),
+                None => BoardErrorKind::Network,
+            },
+            AppError::Io(_) => BoardErrorKind::Network,
+            AppError::UpstreamHttp { status, .. } => BoardErrorKind::Status(*status),
+            AppError::UpstreamRateLimited { .. } => BoardErrorKind::Status(429),
+            AppError::UpstreamNotModified { .. } => BoardErrorKind::Status(304),
+            AppError::Selector { .. } => BoardErrorKind::BadSelector,
+            _ => BoardErrorKind::Other,
+        }
+    }
+
     /// Check retriable error based on HTTP status code.
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -125,13 +193,60 @@ impl AppError {
                 // 5xx, 429 are retryable
                 (500..600).contains(status) || *status == 429
             }
+            AppError::UpstreamRateLimited { .. } => true,
+            AppError::Board { kind, .. } => kind.is_retryable(),
             _ => false,
         }
     }
 }
 
+/// Why a board failed, for matching and report categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardErrorKind {
+    /// Connection failed or was reset
+    Network,
+    /// The request timed out
+    Timeout,
+    /// The server answered with a non-success status
+    Status(u16),
+    /// A required selector does not parse
+    BadSelector,
+    /// The row selector matched nothing
+    EmptyRows,
+    /// Rows matched but none yielded a notice
+    NoNotices,
+    /// Any other failure (unexpected content, oversized or short body, ...)
+    Other,
+}
+
+impl BoardErrorKind {
+    /// Whether a later attempt may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BoardErrorKind::Network | BoardErrorKind::Timeout => true,
+            BoardErrorKind::Status(status) => (500..600).contains(status) || *status == 429,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for BoardErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardErrorKind::Network => f.write_str("network"),
+            BoardErrorKind::Timeout => f.write_str("timeout"),
+            BoardErrorKind::Status(status) => write!(f, "HTTP {status}"),
+            BoardErrorKind::BadSelector => f.write_str("bad selector"),
+            BoardErrorKind::EmptyRows => f.write_str("no rows matched"),
+            BoardErrorKind::NoNotices => f.write_str("no notices extracted"),
+            BoardErrorKind::Other => f.write_str("other"),
+        }
+    }
+}
+
 // Backward compatibility type aliases
-#[allow(dead_code)]
+/// The crate's error type, as re-exported at the crate root.
 pub type CrawlerError = AppError;
 #[allow(dead_code)]
 pub type MapperError = AppError;
@@ -1,11 +1,25 @@
 // src/lib.rs
 
 //! uRing Crawler Library
+//!
+//! Public functions report failures as [`CrawlerError`] through the crate's
+//! [`Result`], so callers can match on the variant:
+//!
+//! ```
+//! use crawler::models::Config;
+//! use crawler::{CrawlerError, Result};
+//!
+//! let loaded: Result<Config> = Config::load("no/such/config.toml");
+//! assert!(matches!(loaded, Err(CrawlerError::Io(_))));
+//! ```
 
 pub mod config;
 pub mod error;
 pub mod models;
+pub mod output;
 pub mod pipeline;
 pub mod services;
 pub mod storage;
 pub mod utils;
+
+pub use error::{AppError, CrawlerError, Result};
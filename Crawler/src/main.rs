@@ -8,26 +8,30 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use crawler::{
     error::{AppError, Result},
-    models::{Campus, Config, LocaleConfig, Seed},
-    pipeline::{crawl::run_crawler, map::run_mapper},
+    models::{Campus, Config, LocaleConfig, Seed, format_count},
+    output::apply_limit,
+    pipeline::{
+        crawl::run_crawler, diff::diff_dirs, map::run_mapper, self_test::run_self_test,
+        validate::validate_output,
+    },
+    services::NoticeCrawler,
     storage::{NoticeStorage, local::LocalStorage},
-    utils::{fs, http, log},
+    utils::{fs, http, log, url},
 };
 
 #[cfg(feature = "s3")]
 use crawler::storage::s3::S3Storage;
 
+/// CLI Arguments
 #[derive(Parser, Debug)]
 #[command(
     name = "uRing",
     version = "1.0.0",
     about = "Integrated University Notice Crawler"
 )]
-
-/// CLI Arguments
 struct Cli {
     #[arg(short, long, default_value = "data/config.toml")]
     config: String,
@@ -35,18 +39,85 @@ struct Cli {
     #[arg(long, default_value = "data/locale.toml")]
     locale: String,
 
+    /// Language variant of the locale file (e.g. `en` loads `locale.en.toml`
+    /// over the base locale)
+    #[arg(long)]
+    lang: Option<String>,
+
     #[arg(long, default_value = "data/seed.toml")]
     seed: String,
 
     #[arg(short, long, global = true)]
     quiet: bool,
 
+    /// Page backward through paginated boards until notices predate this
+    /// date (YYYY-MM-DD)
+    #[arg(long, global = true, value_name = "DATE")]
+    backfill_until: Option<chrono::NaiveDate>,
+
+    /// Write each board's raw HTML and response headers to this directory
+    #[arg(long, global = true, value_name = "DIR")]
+    debug_dir: Option<String>,
+
+    /// Archive fetched pages to this WARC file (requires the `warc` feature)
+    #[arg(long, global = true, value_name = "PATH")]
+    warc: Option<String>,
+
+    /// Cap the total number of notices presented and saved (global, unlike
+    /// per-board limits)
+    #[arg(long, global = true, value_name = "N")]
+    limit: Option<usize>,
+
+    /// Maximum concurrent requests to any one domain
+    #[arg(long, global = true, value_name = "N")]
+    concurrency_per_domain: Option<usize>,
+
+    /// Tokio worker threads (default: one per CPU)
+    #[arg(long, global = true, value_name = "N")]
+    worker_threads: Option<usize>,
+
+    /// Only crawl and save this campus (repeatable)
+    #[arg(long = "campus", global = true, value_name = "NAME")]
+    campuses: Vec<String>,
+
+    /// Exit non-zero when any board fails or no notices are produced
+    #[arg(long, global = true)]
+    strict: bool,
+
+    /// Store each notice's source row HTML in `raw_html` to debug selectors
+    #[arg(long, global = true)]
+    capture_row_html: bool,
+
     /// Select storage backend (local fs or aws s3)
     #[arg(long, global = true, default_value = "s3")]
     storage: StorageMode,
 
+    /// Print the effective configuration (after overrides) and exit
+    #[arg(long, value_name = "FORMAT", num_args = 0..=1, default_missing_value = "toml")]
+    print_config: Option<ConfigFormat>,
+
+    /// Print how many site map boards each domain hosts and exit
+    #[arg(long)]
+    boards_by_domain: bool,
+
+    /// Check the notice JSON files under DIR and exit (non-zero on problems)
+    #[arg(long, value_name = "DIR")]
+    validate_output: Option<PathBuf>,
+
     #[command(subcommand)]
-    command: Command,
+    command: Option<Command>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DiffFormat {
+    Text,
+    Json,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -76,6 +147,26 @@ enum Command {
     },
     /// Validate configuration and seed data
     Validate,
+    /// Check that a sample of boards still yields notices (exits non-zero
+    /// on failure; for CI/monitoring)
+    SelfTest {
+        /// Optional path to a specific site map file
+        #[arg(long)]
+        site_map: Option<String>,
+
+        /// Boards to check, by id or `dept/board` (default: one per domain)
+        #[arg(long = "board", value_name = "ID")]
+        boards: Vec<String>,
+    },
+    /// Report notices added and removed between two output directories
+    Diff {
+        old_dir: PathBuf,
+        new_dir: PathBuf,
+
+        /// Report format
+        #[arg(long, value_enum, default_value = "text")]
+        format: DiffFormat,
+    },
     /// Load notices from storage
     Load {
         /// Load from "new" snapshot or specific month (YYYY-MM format)
@@ -146,32 +237,131 @@ fn is_stale(path: &Path, days: u64) -> bool {
     age.as_secs() > 60 * 60 * 24 * days
 }
 
+/// Apply command-line overrides on top of the loaded configuration.
+fn apply_cli_overrides(config: &mut Config, cli: &Cli) {
+    if cli.quiet {
+        config.output.console_enabled = false;
+        config.logging.show_progress = false;
+    }
+    if let Some(limit) = cli.limit {
+        config.output.limit = Some(limit);
+    }
+    if let Some(date) = cli.backfill_until {
+        config.crawler.backfill_until = Some(date);
+    }
+    if let Some(dir) = &cli.debug_dir {
+        config.paths.debug_dir = Some(dir.clone());
+    }
+    if let Some(path) = &cli.warc {
+        config.paths.warc_file = Some(path.clone());
+    }
+    if cli.strict {
+        config.crawler.strict = true;
+    }
+    if cli.capture_row_html {
+        config.crawler.capture_row_html = true;
+    }
+    if let Some(limit) = cli.concurrency_per_domain {
+        config.crawler.max_concurrent_per_domain = Some(limit);
+    }
+    if let Some(threads) = cli.worker_threads {
+        config.crawler.worker_threads = Some(threads);
+    }
+    if !cli.campuses.is_empty() {
+        config.crawler.campuses = cli.campuses.clone();
+    }
+}
+
+/// Render the effective configuration in the requested format.
+fn render_config(config: &Config, format: ConfigFormat) -> Result<String> {
+    Ok(match format {
+        ConfigFormat::Toml => toml::to_string_pretty(config)?,
+        ConfigFormat::Json => serde_json::to_string_pretty(config)?,
+    })
+}
+
+/// Render the number of boards per domain, busiest first.
+fn render_boards_by_domain(campuses: &[Campus]) -> String {
+    let boards: Vec<_> = campuses
+        .iter()
+        .flat_map(|campus| campus.all_departments())
+        .flat_map(|dept_ref| dept_ref.dept.boards.iter().cloned())
+        .collect();
+    let mut groups: Vec<_> = url::group_boards_by_domain(&boards).into_iter().collect();
+    groups.sort_by(|(a_domain, a), (b_domain, b)| {
+        b.len().cmp(&a.len()).then_with(|| a_domain.cmp(b_domain))
+    });
+    groups
+        .iter()
+        .map(|(domain, boards)| format!("{:>5}  {domain}", boards.len()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Build the multi-threaded runtime with `worker_threads` workers (`None` =
+/// one per CPU).
+fn build_runtime(worker_threads: Option<usize>) -> Result<tokio::runtime::Runtime> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    if let Some(threads) = worker_threads {
+        if threads == 0 {
+            return Err(AppError::validation("crawler.worker_threads must be > 0"));
+        }
+        builder.worker_threads(threads);
+    }
+    Ok(builder.enable_all().build()?)
+}
+
 /// Main entry point
-#[tokio::main]
-async fn main() -> Result<()> {
+fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let mut config = Config::load_or_default(&cli.config);
-    let locale = LocaleConfig::load_or_default(&cli.locale);
-    let seed = Seed::load(&cli.seed)?;
+    let mut config = Config::load_or_default(&cli.config)?;
 
-    // Handle quiet flag BEFORE log::init
-    // Adjust config based on quiet flag so init receives correct level
-    if cli.quiet {
-        config.output.console_enabled = false;
-        config.logging.show_progress = false;
-        // Assuming log::init uses the config's level, we temporarily override or pass a silencer.
-        // Here, we assume config.logging.level controls verbosity.
-        // If LogLevel is an enum, set it to Error or Off.
-        // config.logging.level = LogLevel::Error;
+    // Apply overrides BEFORE log::init so init receives the correct level
+    apply_cli_overrides(&mut config, &cli);
+
+    build_runtime(config.crawler.worker_threads)?.block_on(run(cli, config))
+}
+
+async fn run(cli: Cli, config: Config) -> Result<()> {
+    if let Some(format) = cli.print_config {
+        println!("{}", render_config(&config, format)?);
+        return Ok(());
+    }
+
+    if cli.boards_by_domain {
+        let base = std::env::current_dir()?;
+        let campuses = Campus::load_all(config.departments_boards_path(&base))?;
+        println!("{}", render_boards_by_domain(&campuses));
+        return Ok(());
     }
 
+    if let Some(dir) = &cli.validate_output {
+        let problems = validate_output(dir, &config.paths)?;
+        if !problems.is_empty() {
+            return Err(AppError::validation(format!(
+                "{} problems in output files under {}",
+                problems.len(),
+                dir.display()
+            )));
+        }
+        return Ok(());
+    }
+
+    let Some(command) = cli.command else {
+        Cli::command().print_help()?;
+        return Ok(());
+    };
+
+    let locale = LocaleConfig::load_for_lang(&cli.locale, cli.lang.as_deref());
+    let seed = Seed::load(&cli.seed)?;
+
     // Initialize logging system with the adjusted configuration
     log::init(&locale, &config.logging.level);
 
     let config = Arc::new(config);
 
-    match cli.command {
+    match command {
         Command::Map {
             force,
             refresh_days,
@@ -180,9 +370,7 @@ async fn main() -> Result<()> {
             let site_map_path = config.departments_boards_path(&base);
 
             // Handle staleness or existence
-            let should_run = if force {
-                true
-            } else if !site_map_path.exists() {
+            let should_run = if force || !site_map_path.exists() {
                 true
             } else if let Some(days) = refresh_days {
                 if is_stale(&site_map_path, days) {
@@ -236,6 +424,19 @@ async fn main() -> Result<()> {
             process_crawl_with_storage(&cli.storage, Arc::clone(&config), &locale, &campuses)
                 .await?;
         }
+        Command::SelfTest { site_map, boards } => {
+            let base = std::env::current_dir()?;
+            let site_map_path = site_map
+                .map(PathBuf::from)
+                .unwrap_or_else(|| config.departments_boards_path(&base));
+            let campuses = Campus::load_all(&site_map_path)?;
+
+            let client = http::create_async_client(&config.crawler)?;
+            let crawler = NoticeCrawler::new(Arc::clone(&config), client)?;
+            run_self_test(&crawler, &campuses, &boards)
+                .await
+                .into_result()?;
+        }
         Command::Validate => {
             // Validation logic remains the same...
             log::header(&locale.messages.validate_starting);
@@ -261,14 +462,32 @@ async fn main() -> Result<()> {
             }
             log::success(&locale.messages.validate_seed_success);
         }
+        Command::Diff {
+            old_dir,
+            new_dir,
+            format,
+        } => {
+            let diff = diff_dirs(&old_dir, &new_dir, &config.paths)?;
+            match format {
+                DiffFormat::Text => println!("{}", diff.render_text()),
+                DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+            }
+        }
         Command::Load { from } => match from {
             LoadFrom::New => {
                 // Load also needs to respect the storage option
                 match cli.storage {
+                    #[cfg(feature = "s3")]
                     StorageMode::S3 => {
                         let storage = S3Storage::from_env().await?;
                         load_and_print(&storage, &locale, &config).await?;
                     }
+                    #[cfg(not(feature = "s3"))]
+                    StorageMode::S3 => {
+                        return Err(AppError::config(
+                            "Built without 's3' feature. Rebuild with: cargo run -F 'cli,s3' ...",
+                        ));
+                    }
                     StorageMode::Local => {
                         // Assuming LocalStorage has a default constructor or similar
                         let storage = LocalStorage::new(PathBuf::from("data/storage"));
@@ -319,17 +538,24 @@ async fn main() -> Result<()> {
             // 2. Bundle Phase (Executed consistently for both paths)
             // Storage selection logic is handled inside the helper
             match cli.storage {
+                #[cfg(feature = "s3")]
                 StorageMode::S3 => {
                     let storage = S3Storage::from_env().await?;
                     storage
-                        .write_config_bundle(config.as_ref(), &seed, &locale, site_map)
+                        .write_config_bundle(config.as_ref(), &seed, &locale, &campuses)
                         .await?;
                     run_crawler(Arc::clone(&config), &locale, &storage, &campuses, &client).await?;
                 }
+                #[cfg(not(feature = "s3"))]
+                StorageMode::S3 => {
+                    return Err(AppError::config(
+                        "Built without 's3' feature. Rebuild with: cargo run -F 'cli,s3' ...",
+                    ));
+                }
                 StorageMode::Local => {
                     let storage = LocalStorage::new(PathBuf::from("data/storage"));
                     storage
-                        .write_config_bundle(config.as_ref(), &seed, &locale, site_map)
+                        .write_config_bundle(config.as_ref(), &seed, &locale, &campuses)
                         .await?;
                     run_crawler(Arc::clone(&config), &locale, &storage, &campuses, &client).await?;
                 }
@@ -359,9 +585,9 @@ async fn process_crawl_with_storage(
             }
             #[cfg(not(feature = "s3"))]
             {
-                return Err(AppError::config(
+                Err(AppError::config(
                     "Built without 's3' feature. Rebuild with: cargo run -F 'cli,s3' ...",
-                ));
+                ))
             }
         }
         StorageMode::Local => {
@@ -379,14 +605,10 @@ async fn load_and_print<S: NoticeStorage>(
     config: &Config,
 ) -> Result<()> {
     log::info(&locale.messages.load_new);
-    let notices = storage.load_snapshot().await?;
+    let mut notices = storage.load_snapshot().await?;
+    apply_limit(&mut notices, config.output.limit);
 
-    log::success(
-        &locale
-            .messages
-            .load_complete
-            .replace("{count}", &notices.len().to_string()),
-    );
+    log::success(&format_count(&locale.messages.load_complete, notices.len()));
 
     if config.output.console_enabled {
         for item in notices {
@@ -424,4 +646,43 @@ mod tests {
         assert!("2025-1".parse::<LoadFrom>().is_err());
         assert!("2025-012".parse::<LoadFrom>().is_err());
     }
+
+    #[test]
+    fn limit_flag_overrides_config() {
+        let cli = Cli::parse_from(["uRing", "crawl", "--limit", "3"]);
+        let mut config = Config::default();
+        apply_cli_overrides(&mut config, &cli);
+        assert_eq!(config.output.limit, Some(3));
+    }
+
+    #[test]
+    fn worker_threads_flag_sizes_runtime() {
+        let cli = Cli::parse_from(["uRing", "crawl", "--worker-threads", "2"]);
+        let mut config = Config::default();
+        apply_cli_overrides(&mut config, &cli);
+        assert_eq!(config.crawler.worker_threads, Some(2));
+
+        let runtime = build_runtime(config.crawler.worker_threads).unwrap();
+        assert_eq!(runtime.metrics().num_workers(), 2);
+        assert!(build_runtime(Some(0)).is_err());
+    }
+
+    #[test]
+    fn print_config_reflects_cli_override() {
+        let cli = Cli::parse_from(["uRing", "--quiet", "--print-config", "json"]);
+        assert!(matches!(cli.print_config, Some(ConfigFormat::Json)));
+
+        let mut config = Config::default();
+        config.output.console_enabled = true;
+        apply_cli_overrides(&mut config, &cli);
+
+        let rendered = render_config(&config, ConfigFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(value["output"]["console_enabled"], false);
+        assert_eq!(value["logging"]["show_progress"], false);
+
+        let toml = render_config(&config, ConfigFormat::Toml).unwrap();
+        let reparsed: Config = toml::from_str(&toml).unwrap();
+        assert!(!reparsed.output.console_enabled);
+    }
 }
@@ -2,13 +2,17 @@
 
 //! Campus, College, Department, and Board data structures.
 
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
-use crate::models::CmsSelectors;
+use crate::models::{CmsSelectors, JsonPaths};
+use crate::utils::log;
+use crate::utils::url::canonicalize_url;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CampusMeta {
@@ -26,11 +30,15 @@ impl From<&Campus> for CampusMeta {
 }
 
 /// A university campus containing colleges and/or departments.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Campus {
     /// Campus name (e.g., "신촌캠퍼스", "미래캠퍼스")
     pub campus: String,
 
+    /// `false` keeps the campus in the site map but out of crawls
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+
     /// Colleges within this campus
     #[serde(default)]
     pub colleges: Vec<College>,
@@ -40,11 +48,62 @@ pub struct Campus {
     pub departments: Vec<Department>,
 }
 
+impl Default for Campus {
+    fn default() -> Self {
+        Self {
+            campus: String::new(),
+            enabled: true,
+            colleges: Vec::new(),
+            departments: Vec::new(),
+        }
+    }
+}
+
 impl Campus {
-    /// Load campus configurations from a JSON file.
+    /// Load campus configurations from a site map file.
+    ///
+    /// The format is chosen by extension: `.yaml`/`.yml` for YAML, `.toml` for
+    /// TOML (campuses as a `[[campuses]]` array of tables), anything else is
+    /// parsed as JSON. Board URLs are canonicalized (see [`canonicalize_url`]).
     pub fn load_all(path: impl AsRef<Path>) -> Result<Vec<Self>> {
+        let mut campuses = Self::parse_file(path.as_ref())?;
+        for campus in &mut campuses {
+            campus.canonicalize_board_urls();
+        }
+        Ok(campuses)
+    }
+
+    fn parse_file(path: &Path) -> Result<Vec<Self>> {
         let content = fs::read_to_string(path)?;
-        Ok(serde_json::from_str(&content)?)
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase);
+
+        match extension.as_deref() {
+            Some("yaml" | "yml") => Ok(serde_yaml::from_str(&content)?),
+            Some("toml") => Ok(toml::from_str::<TomlSiteMap>(&content)?.campuses),
+            _ => Ok(serde_json::from_str(&content)?),
+        }
+    }
+
+    /// Rewrite board URLs into canonical form, logging each one changed.
+    fn canonicalize_board_urls(&mut self) {
+        let departments = self
+            .colleges
+            .iter_mut()
+            .flat_map(|college| &mut college.departments)
+            .chain(&mut self.departments);
+        for board in departments.flat_map(|dept| &mut dept.boards) {
+            let canonical = canonicalize_url(&board.url);
+            if canonical != board.url {
+                log::warn(&format!(
+                    "Board {}: rewrote URL {} to {canonical}",
+                    board.id, board.url
+                ));
+                board.url = canonical;
+            }
+        }
     }
 
     /// Get all departments with their hierarchical context.
@@ -74,6 +133,12 @@ impl Campus {
         result
     }
 
+    /// Whether this campus is crawled, given the `crawler.campuses` filter
+    /// (empty = every enabled campus).
+    pub fn is_selected(&self, filter: &[String]) -> bool {
+        self.enabled && (filter.is_empty() || filter.contains(&self.campus))
+    }
+
     /// Count total departments in this campus.
     pub fn department_count(&self) -> usize {
         self.colleges
@@ -94,6 +159,12 @@ impl Campus {
     }
 }
 
+/// TOML documents must be tables, so campuses live under a `campuses` key.
+#[derive(Deserialize)]
+struct TomlSiteMap {
+    campuses: Vec<Campus>,
+}
+
 /// Reference to a department with its hierarchical context.
 #[derive(Debug, Clone, Copy)]
 pub struct DepartmentRef<'a> {
@@ -103,14 +174,14 @@ pub struct DepartmentRef<'a> {
 }
 
 /// A college containing multiple departments.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct College {
     pub name: String,
     pub departments: Vec<Department>,
 }
 
 /// A university department with its notice boards.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Department {
     /// Unique identifier for the department
     pub id: String,
@@ -127,7 +198,7 @@ pub struct Department {
 }
 
 /// A notice board within a department.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Board {
     /// Unique identifier for the board
     pub id: String,
@@ -141,6 +212,173 @@ pub struct Board {
     /// CSS selectors for scraping
     #[serde(flatten)]
     pub selectors: CmsSelectors,
+
+    /// Regex with a capture group for links hidden in JS handlers
+    /// (e.g. `goView\('(\d+)'\)` on `onclick="goView('12345')"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_regex: Option<String>,
+
+    /// Link template filled from `link_regex` captures (e.g. `view.do?articleNo={1}`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_template: Option<String>,
+
+    /// Minimum seconds between successful fetches; the board is skipped in
+    /// cycles that start sooner (tracked in the state file)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_interval_secs: Option<u64>,
+
+    /// Query parameter selecting the list page (e.g. `pageIndex`), used to
+    /// page backward when backfilling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_param: Option<String>,
+
+    /// Crawl order weight; higher-priority boards are fetched first
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub priority: i32,
+
+    /// Leading rows matched by each `row_selector` to drop, e.g. a header
+    /// row in a table without `<thead>`
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub skip_rows: usize,
+
+    /// Drop repeated links within one page (e.g. a notice both pinned and
+    /// in the normal list), keeping the first
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub dedup_within_board: bool,
+
+    /// HTTP basic auth credentials sent with this board's requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub basic_auth: Option<BasicAuth>,
+
+    /// Request method for the list page (some CMS listings are POST-only)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub method: HttpMethod,
+
+    /// Form fields sent URL-encoded with a POST list request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form_body: Option<BTreeMap<String, String>>,
+
+    /// Overrides `crawler.danger_accept_invalid_certs` for this board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accept_invalid_certs: Option<bool>,
+
+    /// `false` stops following redirects for this board's requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+
+    /// Link attributes tried in order when `attr_name` is missing or empty
+    /// on the link element (e.g. `["data-url", "data-href"]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attr_fallbacks: Vec<String>,
+
+    /// Extra patterns removed from this board's titles, appended to
+    /// `cleaning.title_remove_patterns`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub title_remove_patterns: Vec<String>,
+
+    /// Extra patterns removed from this board's dates, appended to
+    /// `cleaning.date_remove_patterns`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub date_remove_patterns: Vec<String>,
+
+    /// Use this board's patterns instead of the global ones rather than
+    /// appending to them
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub replace_cleaning_patterns: bool,
+
+    /// Fewest notices a healthy crawl of this board yields; fewer are
+    /// reported as `below_expected_count` (selector drift)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_min_notices: Option<usize>,
+
+    /// How the list page is parsed: HTML with the CSS selectors, or a JSON
+    /// API response with `json_paths`
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub format: BoardFormat,
+
+    /// Field mappings for `format = "json"` boards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_paths: Option<JsonPaths>,
+}
+
+/// HTTP method for fetching a board's list page.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    #[default]
+    Get,
+    Post,
+}
+
+/// Format of a board's list response.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardFormat {
+    /// An HTML page scraped with the board's CSS selectors
+    #[default]
+    Html,
+    /// A JSON API response read with the board's `json_paths`
+    Json,
+}
+
+/// HTTP basic auth credentials.
+///
+/// `Debug` redacts the password so boards can be logged safely.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: String,
+}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            name: String::new(),
+            url: String::new(),
+            selectors: CmsSelectors::default(),
+            link_regex: None,
+            link_template: None,
+            min_interval_secs: None,
+            page_param: None,
+            priority: 0,
+            skip_rows: 0,
+            dedup_within_board: true,
+            basic_auth: None,
+            method: HttpMethod::Get,
+            form_body: None,
+            accept_invalid_certs: None,
+            follow_redirects: None,
+            attr_fallbacks: Vec::new(),
+            title_remove_patterns: Vec::new(),
+            date_remove_patterns: Vec::new(),
+            replace_cleaning_patterns: false,
+            expected_min_notices: None,
+            format: BoardFormat::Html,
+            json_paths: None,
+        }
+    }
+}
+
+fn is_zero<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
 }
 
 #[cfg(test)]
@@ -150,6 +388,7 @@ mod tests {
     fn create_test_campus() -> Campus {
         Campus {
             campus: "TestCampus".to_string(),
+            enabled: true,
             colleges: vec![College {
                 name: "TestCollege".to_string(),
                 departments: vec![Department {
@@ -176,4 +415,113 @@ mod tests {
         let campus = create_test_campus();
         assert_eq!(campus.department_count(), 1);
     }
+
+    #[test]
+    fn test_load_all_yaml_matches_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("siteMap.json");
+        let yaml_path = dir.path().join("siteMap.yaml");
+
+        std::fs::write(
+            &json_path,
+            r#"[{"campus": "신촌캠퍼스", "colleges": [{"name": "공과대학", "departments": [
+                {"id": "yonsei_cs", "name": "컴퓨터과학과", "url": "https://cs.yonsei.ac.kr",
+                 "boards": [{"id": "notice", "name": "공지사항", "url": "https://cs.yonsei.ac.kr/notice",
+                             "row_selector": "tr", "title_selector": "a", "date_selector": "td.date"}]}
+            ]}]}]"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &yaml_path,
+            r#"
+- campus: 신촌캠퍼스
+  colleges:
+    - name: 공과대학
+      departments:
+        - id: yonsei_cs
+          name: 컴퓨터과학과
+          url: https://cs.yonsei.ac.kr
+          boards:
+            - id: notice
+              name: 공지사항
+              url: https://cs.yonsei.ac.kr/notice
+              row_selector: tr
+              title_selector: a
+              date_selector: td.date
+"#,
+        )
+        .unwrap();
+
+        let from_json = Campus::load_all(&json_path).unwrap();
+        let from_yaml = Campus::load_all(&yaml_path).unwrap();
+        assert_eq!(from_json, from_yaml);
+        assert_eq!(from_yaml[0].board_count(), 1);
+    }
+
+    #[test]
+    fn test_load_all_toml_matches_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("siteMap.json");
+        let toml_path = dir.path().join("siteMap.toml");
+
+        std::fs::write(
+            &json_path,
+            r#"[{"campus": "미래캠퍼스", "departments": [
+                {"id": "yonsei_wj", "name": "원주학과", "url": "https://wj.yonsei.ac.kr",
+                 "boards": [{"id": "academic", "name": "학사공지", "url": "https://wj.yonsei.ac.kr/a",
+                             "row_selector": "tr", "title_selector": "a", "date_selector": "td"}]}
+            ]}]"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &toml_path,
+            r#"
+[[campuses]]
+campus = "미래캠퍼스"
+
+[[campuses.departments]]
+id = "yonsei_wj"
+name = "원주학과"
+url = "https://wj.yonsei.ac.kr"
+
+[[campuses.departments.boards]]
+id = "academic"
+name = "학사공지"
+url = "https://wj.yonsei.ac.kr/a"
+row_selector = "tr"
+title_selector = "a"
+date_selector = "td"
+"#,
+        )
+        .unwrap();
+
+        let from_json = Campus::load_all(&json_path).unwrap();
+        let from_toml = Campus::load_all(&toml_path).unwrap();
+        assert_eq!(from_json, from_toml);
+        assert_eq!(from_toml[0].department_count(), 1);
+    }
+
+    #[test]
+    fn test_load_json_board_without_css_selectors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("siteMap.json");
+        std::fs::write(
+            &path,
+            r#"[{"campus": "신촌캠퍼스", "departments": [
+                {"id": "yonsei_cs", "name": "컴퓨터과학과", "url": "https://cs.yonsei.ac.kr",
+                 "boards": [{"id": "api", "name": "공지 API", "url": "https://cs.yonsei.ac.kr/api/list",
+                             "format": "json",
+                             "json_paths": {"items_path": "$.data.list", "title_path": "subject",
+                                            "date_path": "regDate", "link_path": "url"}}]}
+            ]}]"#,
+        )
+        .unwrap();
+
+        let campuses = Campus::load_all(&path).unwrap();
+        let board = &campuses[0].departments[0].boards[0];
+        assert_eq!(board.format, BoardFormat::Json);
+        assert_eq!(board.json_paths.as_ref().unwrap().items_path, "$.data.list");
+        assert!(board.selectors.row_selector.is_empty());
+        assert!(board.selectors.title_selector.is_empty());
+    }
 }
@@ -2,16 +2,25 @@
 
 //! Application configuration structures.
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeDelta};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
 
 use crate::error::{AppError, Result};
+use crate::models::Board;
+use crate::utils::date::{parse_date, resolve_relative_date};
 use crate::utils::log;
+use crate::utils::url::{get_domain, percent_encode_non_ascii, strip_query_params};
 
 /// Root application configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
     /// HTTP and crawling behavior settings
     #[serde(default)]
@@ -36,24 +45,43 @@ pub struct Config {
     /// Logging settings
     #[serde(default)]
     pub logging: LoggingConfig,
+
+    /// Notice filtering settings
+    #[serde(default)]
+    pub filter: FilterConfig,
+
+    /// Keyword rules assigning `Notice::category` from the title, first
+    /// match wins
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub category_rules: Vec<CategoryRule>,
 }
 
 impl Config {
     /// Load configuration from a TOML file.
+    ///
+    /// `${VAR}` in any string value is replaced by the environment variable
+    /// `VAR`, so secrets need not be stored in the file.
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
         let content = fs::read_to_string(path)?;
-        Ok(toml::from_str(&content)?)
-    }
-
-    /// Load configuration or return default if loading fails.
-    pub fn load_or_default(path: impl AsRef<Path>) -> Self {
-        Self::load(&path).unwrap_or_else(|e| {
-            log::warn(&format!(
-                "Config load failed from {:?}: {e}. Using defaults.",
-                path.as_ref()
-            ));
-            Self::default()
-        })
+        let mut value: toml::Value = toml::from_str(&content)?;
+        expand_env_in_value(&mut value, "", &|name| std::env::var(name).ok())?;
+        Ok(value.try_into()?)
+    }
+
+    /// Load configuration or return default if the file cannot be read or
+    /// parsed. A `${VAR}` naming an unset variable is still an error.
+    pub fn load_or_default(path: impl AsRef<Path>) -> Result<Self> {
+        match Self::load(&path) {
+            Err(e @ AppError::Config(_)) => Err(e),
+            Err(e) => {
+                log::warn(&format!(
+                    "Config load failed from {:?}: {e}. Using defaults.",
+                    path.as_ref()
+                ));
+                Ok(Self::default())
+            }
+            config => config,
+        }
     }
 
     /// Validate configuration values for basic sanity.
@@ -64,6 +92,11 @@ impl Config {
         if self.crawler.timeout_secs == 0 {
             return Err(AppError::validation("crawler.timeout_secs must be > 0"));
         }
+        if self.crawler.connect_timeout_secs == 0 {
+            return Err(AppError::validation(
+                "crawler.connect_timeout_secs must be > 0",
+            ));
+        }
         if self.crawler.sitemap_timeout_secs == 0 {
             return Err(AppError::validation(
                 "crawler.sitemap_timeout_secs must be > 0",
@@ -72,6 +105,53 @@ impl Config {
         if self.crawler.max_concurrent == 0 {
             return Err(AppError::validation("crawler.max_concurrent must be > 0"));
         }
+        if self.crawler.max_concurrent_discovery == 0 {
+            return Err(AppError::validation(
+                "crawler.max_concurrent_discovery must be > 0",
+            ));
+        }
+        if self.crawler.max_body_bytes == Some(0) {
+            return Err(AppError::validation("crawler.max_body_bytes must be > 0"));
+        }
+        if self.crawler.max_run_secs == Some(0) {
+            return Err(AppError::validation("crawler.max_run_secs must be > 0"));
+        }
+        if self.crawler.worker_threads == Some(0) {
+            return Err(AppError::validation("crawler.worker_threads must be > 0"));
+        }
+        if self.filter.suppress_duplicate_titles_days == Some(0) {
+            return Err(AppError::validation(
+                "filter.suppress_duplicate_titles_days must be > 0",
+            ));
+        }
+        if self.crawler.ramp_up_secs == Some(0) {
+            return Err(AppError::validation("crawler.ramp_up_secs must be > 0"));
+        }
+        if self.crawler.max_concurrent_per_domain == Some(0) {
+            return Err(AppError::validation(
+                "crawler.max_concurrent_per_domain must be > 0",
+            ));
+        }
+        if self.cleaning.min_date_year <= 0 {
+            return Err(AppError::validation("cleaning.min_date_year must be > 0"));
+        }
+        if self.cleaning.max_future_days < 0 {
+            return Err(AppError::validation(
+                "cleaning.max_future_days must be >= 0",
+            ));
+        }
+        if let Some(version) = &self.crawler.min_tls_version
+            && !matches!(version.as_str(), "1.0" | "1.1" | "1.2" | "1.3")
+        {
+            return Err(AppError::validation(format!(
+                "crawler.min_tls_version '{version}' must be one of 1.0, 1.1, 1.2, 1.3"
+            )));
+        }
+        if self.output.opml_path.is_some() && self.output.opml_feed_url.is_none() {
+            return Err(AppError::validation(
+                "output.opml_path requires output.opml_feed_url",
+            ));
+        }
         if self.paths.output_dir.trim().is_empty() {
             return Err(AppError::validation("paths.output_dir is empty"));
         }
@@ -91,6 +171,27 @@ impl Config {
                 "discovery.max_board_name_length must be > 0",
             ));
         }
+        let regex_lists = [
+            ("blacklist_regex", &self.discovery.blacklist_regex),
+            ("whitelist_patterns", &self.discovery.whitelist_patterns),
+        ];
+        for (name, patterns) in regex_lists {
+            for pattern in patterns {
+                if let Err(err) = Regex::new(pattern) {
+                    return Err(AppError::validation(format!(
+                        "discovery.{name} '{pattern}' is invalid: {err}"
+                    )));
+                }
+            }
+        }
+        for rule in &self.category_rules {
+            if rule.category.trim().is_empty() || rule.keywords.is_empty() {
+                return Err(AppError::validation(format!(
+                    "category_rules entry '{}' needs a category and at least one keyword",
+                    rule.category
+                )));
+            }
+        }
         Ok(())
     }
 
@@ -121,18 +222,114 @@ impl Config {
     pub fn manual_review_path(&self, base: &Path) -> PathBuf {
         self.output_dir(base).join(&self.paths.manual_review_file)
     }
+
+    /// Get the full path to the crawl state file.
+    pub fn state_path(&self, base: &Path) -> PathBuf {
+        self.output_dir(base).join(&self.paths.state_file)
+    }
+
+    /// Get the full path to the run manifest, if enabled.
+    pub fn manifest_path(&self, base: &Path) -> Option<PathBuf> {
+        self.paths
+            .manifest_file
+            .as_ref()
+            .map(|file| self.output_dir(base).join(file))
+    }
+
+    /// Get the full path to the failure log, if enabled.
+    pub fn failure_log_path(&self, base: &Path) -> Option<PathBuf> {
+        self.paths
+            .failure_log_file
+            .as_ref()
+            .map(|file| self.output_dir(base).join(file))
+    }
+
+    /// Start building a configuration in code, from the defaults.
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            crawler: CrawlerConfig::default(),
-            paths: PathsConfig::default(),
-            discovery: DiscoveryConfig::default(),
-            cleaning: CleaningConfig::default(),
-            output: OutputConfig::default(),
-            logging: LoggingConfig::default(),
-        }
+/// Fluent construction of a validated [`Config`] without a TOML file.
+///
+/// Unset fields keep their defaults; anything not covered by a setter can be
+/// changed on the built `Config`.
+///
+/// # Examples
+/// ```
+/// use crawler::models::Config;
+///
+/// let config = Config::builder()
+///     .user_agent("my-bot/1.0")
+///     .timeout_secs(10)
+///     .json_output("notices.json")
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.crawler.max_concurrent, 5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.crawler.user_agent = user_agent.into();
+        self
+    }
+
+    /// Overall request timeout.
+    pub fn timeout_secs(mut self, secs: u64) -> Self {
+        self.config.crawler.timeout_secs = secs;
+        self
+    }
+
+    pub fn request_delay_ms(mut self, ms: u64) -> Self {
+        self.config.crawler.request_delay_ms = ms;
+        self
+    }
+
+    pub fn max_concurrent(mut self, max: usize) -> Self {
+        self.config.crawler.max_concurrent = max;
+        self
+    }
+
+    pub fn output_dir(mut self, dir: impl Into<String>) -> Self {
+        self.config.paths.output_dir = dir.into();
+        self
+    }
+
+    /// Enable JSON output to `file`, relative to the output directory.
+    pub fn json_output(mut self, file: impl Into<String>) -> Self {
+        self.config.output.json_enabled = true;
+        self.config.output.json_file = file.into();
+        self
+    }
+
+    pub fn console_output(mut self, enabled: bool) -> Self {
+        self.config.output.console_enabled = enabled;
+        self
+    }
+
+    pub fn group_by(mut self, group_by: GroupBy) -> Self {
+        self.config.output.group_by = group_by;
+        self
+    }
+
+    pub fn sort_by(mut self, sort_by: SortBy) -> Self {
+        self.config.output.sort_by = sort_by;
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.config.output.limit = Some(limit);
+        self
+    }
+
+    /// Validate and return the configuration.
+    pub fn build(self) -> Result<Config> {
+        self.config.validate()?;
+        Ok(self.config)
     }
 }
 
@@ -143,6 +340,11 @@ pub struct CrawlerConfig {
     #[serde(default = "defaults::user_agent")]
     pub user_agent: String,
 
+    /// User-Agent per domain (as returned by `get_domain`), for sites that
+    /// block the default one
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub user_agent_overrides: HashMap<String, String>,
+
     /// Request timeout in seconds
     #[serde(default = "defaults::timeout")]
     pub timeout_secs: u64,
@@ -158,20 +360,172 @@ pub struct CrawlerConfig {
     /// Maximum concurrent requests
     #[serde(default = "defaults::max_concurrent")]
     pub max_concurrent: usize,
+
+    /// Maximum concurrent requests during discovery (department sitemaps and
+    /// board landing pages); `max_concurrent` applies to notice fetching
+    #[serde(default = "defaults::max_concurrent_discovery")]
+    pub max_concurrent_discovery: usize,
+
+    /// Maximum concurrent notice requests to any one domain, within
+    /// `max_concurrent` (unset = no per-domain limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_per_domain: Option<usize>,
+
+    /// Worker threads of the CLI's async runtime (unset = one per CPU)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker_threads: Option<usize>,
+
+    /// Campuses to crawl and save, by name (empty = every enabled campus)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub campuses: Vec<String>,
+
+    /// Seconds over which concurrency grows from 1 to `max_concurrent` at
+    /// the start of a run (unset = full concurrency immediately)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ramp_up_secs: Option<u64>,
+
+    /// Consecutive failures on one domain before its circuit opens (0 = disabled)
+    #[serde(default = "defaults::circuit_breaker_threshold")]
+    pub circuit_breaker_threshold: u32,
+
+    /// Seconds an open circuit stays open before retrying, doubled each time
+    /// it re-opens (0 = stay open for the rest of the run)
+    #[serde(default)]
+    pub circuit_breaker_cooldown_secs: u64,
+
+    /// Maximum idle pooled connections kept per host (unset = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_max_idle_per_host: Option<usize>,
+
+    /// Seconds an idle pooled connection is kept alive
+    #[serde(default = "defaults::pool_idle_timeout")]
+    pub pool_idle_timeout_secs: u64,
+
+    /// Seconds to wait for a TCP/TLS connection, capped at `timeout_secs`,
+    /// so unreachable hosts fail before the full request timeout
+    #[serde(default = "defaults::connect_timeout")]
+    pub connect_timeout_secs: u64,
+
+    /// Interval of TCP keep-alive probes on open connections (0 = disabled)
+    #[serde(default = "defaults::tcp_keepalive")]
+    pub tcp_keepalive_secs: u64,
+
+    /// Board pages shorter than this many bytes are treated as failed
+    /// (likely a bot-blocking placeholder) instead of yielding zero notices
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_content_length: Option<usize>,
+
+    /// Backfill: page backward through boards with a `page_param` until a
+    /// page's oldest notice predates this date (or `max_pages` is reached)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backfill_until: Option<NaiveDate>,
+
+    /// Maximum list pages fetched per board when backfilling
+    #[serde(default = "defaults::max_pages")]
+    pub max_pages: usize,
+
+    /// Raise the request delay to a domain's robots.txt `Crawl-delay`
+    #[serde(default)]
+    pub respect_crawl_delay: bool,
+
+    /// Longest robots.txt `Crawl-delay` honored, in seconds; longer ones are
+    /// cut to this so one host cannot stall the run
+    #[serde(default = "defaults::max_crawl_delay")]
+    pub max_crawl_delay_secs: u64,
+
+    /// Retries after an HTTP 429, each waiting for the server's `Retry-After`
+    /// or, without one, a jittered exponential backoff
+    #[serde(default = "defaults::max_retries")]
+    pub max_retries: usize,
+
+    /// Wall-clock cap on fetching, in seconds; when it passes, the notices
+    /// collected so far are saved (unset = no cap)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_run_secs: Option<u64>,
+
+    /// Abort a response once its decompressed body exceeds this many bytes
+    /// (unset = 10 MiB)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_body_bytes: Option<usize>,
+
+    /// Reuse a board's previous notices when its list page is byte-identical
+    /// to the last run (hash kept in the state file)
+    #[serde(default)]
+    pub skip_unchanged: bool,
+
+    /// Request gzip/deflate/brotli responses and decompress them
+    #[serde(default = "defaults::accept_compression")]
+    pub accept_compression: bool,
+
+    /// Accept invalid or expired TLS certificates (insecure; logged loudly)
+    #[serde(default)]
+    pub danger_accept_invalid_certs: bool,
+
+    /// Minimum TLS version: "1.0", "1.1", "1.2" or "1.3" (unset = library default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_tls_version: Option<String>,
+
+    /// Read `file://` board and notice URLs from local disk (offline runs
+    /// against saved pages); off so remote pages cannot reach local files
+    #[serde(default)]
+    pub allow_file_urls: bool,
+
+    /// Fail the run when any board fails or no notices are produced
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Keep each notice's source row HTML in `Notice::raw_html`
+    #[serde(default)]
+    pub capture_row_html: bool,
 }
 
 impl Default for CrawlerConfig {
     fn default() -> Self {
         Self {
             user_agent: defaults::user_agent(),
+            user_agent_overrides: HashMap::new(),
             timeout_secs: defaults::timeout(),
             sitemap_timeout_secs: defaults::sitemap_timeout(),
             request_delay_ms: defaults::request_delay(),
             max_concurrent: defaults::max_concurrent(),
+            max_concurrent_discovery: defaults::max_concurrent_discovery(),
+            max_concurrent_per_domain: None,
+            ramp_up_secs: None,
+            worker_threads: None,
+            campuses: Vec::new(),
+            circuit_breaker_threshold: defaults::circuit_breaker_threshold(),
+            circuit_breaker_cooldown_secs: 0,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout_secs: defaults::pool_idle_timeout(),
+            connect_timeout_secs: defaults::connect_timeout(),
+            tcp_keepalive_secs: defaults::tcp_keepalive(),
+            min_content_length: None,
+            backfill_until: None,
+            max_pages: defaults::max_pages(),
+            max_retries: defaults::max_retries(),
+            max_run_secs: None,
+            max_body_bytes: None,
+            respect_crawl_delay: false,
+            max_crawl_delay_secs: defaults::max_crawl_delay(),
+            skip_unchanged: false,
+            accept_compression: defaults::accept_compression(),
+            danger_accept_invalid_certs: false,
+            min_tls_version: None,
+            allow_file_urls: false,
+            strict: false,
+            capture_row_html: false,
         }
     }
 }
 
+impl CrawlerConfig {
+    /// The overridden User-Agent for `url`'s domain, if any.
+    pub fn user_agent_override(&self, url: &str) -> Option<&str> {
+        let domain = get_domain(url)?;
+        self.user_agent_overrides.get(&domain).map(String::as_str)
+    }
+}
+
 /// File path configurations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PathsConfig {
@@ -198,6 +552,29 @@ pub struct PathsConfig {
     /// Manual review items filename
     #[serde(default = "defaults::manual_review_file")]
     pub manual_review_file: String,
+
+    /// Crawl state carried between runs (per-board last success)
+    #[serde(default = "defaults::state_file")]
+    pub state_file: String,
+
+    /// Append-only NDJSON log of failed boards across runs (unset = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_log_file: Option<String>,
+
+    /// Directory for per-board raw HTML and response header dumps, relative
+    /// to the working directory (unset = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_dir: Option<String>,
+
+    /// WARC file recording every fetched page, relative to the working
+    /// directory (requires the `warc` feature; unset = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warc_file: Option<String>,
+
+    /// Run manifest summarizing what a crawl produced, relative to the
+    /// output directory (unset = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_file: Option<String>,
 }
 
 impl Default for PathsConfig {
@@ -209,6 +586,11 @@ impl Default for PathsConfig {
             departments_file: defaults::departments_file(),
             departments_boards_file: defaults::departments_boards_file(),
             manual_review_file: defaults::manual_review_file(),
+            state_file: defaults::state_file(),
+            failure_log_file: None,
+            debug_dir: None,
+            warc_file: None,
+            manifest_file: None,
         }
     }
 }
@@ -220,9 +602,22 @@ pub struct DiscoveryConfig {
     #[serde(default = "defaults::max_board_name_length")]
     pub max_board_name_length: usize,
 
-    /// URL patterns to exclude from board discovery
+    /// URL patterns to exclude from board discovery (plain substrings)
     #[serde(default = "defaults::blacklist_patterns")]
     pub blacklist_patterns: Vec<String>,
+
+    /// Regexes matched against the full resolved URL to exclude from discovery
+    #[serde(default)]
+    pub blacklist_regex: Vec<String>,
+
+    /// Regexes a discovered URL must match at least one of (empty = accept
+    /// all); checked before the blacklist
+    #[serde(default)]
+    pub whitelist_patterns: Vec<String>,
+
+    /// Drop notices whose link is not an absolute http(s) URL
+    #[serde(default = "defaults::require_valid_link")]
+    pub require_valid_link: bool,
 }
 
 impl Default for DiscoveryConfig {
@@ -230,12 +625,15 @@ impl Default for DiscoveryConfig {
         Self {
             max_board_name_length: defaults::max_board_name_length(),
             blacklist_patterns: defaults::blacklist_patterns(),
+            blacklist_regex: Vec::new(),
+            whitelist_patterns: Vec::new(),
+            require_valid_link: defaults::require_valid_link(),
         }
     }
 }
 
 /// Text cleaning/preprocessing settings.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CleaningConfig {
     /// Patterns to remove from titles
     #[serde(default)]
@@ -248,12 +646,63 @@ pub struct CleaningConfig {
     /// Text replacements to apply to dates
     #[serde(default)]
     pub date_replacements: Vec<Replacement>,
+
+    /// Normalize extracted text to Unicode NFC (composes decomposed Hangul jamo)
+    #[serde(default = "defaults::normalize_unicode")]
+    pub normalize_unicode: bool,
+
+    /// Maximum title length in characters; longer titles are truncated with an ellipsis
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_title_length: Option<usize>,
+
+    /// Resolution of relative dates ("오늘", "3일 전", "2 days ago")
+    #[serde(default)]
+    pub relative_dates: RelativeDateConfig,
+
+    /// Query parameters stripped from notice links (exact names or `prefix*` wildcards)
+    #[serde(default = "defaults::strip_query_params")]
+    pub strip_query_params: Vec<String>,
+
+    /// Percent-encode non-ASCII characters and spaces left in notice links
+    /// (e.g. raw Korean in a link that could not be parsed) and Punycode
+    /// their hosts, keeping existing escapes
+    #[serde(default = "defaults::percent_encode_links")]
+    pub percent_encode_links: bool,
+
+    /// Parsed dates before January 1st of this year are implausible
+    #[serde(default = "defaults::min_date_year")]
+    pub min_date_year: i32,
+
+    /// Parsed dates more than this many days after today are implausible
+    #[serde(default = "defaults::max_future_days")]
+    pub max_future_days: i64,
+}
+
+impl Default for CleaningConfig {
+    fn default() -> Self {
+        Self {
+            title_remove_patterns: Vec::new(),
+            date_remove_patterns: Vec::new(),
+            date_replacements: Vec::new(),
+            normalize_unicode: defaults::normalize_unicode(),
+            max_title_length: None,
+            relative_dates: RelativeDateConfig::default(),
+            strip_query_params: defaults::strip_query_params(),
+            percent_encode_links: defaults::percent_encode_links(),
+            min_date_year: defaults::min_date_year(),
+            max_future_days: defaults::max_future_days(),
+        }
+    }
 }
 
 impl CleaningConfig {
     /// Clean text by removing patterns and applying replacements.
     fn clean(&self, text: &str, patterns: &[String], replacements: &[Replacement]) -> String {
-        let mut result = Self::normalize_whitespace(text);
+        let mut result = if self.normalize_unicode {
+            Self::normalize_whitespace(&Self::normalize_nfc(text))
+        } else {
+            Self::normalize_whitespace(text)
+        };
 
         for pattern in patterns {
             result = result.replace(pattern, "");
@@ -268,17 +717,172 @@ impl CleaningConfig {
 
     /// Clean a title string.
     pub fn clean_title(&self, text: &str) -> String {
-        self.clean(text, &self.title_remove_patterns, &[])
+        let title = self.clean(text, &self.title_remove_patterns, &[]);
+        match self.max_title_length {
+            Some(max) => Self::truncate_title(title, max),
+            None => title,
+        }
     }
 
-    /// Clean a date string.
-    pub fn clean_date(&self, text: &str) -> String {
-        self.clean(text, &self.date_remove_patterns, &self.date_replacements)
+    /// Clean a date string, resolving relative phrases into `YYYY-MM-DD`
+    /// against `now` (the caller's clock) unless
+    /// `relative_dates.reference_now` is set.
+    pub fn clean_date_at(&self, text: &str, now: NaiveDateTime) -> String {
+        let date = self.clean(text, &self.date_remove_patterns, &self.date_replacements);
+        if !self.relative_dates.enabled {
+            return date;
+        }
+        let now = self.relative_dates.reference_now.unwrap_or(now);
+        resolve_relative_date(&date, now, &self.relative_dates).unwrap_or(date)
+    }
+
+    /// These settings with `board`'s own remove patterns merged in.
+    ///
+    /// Board patterns are appended to the global ones, or replace them when
+    /// the board sets `replace_cleaning_patterns`.
+    pub fn for_board(&self, board: &Board) -> Cow<'_, Self> {
+        if board.title_remove_patterns.is_empty()
+            && board.date_remove_patterns.is_empty()
+            && !board.replace_cleaning_patterns
+        {
+            return Cow::Borrowed(self);
+        }
+        let merge = |global: &[String], own: &[String]| {
+            if board.replace_cleaning_patterns {
+                own.to_vec()
+            } else {
+                [global, own].concat()
+            }
+        };
+        Cow::Owned(Self {
+            title_remove_patterns: merge(&self.title_remove_patterns, &board.title_remove_patterns),
+            date_remove_patterns: merge(&self.date_remove_patterns, &board.date_remove_patterns),
+            ..self.clone()
+        })
+    }
+
+    /// Whether a cleaned date could be a real posting date as of `today`.
+    ///
+    /// Parseable dates (including compact `20240302`) must fall between
+    /// `min_date_year` and `max_future_days` from today. Bare numbers that do
+    /// not parse ("1523", "2024") are usually a view count or a lone year
+    /// picked up by a misconfigured date selector and are rejected; other
+    /// text ("상시") passes, since it is not a date at all. A
+    /// `max_future_days` too large to add to `today` leaves dates unbounded
+    /// above.
+    pub fn is_plausible_date(&self, date: &str, today: NaiveDate) -> bool {
+        let Some(parsed) = parse_date(date) else {
+            return date.is_empty() || !date.chars().all(|c| c.is_ascii_digit());
+        };
+        let latest = TimeDelta::try_days(self.max_future_days)
+            .and_then(|days| today.checked_add_signed(days));
+        parsed.year() >= self.min_date_year && latest.is_none_or(|latest| parsed <= latest)
+    }
+
+    /// Clean notice body text, keeping its line structure.
+    pub fn clean_body(&self, text: &str) -> String {
+        if self.normalize_unicode {
+            Self::normalize_whitespace_keep_newlines(&Self::normalize_nfc(text))
+        } else {
+            Self::normalize_whitespace_keep_newlines(text)
+        }
     }
 
     fn normalize_whitespace(s: &str) -> String {
         s.split_whitespace().collect::<Vec<_>>().join(" ")
     }
+
+    /// Like [`normalize_whitespace`](Self::normalize_whitespace) within each
+    /// line, but keeps line breaks; runs of blank lines collapse to one and
+    /// leading/trailing blank lines are dropped.
+    fn normalize_whitespace_keep_newlines(s: &str) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        for line in s.lines().map(Self::normalize_whitespace) {
+            let blank_run = line.is_empty() && lines.last().is_none_or(|last| last.is_empty());
+            if !blank_run {
+                lines.push(line);
+            }
+        }
+        if lines.last().is_some_and(|last| last.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
+    /// Clean a resolved notice link by stripping tracking query parameters
+    /// and, with `percent_encode_links`, escaping non-ASCII characters.
+    pub fn clean_link(&self, link: &str) -> String {
+        let link = strip_query_params(link, &self.strip_query_params);
+        if self.percent_encode_links {
+            percent_encode_non_ascii(&link)
+        } else {
+            link
+        }
+    }
+
+    /// Truncate a title to `max` characters (not bytes) and append an ellipsis.
+    fn truncate_title(title: String, max: usize) -> String {
+        if title.chars().count() <= max {
+            return title;
+        }
+        log::debug(&format!(
+            "Truncating title longer than {max} chars (check title selector): {title}"
+        ));
+        let mut truncated: String = title.chars().take(max).collect();
+        truncated.push('…');
+        truncated
+    }
+
+    /// Compose text into Unicode NFC so identical strings hash identically.
+    fn normalize_nfc(s: &str) -> String {
+        s.nfc().collect()
+    }
+}
+
+/// Relative date phrases and the reference time they resolve against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelativeDateConfig {
+    /// Enable relative date resolution
+    #[serde(default = "defaults::relative_dates_enabled")]
+    pub enabled: bool,
+
+    /// Reference "now" (defaults to the current local time), e.g. "2024-01-15T09:00:00"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_now: Option<NaiveDateTime>,
+
+    /// Fixed phrases mapped to a day offset (e.g. "어제" = -1)
+    #[serde(default = "defaults::relative_phrases")]
+    pub phrases: BTreeMap<String, i64>,
+
+    /// Unit words for "N days ago"
+    #[serde(default = "defaults::relative_day_units")]
+    pub day_units: Vec<String>,
+
+    /// Unit words for "N hours ago"
+    #[serde(default = "defaults::relative_hour_units")]
+    pub hour_units: Vec<String>,
+
+    /// Unit words for "N minutes ago"
+    #[serde(default = "defaults::relative_minute_units")]
+    pub minute_units: Vec<String>,
+
+    /// Suffixes marking a phrase as being in the past ("전", "ago")
+    #[serde(default = "defaults::relative_ago_suffixes")]
+    pub ago_suffixes: Vec<String>,
+}
+
+impl Default for RelativeDateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: defaults::relative_dates_enabled(),
+            reference_now: None,
+            phrases: defaults::relative_phrases(),
+            day_units: defaults::relative_day_units(),
+            hour_units: defaults::relative_hour_units(),
+            minute_units: defaults::relative_minute_units(),
+            ago_suffixes: defaults::relative_ago_suffixes(),
+        }
+    }
 }
 
 /// A text replacement rule.
@@ -288,22 +892,205 @@ pub struct Replacement {
     pub to: String,
 }
 
+/// Assigns `category` to notices whose title contains any of `keywords`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CategoryRule {
+    pub category: String,
+    pub keywords: Vec<String>,
+}
+
+impl CategoryRule {
+    /// Whether `title` contains one of the rule's keywords (case-insensitive).
+    pub fn matches(&self, title: &str) -> bool {
+        let title = title.to_lowercase();
+        self.keywords
+            .iter()
+            .any(|keyword| title.contains(&keyword.to_lowercase()))
+    }
+}
+
 /// Output format settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputConfig {
     /// Enable console output
     #[serde(default)]
     pub console_enabled: bool,
+
+    /// Console layout for notices
+    #[serde(default)]
+    pub console_format: ConsoleFormat,
+
+    /// Console template for notices (used by the `template` format)
+    #[serde(default = "defaults::notice_format")]
+    pub notice_format: String,
+
+    /// Colorize console output (auto honors NO_COLOR and TTY detection)
+    #[serde(default)]
+    pub color: ColorMode,
+
+    /// Enable combined JSON file output
+    #[serde(default)]
+    pub json_enabled: bool,
+
+    /// Pretty print JSON output
+    #[serde(default = "defaults::json_pretty")]
+    pub json_pretty: bool,
+
+    /// Combined JSON filename (relative to the output directory)
+    #[serde(default = "defaults::json_file")]
+    pub json_file: String,
+
+    /// How JSON output is split into files
+    #[serde(default)]
+    pub group_by: GroupBy,
+
+    /// Shape of each JSON file
+    #[serde(default)]
+    pub json_format: JsonFormat,
+
+    /// Merge new notices into existing JSON files (deduped by link) instead
+    /// of overwriting them, so each file accumulates an archive
+    #[serde(default)]
+    pub append_mode: bool,
+
+    /// Under `group_by = "board_tree"`, write an empty file for boards that
+    /// matched rows but yielded no notices, instead of no file at all
+    #[serde(default)]
+    pub write_empty_boards: bool,
+
+    /// Gzip every JSON file, writing `<name>.json.gz`
+    #[serde(default)]
+    pub gzip_output: bool,
+
+    /// OPML outline of all board feeds (relative to the output directory;
+    /// unset = not written)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opml_path: Option<String>,
+
+    /// Feed location of each board in the OPML outline; supports
+    /// `{campus}`, `{department_id}` and `{board_id}` (percent-encoded as
+    /// path segments). The crawler writes no
+    /// feeds itself, so this must point at feeds published elsewhere and is
+    /// required with `opml_path`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opml_feed_url: Option<String>,
+
+    /// Excel workbook of all notices (relative to the output directory;
+    /// requires the `xlsx` feature; unset = not written)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xlsx_path: Option<String>,
+
+    /// How notices are split into worksheets of the Excel workbook
+    #[serde(default)]
+    pub xlsx_sheets: XlsxSheets,
+
+    /// Order of notices presented and saved
+    #[serde(default)]
+    pub sort_by: SortBy,
+
+    /// Global cap on notices presented and saved per run (unset = no cap)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
 }
 
 impl Default for OutputConfig {
     fn default() -> Self {
         Self {
             console_enabled: false,
+            console_format: ConsoleFormat::default(),
+            notice_format: defaults::notice_format(),
+            color: ColorMode::default(),
+            json_enabled: false,
+            json_pretty: defaults::json_pretty(),
+            json_file: defaults::json_file(),
+            group_by: GroupBy::default(),
+            json_format: JsonFormat::default(),
+            append_mode: false,
+            write_empty_boards: false,
+            gzip_output: false,
+            opml_path: None,
+            opml_feed_url: None,
+            xlsx_path: None,
+            xlsx_sheets: XlsxSheets::default(),
+            sort_by: SortBy::default(),
+            limit: None,
         }
     }
 }
 
+/// Console layout for presenting notices.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsoleFormat {
+    /// One block per notice rendered from `notice_format`
+    #[default]
+    Template,
+    /// Aligned columns (department, board, title, date)
+    Table,
+}
+
+/// How JSON output files are partitioned.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    /// Every notice in `json_file`
+    #[default]
+    Flat,
+    /// One file per board: `<campus>/<department_id>/<board_id>.json`
+    BoardTree,
+    /// One file per notice date: `<YYYY-MM-DD>.json`, plus `undated.json`
+    Date,
+}
+
+/// Shape of JSON output files.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JsonFormat {
+    /// A plain array of notices
+    #[default]
+    Array,
+    /// An object with `generated_at`, `crawler_version` and `count` around
+    /// the `notices` array
+    CombinedWithMeta,
+}
+
+/// Order of notices in output.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SortBy {
+    /// Crawl order
+    #[default]
+    None,
+    /// Newest first; undated notices last
+    DateDesc,
+    /// Oldest first; undated notices last
+    DateAsc,
+    /// By department name, newest first within a department
+    Department,
+}
+
+/// How Excel output is split into worksheets.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum XlsxSheets {
+    /// One worksheet per campus
+    #[default]
+    Campus,
+    /// One worksheet per department
+    Department,
+}
+
+/// When to emit ANSI colors on the console.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorMode {
+    /// Color only when stdout is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
 /// Logging settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -325,10 +1112,21 @@ impl Default for LoggingConfig {
     }
 }
 
+/// Notice filtering settings.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FilterConfig {
+    /// Drop notices whose title repeats that of a different notice first
+    /// seen on the same board within this many days (tracked in the state
+    /// file, so it spans runs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppress_duplicate_titles_days: Option<u32>,
+}
+
 /// Internationalization/localization settings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LocaleConfig {
     /// UI messages
+    #[serde(default)]
     pub messages: Messages,
 
     /// Error messages
@@ -338,28 +1136,157 @@ pub struct LocaleConfig {
 
 impl LocaleConfig {
     /// Load locale from a TOML file.
+    ///
+    /// Messages missing from the file keep their built-in defaults.
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
-        let content = fs::read_to_string(path)?;
-        Ok(toml::from_str(&content)?)
+        Self::load_layered(&[path.as_ref().to_path_buf()])
     }
 
     /// Load locale or return default if loading fails.
     pub fn load_or_default(path: impl AsRef<Path>) -> Self {
-        Self::load(&path).unwrap_or_else(|e| {
+        Self::load_for_lang(path, None)
+    }
+
+    /// Load `path` overlaid with its `lang` variant (`locale.toml` ->
+    /// `locale.<lang>.toml`), falling back to defaults if loading fails.
+    ///
+    /// Lookup order per message: language file, base file, built-in default.
+    pub fn load_for_lang(path: impl AsRef<Path>, lang: Option<&str>) -> Self {
+        let path = path.as_ref();
+        let mut layers = vec![path.to_path_buf()];
+        if let Some(lang) = lang {
+            let lang_path = Self::lang_path(path, lang);
+            if !lang_path.exists() {
+                log::warn(&format!(
+                    "Locale file for '{lang}' not found at {lang_path:?}"
+                ));
+            }
+            layers.push(lang_path);
+        }
+
+        Self::load_layered(&layers).unwrap_or_else(|e| {
             log::warn(&format!(
-                "Locale load failed from {:?}: {e}. Using defaults.",
-                path.as_ref()
+                "Locale load failed from {path:?}: {e}. Using defaults."
             ));
             Self::default()
         })
     }
+
+    /// Path of the `lang` variant of a locale file.
+    pub fn lang_path(path: &Path, lang: &str) -> PathBuf {
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("locale");
+        path.with_file_name(format!("{stem}.{lang}.toml"))
+    }
+
+    /// Merge locale files key by key, later files overriding earlier ones.
+    ///
+    /// Missing files are skipped, and non-string messages are ignored with a
+    /// warning so one bad entry does not discard the whole file.
+    fn load_layered(paths: &[PathBuf]) -> Result<Self> {
+        let mut merged = toml::Table::new();
+        for path in paths.iter().filter(|path| path.exists()) {
+            let layer: toml::Table = toml::from_str(&fs::read_to_string(path)?)?;
+            merge_locale_table(&mut merged, layer, path);
+        }
+        Ok(toml::Value::Table(merged).try_into()?)
+    }
 }
 
-impl Default for LocaleConfig {
-    fn default() -> Self {
-        Self {
-            messages: Messages::default(),
-            errors: Errors::default(),
+/// `${NAME}` environment variable references in config strings.
+static ENV_REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}").expect("valid regex"));
+
+/// Replace `${NAME}` references in `value` with `lookup(NAME)`.
+///
+/// Fails naming the variable if `lookup` has no value for it.
+pub fn expand_env(value: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut last = 0;
+    for caps in ENV_REFERENCE.captures_iter(value) {
+        let (whole, name) = (caps.get(0).unwrap(), &caps[1]);
+        let resolved = lookup(name)
+            .ok_or_else(|| AppError::config(format!("environment variable {name} is not set")))?;
+        expanded.push_str(&value[last..whole.start()]);
+        expanded.push_str(&resolved);
+        last = whole.end();
+    }
+    expanded.push_str(&value[last..]);
+    Ok(expanded)
+}
+
+/// Expand `${NAME}` references in every string under `value`; `key` is the
+/// dotted path of `value`, used in error messages.
+fn expand_env_in_value(
+    value: &mut toml::Value,
+    key: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<()> {
+    match value {
+        toml::Value::String(text) => {
+            *text = expand_env(text, lookup).map_err(|e| match e {
+                AppError::Config(message) => AppError::config(format!("{key}: {message}")),
+                other => other,
+            })?;
+        }
+        toml::Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                expand_env_in_value(item, &format!("{key}[{idx}]"), lookup)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (name, item) in table.iter_mut() {
+                let path = if key.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{key}.{name}")
+                };
+                expand_env_in_value(item, &path, lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// `{count|singular|plural}` plural patterns in messages.
+static PLURAL_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{count\|([^|}]*)\|([^}]*)\}").expect("valid regex"));
+
+/// Substitute `count` into a message.
+///
+/// Plural patterns `{count|singular|plural}` resolve to the form matching
+/// `count`, then `{count}` is replaced by the number. Messages without
+/// patterns (e.g. Korean ones) are only number-substituted.
+pub fn format_count(message: &str, count: usize) -> String {
+    PLURAL_PATTERN
+        .replace_all(message, |caps: &regex::Captures| {
+            let form = if count == 1 { &caps[1] } else { &caps[2] };
+            form.to_string()
+        })
+        .replace("{count}", &count.to_string())
+}
+
+fn merge_locale_table(into: &mut toml::Table, layer: toml::Table, path: &Path) {
+    for (key, value) in layer {
+        match value {
+            toml::Value::Table(section) => {
+                let entry = into
+                    .entry(key)
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                if let toml::Value::Table(existing) = entry {
+                    merge_locale_table(existing, section, path);
+                }
+            }
+            toml::Value::String(_) => {
+                into.insert(key, value);
+            }
+            other => log::warn(&format!(
+                "Ignoring locale entry '{key}' in {path:?}: expected a string, got {}",
+                other.type_str()
+            )),
         }
     }
 }
@@ -576,7 +1503,7 @@ impl Default for Messages {
 }
 
 /// Error message strings.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct Errors {
     #[serde(default = "defaults::err_config_load")]
@@ -603,7 +1530,27 @@ pub struct Errors {
     pub parse_error: String,
 }
 
+impl Default for Errors {
+    fn default() -> Self {
+        Self {
+            config_load_failed: defaults::err_config_load(),
+            config_using_defaults: defaults::err_config_using_defaults(),
+            seed_load_failed: defaults::err_seed_load_failed(),
+            seed_using_defaults: defaults::err_seed_using_defaults(),
+            seed_validation_failed: defaults::err_seed_validation_failed(),
+            sitemap_not_found: defaults::err_sitemap_not_found(),
+            invalid_date_format: defaults::err_invalid_date_format(),
+            invalid_year: defaults::err_invalid_year(),
+            invalid_month: defaults::err_invalid_month(),
+            http_error: defaults::err_http_error(),
+            parse_error: defaults::err_parse_error(),
+        }
+    }
+}
+
 mod defaults {
+    use std::collections::BTreeMap;
+
     // Crawler defaults
     pub fn user_agent() -> String {
         "Mozilla/5.0 (compatible; uRing/1.0)".into()
@@ -620,6 +1567,33 @@ mod defaults {
     pub fn max_concurrent() -> usize {
         5
     }
+    pub fn max_pages() -> usize {
+        10
+    }
+    pub fn max_crawl_delay() -> u64 {
+        30
+    }
+    pub fn max_retries() -> usize {
+        2
+    }
+    pub fn max_concurrent_discovery() -> usize {
+        5
+    }
+    pub fn circuit_breaker_threshold() -> u32 {
+        5
+    }
+    pub fn pool_idle_timeout() -> u64 {
+        60
+    }
+    pub fn connect_timeout() -> u64 {
+        10
+    }
+    pub fn tcp_keepalive() -> u64 {
+        30
+    }
+    pub fn accept_compression() -> bool {
+        true
+    }
 
     // Path defaults
     pub fn seed_file() -> String {
@@ -631,6 +1605,9 @@ mod defaults {
     pub fn manual_review_file() -> String {
         "Temp/manual_review_needed.json".into()
     }
+    pub fn state_file() -> String {
+        "Temp/crawl_state.json".into()
+    }
     pub fn departments_file() -> String {
         "Temp/yonsei_departments.json".into()
     }
@@ -653,7 +1630,71 @@ mod defaults {
         ]
     }
 
+    pub fn require_valid_link() -> bool {
+        true
+    }
+
+    // Cleaning defaults
+    pub fn normalize_unicode() -> bool {
+        true
+    }
+
+    pub fn strip_query_params() -> Vec<String> {
+        vec![
+            "PHPSESSID".into(),
+            "JSESSIONID".into(),
+            "utm_*".into(),
+            "fbclid".into(),
+            "gclid".into(),
+        ]
+    }
+    pub fn percent_encode_links() -> bool {
+        true
+    }
+    pub fn min_date_year() -> i32 {
+        2000
+    }
+    pub fn max_future_days() -> i64 {
+        365
+    }
+    pub fn relative_dates_enabled() -> bool {
+        true
+    }
+    pub fn relative_phrases() -> BTreeMap<String, i64> {
+        [
+            ("오늘", 0),
+            ("어제", -1),
+            ("그제", -2),
+            ("today", 0),
+            ("yesterday", -1),
+        ]
+        .into_iter()
+        .map(|(phrase, days)| (phrase.to_string(), days))
+        .collect()
+    }
+    pub fn relative_day_units() -> Vec<String> {
+        vec!["일".into(), "day".into(), "days".into()]
+    }
+    pub fn relative_hour_units() -> Vec<String> {
+        vec!["시간".into(), "hour".into(), "hours".into()]
+    }
+    pub fn relative_minute_units() -> Vec<String> {
+        vec!["분".into(), "minute".into(), "minutes".into()]
+    }
+    pub fn relative_ago_suffixes() -> Vec<String> {
+        vec!["전".into(), "ago".into()]
+    }
+
     // Output defaults
+    pub fn json_pretty() -> bool {
+        true
+    }
+    pub fn json_file() -> String {
+        "notices.json".into()
+    }
+    pub fn notice_format() -> String {
+        "📌 [{dept_name}:{board_name}] {title}\n   📅 {date}\n   🔗 {link}".into()
+    }
 
     // Logging defaults
     pub fn log_level() -> String {
@@ -735,13 +1776,13 @@ mod defaults {
         "Crawl complete".into()
     }
     pub fn msg_total() -> String {
-        "Total notices fetched: {count}".into()
+        "Total {count|notice|notices} fetched: {count}".into()
     }
     pub fn msg_saved() -> String {
         "Saved notices to {path}".into()
     }
     pub fn msg_storage_saved() -> String {
-        "Storage: {count} notices saved to {path}".into()
+        "Storage: {count} {count|notice|notices} saved to {path}".into()
     }
     pub fn msg_storage_paths_header() -> String {
         "S3 Storage Paths (events + snapshots)".into()
@@ -769,7 +1810,7 @@ mod defaults {
         "Loading event notices from {year}-{month}".into()
     }
     pub fn msg_load_complete() -> String {
-        "Loaded {count} notices".into()
+        "Loaded {count} {count|notice|notices}".into()
     }
     pub fn msg_load_notice_item() -> String {
         "{title} [{date}]".into()
@@ -907,6 +1948,57 @@ mod tests {
         assert!(Config::default().validate().is_ok());
     }
 
+    #[test]
+    fn env_references_expand_and_unset_ones_fail() {
+        let lookup = |name: &str| (name == "CRAWLER_AGENT").then(|| "bot/1.0".to_string());
+        assert_eq!(
+            expand_env("uRing ${CRAWLER_AGENT} ($HOME)", &lookup).unwrap(),
+            "uRing bot/1.0 ($HOME)"
+        );
+
+        let mut value: toml::Value =
+            toml::from_str("[crawler]\nuser_agent = \"${DISCORD_WEBHOOK}\"").unwrap();
+        let err = expand_env_in_value(&mut value, "", &lookup).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Configuration error: crawler.user_agent: environment variable DISCORD_WEBHOOK is not set"
+        );
+    }
+
+    #[test]
+    fn builder_sets_fields_and_keeps_defaults() {
+        let config = Config::builder()
+            .user_agent("test-agent")
+            .timeout_secs(7)
+            .max_concurrent(2)
+            .json_output("out.json")
+            .sort_by(SortBy::DateDesc)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.crawler.user_agent, "test-agent");
+        assert_eq!(config.crawler.timeout_secs, 7);
+        assert_eq!(config.crawler.max_concurrent, 2);
+        assert!(config.output.json_enabled);
+        assert_eq!(config.output.json_file, "out.json");
+        assert_eq!(config.output.sort_by, SortBy::DateDesc);
+
+        let defaults = Config::default();
+        assert_eq!(
+            config.crawler.request_delay_ms,
+            defaults.crawler.request_delay_ms
+        );
+        assert_eq!(config.paths.output_dir, defaults.paths.output_dir);
+        assert_eq!(config.output.json_pretty, defaults.output.json_pretty);
+        assert!(!config.output.console_enabled);
+        assert_eq!(config.output.limit, None);
+    }
+
+    #[test]
+    fn builder_rejects_invalid_config() {
+        assert!(Config::builder().max_concurrent(0).build().is_err());
+    }
+
     #[test]
     fn validate_rejects_empty_user_agent() {
         let mut config = Config::default();
@@ -920,4 +2012,176 @@ mod tests {
         config.crawler.max_concurrent = 0;
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn validate_rejects_negative_date_bounds() {
+        let mut config = Config::default();
+        config.cleaning.max_future_days = -1;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.cleaning.min_date_year = -2000;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn is_plausible_date_survives_huge_future_window() {
+        let cleaning = CleaningConfig {
+            max_future_days: i64::MAX,
+            ..CleaningConfig::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert!(cleaning.is_plausible_date("2099-12-31", today));
+        assert!(!cleaning.is_plausible_date("1523", today));
+    }
+
+    #[test]
+    fn is_plausible_date_windows_compact_dates() {
+        let cleaning = CleaningConfig::default();
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert!(cleaning.is_plausible_date("20240229", today));
+        assert!(!cleaning.is_plausible_date("19991231", today));
+        assert!(!cleaning.is_plausible_date("2024", today));
+        assert!(cleaning.is_plausible_date("상시", today));
+        assert!(cleaning.is_plausible_date("", today));
+    }
+
+    #[test]
+    fn validate_requires_feed_url_for_opml() {
+        let mut config = Config::default();
+        config.output.opml_path = Some("feeds.opml".to_string());
+        assert!(config.validate().is_err());
+
+        config.output.opml_feed_url = Some("https://feeds.example.com/{board_id}.xml".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_invalid_blacklist_regex() {
+        let mut config = Config::default();
+        config.discovery.blacklist_regex = vec!["[?&]seq=(".to_string()];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn clean_title_composes_nfd_input() {
+        let cleaning = CleaningConfig::default();
+        // "공지" spelled with decomposed jamo (NFD)
+        let nfd = "\u{1100}\u{1169}\u{11BC}\u{110C}\u{1175}";
+        assert_eq!(cleaning.clean_title(nfd), "공지");
+
+        let raw = CleaningConfig {
+            normalize_unicode: false,
+            ..CleaningConfig::default()
+        };
+        assert_eq!(raw.clean_title(nfd), nfd);
+    }
+
+    #[test]
+    fn clean_date_resolves_relative_phrase_and_keeps_raw_otherwise() {
+        let mut cleaning = CleaningConfig {
+            date_remove_patterns: vec!["작성일".to_string()],
+            ..CleaningConfig::default()
+        };
+        let now = |text: &str| NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(
+            cleaning.clean_date_at("작성일 2일 전", now("2024-01-15 12:00:00")),
+            "2024-01-13"
+        );
+        assert_eq!(
+            cleaning.clean_date_at("2024.01.01", now("2024-01-15 12:00:00")),
+            "2024.01.01"
+        );
+
+        // A configured reference time wins over the caller's
+        cleaning.relative_dates.reference_now = Some(now("2024-03-10 12:00:00"));
+        assert_eq!(
+            cleaning.clean_date_at("2일 전", now("2024-01-15 12:00:00")),
+            "2024-03-08"
+        );
+    }
+
+    #[test]
+    fn normalize_whitespace_variants_differ_on_newlines() {
+        let text = "  첫 문단\t 입니다.\r\n둘째   줄\n\n\n  다음 문단 \n";
+        assert_eq!(
+            CleaningConfig::normalize_whitespace(text),
+            "첫 문단 입니다. 둘째 줄 다음 문단"
+        );
+        assert_eq!(
+            CleaningConfig::normalize_whitespace_keep_newlines(text),
+            "첫 문단 입니다.\n둘째 줄\n\n다음 문단"
+        );
+    }
+
+    #[test]
+    fn clean_body_keeps_lines_while_titles_flatten() {
+        let cleaning = CleaningConfig::default();
+        let text = "<p>공지   안내</p>\n<p>내용</p>";
+        assert_eq!(cleaning.clean_body(text), "<p>공지 안내</p>\n<p>내용</p>");
+        assert_eq!(cleaning.clean_title(text), "<p>공지 안내</p> <p>내용</p>");
+    }
+
+    #[test]
+    fn clean_title_truncates_on_char_boundary() {
+        let cleaning = CleaningConfig {
+            max_title_length: Some(3),
+            ..CleaningConfig::default()
+        };
+        assert_eq!(cleaning.clean_title("장학금 신청 안내"), "장학금…");
+        assert_eq!(cleaning.clean_title("학사"), "학사");
+        assert_eq!(cleaning.clean_title("abc"), "abc");
+    }
+
+    #[test]
+    fn locale_partial_override_keeps_other_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("locale.toml");
+        fs::write(
+            &path,
+            "[messages]\ncrawler_starting = \"크롤러 시작\"\napp_finished = 3\n",
+        )
+        .unwrap();
+
+        let locale = LocaleConfig::load(&path).unwrap();
+        assert_eq!(locale.messages.crawler_starting, "크롤러 시작");
+        assert_eq!(
+            locale.messages.app_finished,
+            Messages::default().app_finished
+        );
+        assert_eq!(locale.errors.http_error, "HTTP error: {error}");
+    }
+
+    #[test]
+    fn format_count_resolves_plural_forms() {
+        let message = "Loaded {count} {count|notice|notices}";
+        assert_eq!(format_count(message, 1), "Loaded 1 notice");
+        assert_eq!(format_count(message, 0), "Loaded 0 notices");
+        assert_eq!(format_count(message, 3), "Loaded 3 notices");
+        assert_eq!(format_count("공지 {count}개", 1), "공지 1개");
+    }
+
+    #[test]
+    fn locale_lang_file_overrides_base_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("locale.toml");
+        fs::write(
+            &path,
+            "[messages]\ncrawler_starting = \"base\"\napp_starting = \"base\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("locale.ko.toml"),
+            "[messages]\ncrawler_starting = \"크롤러 시작\"\n",
+        )
+        .unwrap();
+
+        let locale = LocaleConfig::load_for_lang(&path, Some("ko"));
+        assert_eq!(locale.messages.crawler_starting, "크롤러 시작");
+        assert_eq!(locale.messages.app_starting, "base");
+        assert_eq!(
+            locale.messages.mapper_starting,
+            Messages::default().mapper_starting
+        );
+    }
 }
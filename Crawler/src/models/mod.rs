@@ -10,16 +10,29 @@ mod config;
 mod notice;
 mod seed;
 mod selectors;
+mod state;
+
+use std::collections::BTreeMap;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::error::BoardErrorKind;
+
 // Re-export all public types
-pub use campus::{Board, Campus, CampusMeta, College, Department, DepartmentRef};
-pub use config::{Config, CrawlerConfig, DiscoveryConfig, LocaleConfig};
+pub use campus::{
+    BasicAuth, Board, BoardFormat, Campus, CampusMeta, College, Department, DepartmentRef,
+    HttpMethod,
+};
+pub use config::{
+    CategoryRule, CleaningConfig, ColorMode, Config, ConfigBuilder, ConsoleFormat, CrawlerConfig,
+    DiscoveryConfig, FilterConfig, GroupBy, JsonFormat, LocaleConfig, OutputConfig, PathsConfig,
+    RelativeDateConfig, SortBy, XlsxSheets, format_count,
+};
 pub use notice::Notice;
 pub use seed::{CampusInfo, CmsPattern, KeywordMapping, Seed};
-pub use selectors::CmsSelectors;
+pub use selectors::{CmsSelectors, JsonPaths, RowSelector};
+pub use state::{BoardState, CrawlState, SeenTitle};
 
 /// Statistics for a crawl session.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +61,11 @@ pub enum CrawlStage {
     BoardList,
     NoticeDetail,
     BoardLookup,
+    CircuitOpen,
+    /// Board page shorter than `crawler.min_content_length`
+    ShortResponse,
+    /// Board yielded fewer notices than its `expected_min_notices`
+    BelowExpectedCount,
 }
 
 /// Structured crawl error for storage/reporting.
@@ -64,8 +82,101 @@ pub struct CrawlError {
     pub notice_id: Option<String>,
     pub message: String,
     pub retryable: bool,
+    /// Failure category for board-level errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<BoardErrorKind>,
+}
+
+/// One line of the append-only failure log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailureLogEntry {
+    pub run_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub category: CrawlStage,
+    pub message: String,
+}
+
+impl FailureLogEntry {
+    /// Board-level failures of a run (notice detail failures are excluded).
+    pub fn from_outcome(outcome: &CrawlOutcome, run_at: DateTime<Utc>) -> Vec<Self> {
+        outcome
+            .errors
+            .iter()
+            .filter(|error| error.notice_id.is_none())
+            .map(|error| Self {
+                run_at,
+                board_id: error.board_id.clone(),
+                url: error.url.clone(),
+                category: error.stage.clone(),
+                message: error.message.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Summary of what one run produced, for downstream jobs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub run_at: DateTime<Utc>,
+    pub notice_count: usize,
+    /// Notice count per `<department_id>/<board_id>`
+    pub board_counts: BTreeMap<String, usize>,
+    /// Files and storage locations written by the run
+    pub outputs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<CrawlError>,
+    /// Boards that matched rows but yielded no notices, as
+    /// `<department_id>/<board_id>`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub empty_boards: Vec<String>,
+}
+
+impl RunManifest {
+    pub fn new(outcome: &CrawlOutcome, run_at: DateTime<Utc>, outputs: Vec<String>) -> Self {
+        let mut board_counts = BTreeMap::new();
+        for notice in &outcome.notices {
+            *board_counts
+                .entry(format!("{}/{}", notice.department_id, notice.board_id))
+                .or_insert(0) += 1;
+        }
+        Self {
+            run_at,
+            notice_count: outcome.notices.len(),
+            board_counts,
+            outputs,
+            errors: outcome.errors.clone(),
+            empty_boards: outcome
+                .empty_boards
+                .iter()
+                .map(|board| format!("{}/{}", board.department_id, board.board_id))
+                .collect(),
+        }
+    }
 }
 
+/// A board whose list page matched rows that all failed to become notices.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmptyBoard {
+    pub campus: String,
+    pub department_id: String,
+    pub board_id: String,
+}
+
+/// How long one board's list fetch and extraction took.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BoardTiming {
+    pub department_id: String,
+    pub board_id: String,
+    pub board_name: String,
+    pub duration_ms: u64,
+}
+
+/// Number of boards listed in a report's `slow_boards` section.
+pub const SLOW_BOARD_LIMIT: usize = 10;
+
 /// Summary of a crawl run.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CrawlOutcome {
@@ -73,12 +184,37 @@ pub struct CrawlOutcome {
     pub notices: Vec<Notice>,
     pub board_total: usize,
     pub board_failures: usize,
+    /// Boards skipped because their `min_interval_secs` had not elapsed
+    #[serde(default)]
+    pub board_skipped: usize,
     pub notice_total: usize,
     pub notice_failures: usize,
     pub detail_total: usize,
     pub detail_failures: usize,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<CrawlError>,
+    /// Domains whose circuit breaker opened during the run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_circuits: Vec<String>,
+    /// Per-board list fetch + parse durations, in completion order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub board_timings: Vec<BoardTiming>,
+    /// The run hit `crawler.max_run_secs` and holds partial results
+    #[serde(default)]
+    pub cut_off: bool,
+    /// Boards that matched rows but yielded no notices
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub empty_boards: Vec<EmptyBoard>,
+}
+
+impl CrawlOutcome {
+    /// The `n` slowest boards, slowest first.
+    pub fn slowest_boards(&self, n: usize) -> Vec<&BoardTiming> {
+        let mut timings: Vec<_> = self.board_timings.iter().collect();
+        timings.sort_by_key(|timing| std::cmp::Reverse(timing.duration_ms));
+        timings.truncate(n);
+        timings
+    }
 }
 
 /// Crawl outcome report without notice payloads.
@@ -86,12 +222,21 @@ pub struct CrawlOutcome {
 pub struct CrawlOutcomeReport {
     pub board_total: usize,
     pub board_failures: usize,
+    #[serde(default)]
+    pub board_skipped: usize,
     pub notice_total: usize,
     pub notice_failures: usize,
     pub detail_total: usize,
     pub detail_failures: usize,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<CrawlError>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_circuits: Vec<String>,
+    /// Slowest boards of the run, slowest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slow_boards: Vec<BoardTiming>,
+    #[serde(default)]
+    pub cut_off: bool,
 }
 
 impl From<&CrawlOutcome> for CrawlOutcomeReport {
@@ -99,11 +244,19 @@ impl From<&CrawlOutcome> for CrawlOutcomeReport {
         Self {
             board_total: outcome.board_total,
             board_failures: outcome.board_failures,
+            board_skipped: outcome.board_skipped,
             notice_total: outcome.notice_total,
             notice_failures: outcome.notice_failures,
             detail_total: outcome.detail_total,
             detail_failures: outcome.detail_failures,
             errors: outcome.errors.clone(),
+            open_circuits: outcome.open_circuits.clone(),
+            slow_boards: outcome
+                .slowest_boards(SLOW_BOARD_LIMIT)
+                .into_iter()
+                .cloned()
+                .collect(),
+            cut_off: outcome.cut_off,
         }
     }
 }
@@ -120,6 +273,20 @@ pub enum NoticeCategory {
     Other,
 }
 
+impl NoticeCategory {
+    /// The category's serialized id, e.g. `"scholarship"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoticeCategory::Academic => "academic",
+            NoticeCategory::Scholarship => "scholarship",
+            NoticeCategory::Recruitment => "recruitment",
+            NoticeCategory::Event => "event",
+            NoticeCategory::General => "general",
+            NoticeCategory::Other => "other",
+        }
+    }
+}
+
 /// Metadata for notice categories.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CategoryMeta {
@@ -194,16 +361,19 @@ pub struct Diff {
 #[derive(Debug, Default)]
 pub struct BoardDiscoveryResult {
     pub boards: Vec<Board>,
-    pub manual_review: Option<ManualReviewItem>,
+    pub manual_reviews: Vec<ManualReviewItem>,
 }
 
-/// Represents a department that needs manual review.
+/// Represents a department or board that needs manual review.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManualReviewItem {
     pub campus: String,
     pub name: String,
     pub url: String,
     pub reason: String,
+    /// Auto-detected board candidates, best first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<Board>,
 }
 
 /// A summarized notice for index files.
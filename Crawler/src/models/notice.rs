@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 /// A notice fetched from a board.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Notice {
     /// Campus name
     pub campus: String,
@@ -45,6 +45,19 @@ pub struct Notice {
     /// Notice body content (HTML or text)
     #[serde(default)]
     pub body: String,
+
+    /// When the crawler collected the notice (RFC 3339), as opposed to `date`
+    #[serde(default)]
+    pub fetched_at: String,
+
+    /// Category assigned by a post-processing hook (unset = uncategorized)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Outer HTML of the list row the notice was parsed from, kept only with
+    /// `crawler.capture_row_html` for selector debugging
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_html: Option<String>,
 }
 
 impl Notice {
@@ -107,8 +120,9 @@ mod tests {
             author: "Admin".to_string(),
             date: "2024-01-01".to_string(),
             link: "https://example.com/notice/1".to_string(),
-            source_id: None,
             body: "<p>Hello, world!</p>".to_string(),
+            fetched_at: "2024-01-01T09:00:00Z".to_string(),
+            ..Notice::default()
         }
     }
 
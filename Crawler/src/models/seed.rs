@@ -54,6 +54,11 @@ pub struct CampusInfo {
 
     /// URL of the campus department listing page
     pub url: String,
+
+    /// Optional campus index listing department homepages, either an HTML
+    /// sitemap or an XML `sitemap.xml`; used instead of `url` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sitemap_url: Option<String>,
 }
 
 /// Mapping from board keyword to standardized ID.
@@ -103,10 +108,12 @@ impl Default for Seed {
                 CampusInfo {
                     name: "신촌캠퍼스".to_string(),
                     url: "https://www.yonsei.ac.kr/sc/186/subview.do".to_string(),
+                    sitemap_url: None,
                 },
                 CampusInfo {
                     name: "미래캠퍼스".to_string(),
                     url: "https://mirae.yonsei.ac.kr/wj/1413/subview.do".to_string(),
+                    sitemap_url: None,
                 },
             ],
             keywords: vec![
@@ -2,18 +2,27 @@
 
 //! CSS selectors for scraping a notice board.
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 /// CSS selectors for scraping a notice board.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The row, title and date selectors are required for HTML boards; JSON
+/// boards (`format = "json"`) may omit them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CmsSelectors {
-    /// Selector for each row/item in the notice list
-    pub row_selector: String,
+    /// Selector for each row/item in the notice list; a list combines rows
+    /// from several layouts (e.g. two tables) on one page, in list order
+    #[serde(default, skip_serializing_if = "RowSelector::is_empty")]
+    pub row_selector: RowSelector,
 
     /// Selector for the title element within a row
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub title_selector: String,
 
     /// Selector for the date element within a row
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub date_selector: String,
 
     /// Selector for the author element within a row
@@ -28,7 +37,8 @@ pub struct CmsSelectors {
     #[serde(default = "default_attr_name")]
     pub attr_name: String,
 
-    /// Optional selector for the link element (if different from title)
+    /// Optional selector for the link element (if unset, the first `<a>`
+    /// inside the title element, else the title element itself)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link_selector: Option<String>,
 }
@@ -40,7 +50,7 @@ fn default_attr_name() -> String {
 impl Default for CmsSelectors {
     fn default() -> Self {
         Self {
-            row_selector: "tr".to_string(),
+            row_selector: "tr".into(),
             title_selector: "a".to_string(),
             date_selector: "td:last-child".to_string(),
             author_selector: None,
@@ -54,7 +64,7 @@ impl Default for CmsSelectors {
 impl CmsSelectors {
     /// Create selectors from a CMS pattern.
     pub fn from_pattern(
-        row: impl Into<String>,
+        row: impl Into<RowSelector>,
         title: impl Into<String>,
         date: impl Into<String>,
         attr: impl Into<String>,
@@ -74,7 +84,7 @@ impl CmsSelectors {
     /// These are generic selectors that should work with most table-based boards.
     pub fn fallback() -> Self {
         Self {
-            row_selector: "table tr:has(a)".to_string(),
+            row_selector: "table tr:has(a)".into(),
             title_selector: "a".to_string(),
             date_selector: "td:last-child".to_string(),
             author_selector: None,
@@ -84,3 +94,80 @@ impl CmsSelectors {
         }
     }
 }
+
+/// One or more row selectors, written as a string or a list of strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum RowSelector {
+    Single(String),
+    List(Vec<String>),
+}
+
+impl RowSelector {
+    /// The selectors in the order their rows are extracted.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let list: &[String] = match self {
+            Self::Single(selector) => std::slice::from_ref(selector),
+            Self::List(selectors) => selectors,
+        };
+        list.iter().map(String::as_str)
+    }
+
+    /// Whether no (non-blank) selector is given.
+    pub fn is_empty(&self) -> bool {
+        self.iter().all(|selector| selector.trim().is_empty())
+    }
+}
+
+impl Default for RowSelector {
+    fn default() -> Self {
+        Self::Single(String::new())
+    }
+}
+
+impl From<String> for RowSelector {
+    fn from(selector: String) -> Self {
+        Self::Single(selector)
+    }
+}
+
+impl From<&str> for RowSelector {
+    fn from(selector: &str) -> Self {
+        Self::Single(selector.to_string())
+    }
+}
+
+impl PartialEq<&str> for RowSelector {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Self::Single(selector) if selector == other)
+    }
+}
+
+impl fmt::Display for RowSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.iter().collect::<Vec<_>>().join(", "))
+    }
+}
+
+/// JSONPath-like field mappings for boards whose list is a JSON API
+/// response (`format = "json"`), e.g. `items_path = "$.data.list"`.
+///
+/// Field paths are relative to each item.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct JsonPaths {
+    /// Path to the array of notice items
+    pub items_path: String,
+
+    /// Path to the title within an item
+    pub title_path: String,
+
+    /// Path to the date within an item
+    pub date_path: String,
+
+    /// Path to the link (or the id `link_regex`/`link_template` turn into one)
+    pub link_path: String,
+
+    /// Path to the author within an item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_path: Option<String>,
+}
@@ -0,0 +1,140 @@
+// src/models/state.rs
+
+//! Crawl state persisted between runs.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::models::Notice;
+use crate::utils::fs;
+
+/// Per-board bookkeeping carried from one crawl cycle to the next.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CrawlState {
+    /// Board state keyed by [`CrawlState::board_key`]
+    #[serde(default)]
+    pub boards: BTreeMap<String, BoardState>,
+}
+
+/// State of a single board.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BoardState {
+    /// When the board list was last fetched successfully
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_success: Option<DateTime<Utc>>,
+
+    /// Hash of the last fetched list page and the selectors applied to it
+    /// (only kept with `crawler.skip_unchanged`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+
+    /// Notices extracted from the page behind `content_hash`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notices: Vec<Notice>,
+
+    /// The notice each title was first seen with (only kept with
+    /// `filter.suppress_duplicate_titles_days`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub titles: BTreeMap<String, SeenTitle>,
+}
+
+/// The notice a board title was first seen with.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeenTitle {
+    /// [`Notice::canonical_id`] of that notice
+    pub notice_id: String,
+    pub first_seen: DateTime<Utc>,
+}
+
+impl CrawlState {
+    /// Load state from a JSON file; a missing file yields empty state.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save state as JSON.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::save_json(path, self)
+    }
+
+    /// Key for a board; board ids are only unique within a department.
+    pub fn board_key(department_id: &str, board_id: &str) -> String {
+        format!("{department_id}/{board_id}")
+    }
+
+    /// Record a successful fetch of a board.
+    pub fn record_success(&mut self, key: &str, at: DateTime<Utc>) {
+        self.boards.entry(key.to_string()).or_default().last_success = Some(at);
+    }
+
+    /// Prior notices of a board if its page hash is still `hash`.
+    pub fn unchanged_notices(&self, key: &str, hash: &str) -> Option<Vec<Notice>> {
+        self.boards
+            .get(key)
+            .filter(|board| board.content_hash.as_deref() == Some(hash))
+            .map(|board| board.notices.clone())
+    }
+
+    /// Remember the page hash and the notices extracted from it.
+    pub fn record_content(&mut self, key: &str, hash: String, notices: Vec<Notice>) {
+        let board = self.boards.entry(key.to_string()).or_default();
+        board.content_hash = Some(hash);
+        board.notices = notices;
+    }
+
+    /// Whether `notice` repeats the title of a different notice first seen
+    /// on its board less than `window` before `now`.
+    ///
+    /// Otherwise the notice becomes the title's reference, unless it already
+    /// is.
+    pub fn is_repeated_title(
+        &mut self,
+        notice: &Notice,
+        window: Duration,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let key = Self::board_key(&notice.department_id, &notice.board_id);
+        let titles = &mut self.boards.entry(key).or_default().titles;
+        let title = notice.title.trim();
+        let notice_id = notice.canonical_id();
+        match titles.get(title) {
+            Some(seen) if seen.notice_id == notice_id => false,
+            Some(seen) if now - seen.first_seen < window => true,
+            _ => {
+                titles.insert(
+                    title.to_string(),
+                    SeenTitle {
+                        notice_id,
+                        first_seen: now,
+                    },
+                );
+                false
+            }
+        }
+    }
+
+    /// Forget titles first seen `window` or more before `now`.
+    pub fn prune_titles(&mut self, window: Duration, now: DateTime<Utc>) {
+        for board in self.boards.values_mut() {
+            board
+                .titles
+                .retain(|_, seen| now - seen.first_seen < window);
+        }
+    }
+
+    /// Whether a board fetched successfully less than `min_interval_secs` ago.
+    pub fn in_cooldown(&self, key: &str, min_interval_secs: u64, now: DateTime<Utc>) -> bool {
+        self.boards
+            .get(key)
+            .and_then(|board| board.last_success)
+            .is_some_and(|last| now - last < Duration::seconds(min_interval_secs as i64))
+    }
+}
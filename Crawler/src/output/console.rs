@@ -0,0 +1,352 @@
+// src/output/console.rs
+
+//! Console rendering of notices.
+
+use std::io::{self, IsTerminal, Write};
+
+use owo_colors::OwoColorize;
+use unicode_width::UnicodeWidthStr;
+
+use crate::error::Result;
+use crate::models::{ColorMode, ConsoleFormat, Notice, OutputConfig, format_count};
+use crate::output::OutputSink;
+use crate::utils::log;
+
+/// Table column headers (department, board, title, date).
+const TABLE_HEADERS: [&str; 4] = ["Department", "Board", "Title", "Date"];
+
+/// Styling applied to notice fields on the console.
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    enabled: bool,
+}
+
+impl Palette {
+    /// A palette that leaves text untouched.
+    pub fn plain() -> Self {
+        Self { enabled: false }
+    }
+
+    /// Resolve the palette for stdout from the configured color mode.
+    pub fn for_stdout(mode: ColorMode) -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Self::resolve(mode, io::stdout().is_terminal(), no_color)
+    }
+
+    /// Resolve the palette for an output with the given terminal state.
+    pub fn resolve(mode: ColorMode, is_tty: bool, no_color: bool) -> Self {
+        Self {
+            enabled: should_colorize(mode, is_tty, no_color),
+        }
+    }
+
+    fn department(&self, text: &str) -> String {
+        if self.enabled {
+            text.cyan().bold().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn date(&self, text: &str) -> String {
+        if self.enabled {
+            text.dimmed().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn link(&self, text: &str) -> String {
+        if self.enabled {
+            text.underline().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Decide whether to emit colors for the given mode and terminal state.
+pub fn should_colorize(mode: ColorMode, is_tty: bool, no_color: bool) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => is_tty && !no_color,
+    }
+}
+
+/// Prints notices to stdout.
+pub struct ConsoleSink {
+    output: OutputConfig,
+}
+
+impl ConsoleSink {
+    pub fn new(output: OutputConfig) -> Self {
+        Self { output }
+    }
+}
+
+impl OutputSink for ConsoleSink {
+    fn name(&self) -> &str {
+        "console"
+    }
+
+    fn write(&self, notices: &[Notice]) -> Result<()> {
+        present_notices_to_console(notices, &self.output);
+        Ok(())
+    }
+}
+
+/// Print notices to stdout using the configured console format.
+pub fn present_notices_to_console(notices: &[Notice], output: &OutputConfig) {
+    let palette = Palette::for_stdout(output.color);
+    if let Err(err) = write_notices(&mut io::stdout().lock(), notices, output, &palette) {
+        log::warn(&format!("Failed to print notices: {err}"));
+    }
+    log::info(&format_count(
+        &log::locale().messages.total_notices,
+        notices.len(),
+    ));
+}
+
+/// Write notices to `out`, one line each, in the configured console format.
+pub fn write_notices(
+    out: &mut impl Write,
+    notices: &[Notice],
+    output: &OutputConfig,
+    palette: &Palette,
+) -> io::Result<()> {
+    match output.console_format {
+        ConsoleFormat::Template => {
+            for notice in notices {
+                writeln!(
+                    out,
+                    "{}",
+                    format_notice_with(&output.notice_format, notice, palette)
+                )?;
+            }
+        }
+        ConsoleFormat::Table => {
+            for line in render_table_with(notices, palette) {
+                writeln!(out, "{line}")?;
+            }
+        }
+    }
+    out.flush()
+}
+
+/// Render a notice using a `notice_format` template.
+pub fn format_notice(template: &str, notice: &Notice) -> String {
+    format_notice_with(template, notice, &Palette::plain())
+}
+
+/// Render a notice using a `notice_format` template and a palette.
+///
+/// Any serialized `Notice` field is addressable as `{field_name}` (plus the
+/// `{dept_name}` alias); fields a notice lacks render as empty. The template
+/// is scanned once, so field values containing placeholder-like text (e.g. a
+/// title of `{link}`) are inserted verbatim, never re-expanded.
+pub fn format_notice_with(template: &str, notice: &Notice, palette: &Palette) -> String {
+    let fields = match serde_json::to_value(notice) {
+        Ok(serde_json::Value::Object(fields)) => fields,
+        _ => serde_json::Map::new(),
+    };
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let name = &after[..end];
+        if name.contains('{') {
+            // Stray brace before a real placeholder: keep it literally
+            out.push('{');
+            rest = after;
+            continue;
+        }
+        out.push_str(&notice_field(name, &fields, palette));
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Value of a `notice_format` placeholder, looked up in the notice's fields.
+fn notice_field(
+    name: &str,
+    fields: &serde_json::Map<String, serde_json::Value>,
+    palette: &Palette,
+) -> String {
+    let key = match name {
+        "dept_name" => "department_name",
+        other => other,
+    };
+    let value = match fields.get(key) {
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(serde_json::Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    };
+    match key {
+        "department_name" => palette.department(&value),
+        "date" => palette.date(&value),
+        "link" => palette.link(&value),
+        _ => value,
+    }
+}
+
+/// Render notices as an aligned table, one line per row.
+pub fn render_table(notices: &[Notice]) -> Vec<String> {
+    render_table_with(notices, &Palette::plain())
+}
+
+/// Render notices as an aligned table, styling cells after padding.
+pub fn render_table_with(notices: &[Notice], palette: &Palette) -> Vec<String> {
+    let rows: Vec<[&str; 4]> = notices
+        .iter()
+        .map(|n| {
+            [
+                n.department_name.as_str(),
+                n.board_name.as_str(),
+                n.title.as_str(),
+                n.date.as_str(),
+            ]
+        })
+        .collect();
+
+    let mut widths = TABLE_HEADERS.map(display_width);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(cell));
+        }
+    }
+
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+    lines.push(render_row(&TABLE_HEADERS, &widths));
+    lines.push(
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    lines.extend(rows.iter().map(|row| {
+        let [dept, board, title, date] = *row;
+        [
+            palette.department(&pad(dept, widths[0])),
+            pad(board, widths[1]),
+            pad(title, widths[2]),
+            palette.date(date),
+        ]
+        .join(" | ")
+    }));
+    lines
+}
+
+fn render_row(cells: &[&str; 4], widths: &[usize; 4]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| pad(cell, *width))
+        .collect::<Vec<_>>()
+        .join(" | ")
+        .trim_end()
+        .to_string()
+}
+
+/// Pad `text` with spaces up to `width` terminal columns.
+fn pad(text: &str, width: usize) -> String {
+    let fill = width.saturating_sub(display_width(text));
+    format!("{text}{}", " ".repeat(fill))
+}
+
+/// Terminal column width of a string (CJK characters occupy two columns).
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(dept: &str, title: &str) -> Notice {
+        Notice {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: "dept".to_string(),
+            department_name: dept.to_string(),
+            board_id: "notice".to_string(),
+            board_name: "공지".to_string(),
+            title: title.to_string(),
+            date: "2024-01-01".to_string(),
+            link: "https://example.com/1".to_string(),
+            ..Notice::default()
+        }
+    }
+
+    #[test]
+    fn test_never_and_non_tty_produce_plain_output() {
+        assert!(!should_colorize(ColorMode::Never, true, false));
+        assert!(!should_colorize(ColorMode::Auto, false, false));
+        assert!(!should_colorize(ColorMode::Auto, true, true));
+        assert!(should_colorize(ColorMode::Always, false, true));
+
+        let printed = |mode, is_tty, console_format| {
+            let output = OutputConfig {
+                color: mode,
+                console_format,
+                notice_format: "{dept_name} {title} {date} {link}".to_string(),
+                ..OutputConfig::default()
+            };
+            let mut buf = Vec::new();
+            let palette = Palette::resolve(mode, is_tty, false);
+            write_notices(&mut buf, &[notice("CS", "A")], &output, &palette).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        for format in [ConsoleFormat::Template, ConsoleFormat::Table] {
+            assert!(!printed(ColorMode::Never, true, format).contains("\x1b["));
+            assert!(!printed(ColorMode::Auto, false, format).contains("\x1b["));
+            assert!(printed(ColorMode::Always, false, format).contains("\x1b["));
+            assert!(printed(ColorMode::Auto, true, format).contains("\x1b["));
+        }
+    }
+
+    #[test]
+    fn test_format_notice_does_not_reexpand_field_values() {
+        let rendered = format_notice("{title} -> {link}", &notice("CS", "{link}"));
+        assert_eq!(rendered, "{link} -> https://example.com/1");
+        assert_eq!(format_notice("{title", &notice("CS", "A")), "{title");
+        assert_eq!(format_notice("{ {title}", &notice("CS", "A")), "{ A");
+    }
+
+    #[test]
+    fn test_format_notice_resolves_any_field() {
+        let mut item = notice("CS", "A");
+        item.author = "학사팀".to_string();
+        assert_eq!(
+            format_notice(
+                "[{campus}/{department_id}] {title} ({author}){source_id}",
+                &item
+            ),
+            "[신촌캠퍼스/dept] A (학사팀)"
+        );
+
+        item.source_id = Some("310".to_string());
+        assert_eq!(format_notice("#{source_id}", &item), "#310");
+    }
+
+    #[test]
+    fn test_display_width_mixed_ascii_cjk() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("공지"), 4);
+        assert_eq!(display_width("CS학과"), 6);
+    }
+
+    #[test]
+    fn test_render_table_aligns_cjk_columns() {
+        let lines = render_table(&[notice("컴퓨터과학과", "A"), notice("CS", "장학 안내")]);
+        let title_col = |line: &str| display_width(&line[..line.rfind(" | ").unwrap()]);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(title_col(&lines[2]), title_col(&lines[3]));
+    }
+}
@@ -0,0 +1,623 @@
+// src/output/json.rs
+
+//! JSON file output, as one combined file or partitioned by board or date.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use chrono::{DateTime, Utc};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::models::{EmptyBoard, GroupBy, JsonFormat, Notice, PathsConfig};
+use crate::output::OutputSink;
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::date::parse_date;
+use crate::utils::fs;
+
+/// File name for notices whose date cannot be parsed under [`GroupBy::Date`].
+const UNDATED_FILE: &str = "undated.json";
+
+/// Most threads writing partitioned files at once.
+const MAX_WRITE_THREADS: usize = 8;
+
+/// Writes notices as JSON arrays.
+///
+/// With [`GroupBy::Flat`] everything goes to `path`; other groupings write
+/// one file per group next to it. In append mode each file keeps the notices
+/// it already held. Partitioned files are written on several threads. With
+/// gzip enabled every file gets a `.gz` suffix.
+pub struct JsonSink {
+    path: PathBuf,
+    pretty: bool,
+    group_by: GroupBy,
+    append: bool,
+    format: JsonFormat,
+    write_threads: usize,
+    empty_boards: Vec<EmptyBoard>,
+    gzip: bool,
+    clock: Arc<dyn Clock>,
+}
+
+/// A JSON file written with [`JsonFormat::CombinedWithMeta`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonEnvelope<T> {
+    pub generated_at: DateTime<Utc>,
+    pub crawler_version: String,
+    pub count: usize,
+    pub notices: Vec<T>,
+}
+
+/// An existing output file in either [`JsonFormat`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredNotices {
+    Array(Vec<Notice>),
+    Envelope(JsonEnvelope<Notice>),
+}
+
+impl StoredNotices {
+    fn into_notices(self) -> Vec<Notice> {
+        match self {
+            Self::Array(notices) => notices,
+            Self::Envelope(envelope) => envelope.notices,
+        }
+    }
+}
+
+impl JsonSink {
+    pub fn new(path: impl Into<PathBuf>, pretty: bool) -> Self {
+        Self {
+            path: path.into(),
+            pretty,
+            group_by: GroupBy::Flat,
+            append: false,
+            format: JsonFormat::Array,
+            write_threads: thread::available_parallelism()
+                .map_or(1, |n| n.get())
+                .min(MAX_WRITE_THREADS),
+            empty_boards: Vec::new(),
+            gzip: false,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Stamp [`JsonEnvelope::generated_at`] from `clock` (the system clock
+    /// otherwise).
+    pub fn with_clock(self, clock: Arc<dyn Clock>) -> Self {
+        Self { clock, ..self }
+    }
+
+    /// Gzip each file, appending `.gz` to its name.
+    pub fn with_gzip(self, gzip: bool) -> Self {
+        Self { gzip, ..self }
+    }
+
+    /// Also write empty files for `boards` under [`GroupBy::BoardTree`].
+    pub fn with_empty_boards(self, empty_boards: Vec<EmptyBoard>) -> Self {
+        Self {
+            empty_boards,
+            ..self
+        }
+    }
+
+    /// Merge into existing files instead of overwriting them.
+    pub fn with_append(self, append: bool) -> Self {
+        Self { append, ..self }
+    }
+
+    /// Write partitioned files on up to `threads` threads (1 = sequential).
+    pub fn with_write_threads(self, threads: usize) -> Self {
+        Self {
+            write_threads: threads.max(1),
+            ..self
+        }
+    }
+
+    /// Write each file in `format`.
+    pub fn with_format(self, format: JsonFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Partition output files by `group_by`.
+    pub fn with_group_by(self, group_by: GroupBy) -> Self {
+        Self { group_by, ..self }
+    }
+
+    /// Map each output file to the notices it holds.
+    fn partition<'a>(&self, notices: &'a [Notice]) -> BTreeMap<PathBuf, Vec<&'a Notice>> {
+        let dir = self.path.parent().unwrap_or(Path::new(""));
+        let mut files: BTreeMap<PathBuf, Vec<&Notice>> = BTreeMap::new();
+        let board_file = |campus: &str, department_id: &str, board_id: &str| {
+            dir.join(path_component(campus))
+                .join(path_component(department_id))
+                .join(format!("{}.json", path_component(board_id)))
+        };
+        if self.group_by == GroupBy::BoardTree {
+            for board in &self.empty_boards {
+                files.insert(
+                    self.file_path(board_file(
+                        &board.campus,
+                        &board.department_id,
+                        &board.board_id,
+                    )),
+                    Vec::new(),
+                );
+            }
+        }
+        for notice in notices {
+            let path = match self.group_by {
+                GroupBy::Flat => self.path.clone(),
+                GroupBy::BoardTree => {
+                    board_file(&notice.campus, &notice.department_id, &notice.board_id)
+                }
+                GroupBy::Date => dir.join(
+                    parse_date(&notice.date)
+                        .map_or_else(|| UNDATED_FILE.to_string(), |date| format!("{date}.json")),
+                ),
+            };
+            files.entry(self.file_path(path)).or_default().push(notice);
+        }
+        files
+    }
+
+    /// `path` with the `.gz` suffix when gzip is enabled.
+    fn file_path(&self, path: PathBuf) -> PathBuf {
+        if !self.gzip {
+            return path;
+        }
+        let mut name = path.into_os_string();
+        name.push(".gz");
+        PathBuf::from(name)
+    }
+
+    fn write_file(&self, path: &Path, notices: &[&Notice]) -> Result<()> {
+        if self.append
+            && let Some(existing) = load_stored(path)?
+        {
+            let merged = merge_notices(notices, &existing.into_notices());
+            return self.write_file_raw(path, &merged.iter().collect::<Vec<_>>());
+        }
+        self.write_file_raw(path, notices)
+    }
+
+    fn write_file_raw(&self, path: &Path, notices: &[&Notice]) -> Result<()> {
+        match self.format {
+            JsonFormat::Array => self.save(path, &notices),
+            JsonFormat::CombinedWithMeta => self.save(
+                path,
+                &JsonEnvelope {
+                    generated_at: self.clock.now(),
+                    crawler_version: env!("CARGO_PKG_VERSION").to_string(),
+                    count: notices.len(),
+                    notices: notices.to_vec(),
+                },
+            ),
+        }
+    }
+
+    fn save<T: Serialize>(&self, path: &Path, data: &T) -> Result<()> {
+        if self.gzip {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let file = BufWriter::new(std::fs::File::create(path)?);
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            if self.pretty {
+                serde_json::to_writer_pretty(&mut encoder, data)?;
+            } else {
+                serde_json::to_writer(&mut encoder, data)?;
+            }
+            encoder.finish()?.flush()?;
+            return Ok(());
+        }
+        if self.pretty {
+            fs::save_json(path, data)
+        } else {
+            fs::write(path, serde_json::to_vec(data)?)
+        }
+    }
+}
+
+impl OutputSink for JsonSink {
+    fn name(&self) -> &str {
+        "json"
+    }
+
+    fn files(&self, notices: &[Notice]) -> Vec<PathBuf> {
+        let files: Vec<_> = self.partition(notices).into_keys().collect();
+        if files.is_empty() && self.group_by == GroupBy::Flat {
+            return vec![self.file_path(self.path.clone())];
+        }
+        files
+    }
+
+    fn write(&self, notices: &[Notice]) -> Result<()> {
+        let files = self.partition(notices);
+        if files.is_empty() && self.group_by == GroupBy::Flat {
+            // Keep the combined file current even when a run finds nothing
+            return self.write_file(&self.file_path(self.path.clone()), &[]);
+        }
+        let threads = self.write_threads.min(files.len());
+        if threads <= 1 {
+            for (path, group) in files {
+                self.write_file(&path, &group)?;
+            }
+            return Ok(());
+        }
+
+        // Workers pull files off a shared queue; the first error stops them.
+        // Sibling files share parent directories, which is safe because
+        // `create_dir_all` treats a concurrently created directory as success.
+        let queue = Mutex::new(files.into_iter());
+        let first_error = Mutex::new(None);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    loop {
+                        if first_error
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .is_some()
+                        {
+                            return;
+                        }
+                        let next = queue.lock().unwrap_or_else(|e| e.into_inner()).next();
+                        let Some((path, group)) = next else {
+                            return;
+                        };
+                        if let Err(err) = self.write_file(&path, &group) {
+                            first_error
+                                .lock()
+                                .unwrap_or_else(|e| e.into_inner())
+                                .get_or_insert(err);
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+        match first_error.into_inner().unwrap_or_else(|e| e.into_inner()) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Read a JSON output file written in either [`JsonFormat`], gunzipping
+/// `.gz` files.
+pub fn read_notices(path: &Path) -> Result<Vec<Notice>> {
+    Ok(load_stored(path)?.map_or_else(Vec::new, StoredNotices::into_notices))
+}
+
+/// `.json` and `.json.gz` files under `dir`, recursively, in path order.
+pub fn find_json_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(".json") || name.ends_with(".json.gz"))
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Like [`find_json_files`], minus the crawler's own JSON files: the run
+/// manifest and state file (matched relative to `dir`) and debug
+/// `.headers.json` dumps.
+pub fn find_notice_files(dir: &Path, paths: &PathsConfig) -> Result<Vec<PathBuf>> {
+    let own: Vec<&String> = paths
+        .manifest_file
+        .iter()
+        .chain([&paths.state_file])
+        .collect();
+    Ok(find_json_files(dir)?
+        .into_iter()
+        .filter(|path| {
+            let relative = path.strip_prefix(dir).unwrap_or(path);
+            !own.iter().any(|file| relative == Path::new(file))
+                && !path.to_string_lossy().ends_with(".headers.json")
+        })
+        .collect())
+}
+
+/// Parse an existing output file, or `None` if it does not exist.
+fn load_stored(path: &Path) -> Result<Option<StoredNotices>> {
+    if path.extension().is_none_or(|ext| ext != "gz") {
+        return fs::load_json_optional(path);
+    }
+    let compressed = match std::fs::read(path) {
+        Ok(compressed) => compressed,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let mut json = Vec::new();
+    GzDecoder::new(compressed.as_slice()).read_to_end(&mut json)?;
+    Ok(Some(serde_json::from_slice(&json)?))
+}
+
+/// New notices first, then existing ones not superseded by a new notice.
+///
+/// Notices are matched by link, or by canonical id when the link is empty.
+fn merge_notices(new: &[&Notice], existing: &[Notice]) -> Vec<Notice> {
+    let key = |notice: &Notice| {
+        if notice.link.is_empty() {
+            notice.canonical_id()
+        } else {
+            notice.link.clone()
+        }
+    };
+    let mut seen = HashSet::new();
+    new.iter()
+        .copied()
+        .chain(existing)
+        .filter(|notice| seen.insert(key(notice)))
+        .cloned()
+        .collect()
+}
+
+/// Make an id usable as a single path component.
+fn path_component(value: &str) -> String {
+    let cleaned = value.replace(['/', '\\'], "_");
+    match cleaned.as_str() {
+        "" | "." | ".." => "_".to_string(),
+        _ => cleaned,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::clock::FixedClock;
+    use tempfile::tempdir;
+
+    fn notice(board_id: &str, date: &str, link: &str) -> Notice {
+        Notice {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: "cs".to_string(),
+            department_name: "컴퓨터과학과".to_string(),
+            board_id: board_id.to_string(),
+            board_name: board_id.to_string(),
+            title: link.to_string(),
+            date: date.to_string(),
+            link: link.to_string(),
+            ..Notice::default()
+        }
+    }
+
+    fn read(path: &Path) -> Vec<Notice> {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_json_sink_writes_array() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("out/notices.json");
+        JsonSink::new(&path, false).write(&[]).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_combined_with_meta_wraps_notices() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("notices.json");
+        let generated_at = "2024-03-01T09:00:00Z".parse().unwrap();
+        let sink = JsonSink::new(&path, true)
+            .with_format(JsonFormat::CombinedWithMeta)
+            .with_append(true)
+            .with_clock(Arc::new(FixedClock(generated_at)));
+        sink.write(&[
+            notice("notice", "2024-01-15", "https://a/1"),
+            notice("notice", "2024-01-16", "https://a/2"),
+        ])
+        .unwrap();
+        // Appending reads the envelope back
+        sink.write(&[notice("notice", "2024-01-17", "https://a/3")])
+            .unwrap();
+
+        let envelope: JsonEnvelope<Notice> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(envelope.generated_at, generated_at);
+        assert_eq!(envelope.count, 3);
+        assert_eq!(envelope.count, envelope.notices.len());
+        assert_eq!(envelope.crawler_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(envelope.notices[0].link, "https://a/3");
+    }
+
+    #[test]
+    fn test_empty_board_writes_empty_array() {
+        let dir = tempdir().unwrap();
+        let empty = EmptyBoard {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: "cs".to_string(),
+            board_id: "job".to_string(),
+        };
+        let notices = [notice("notice", "2024-01-15", "https://a/1")];
+        let board_dir = dir.path().join("신촌캠퍼스/cs");
+
+        // Without the option the empty board leaves no trace
+        let sink =
+            JsonSink::new(dir.path().join("notices.json"), false).with_group_by(GroupBy::BoardTree);
+        sink.write(&notices).unwrap();
+        assert!(!board_dir.join("job.json").exists());
+
+        let sink = sink.with_empty_boards(vec![empty]);
+        assert_eq!(sink.files(&notices).len(), 2);
+        sink.write(&notices).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(board_dir.join("job.json")).unwrap(),
+            "[]"
+        );
+        assert_eq!(read(&board_dir.join("notice.json")).len(), 1);
+    }
+
+    #[test]
+    fn test_gzip_output_round_trips() {
+        let dir = tempdir().unwrap();
+        let sink = JsonSink::new(dir.path().join("notices.json"), true)
+            .with_group_by(GroupBy::BoardTree)
+            .with_gzip(true)
+            .with_append(true);
+        let notices = [
+            notice("notice", "2024-01-15", "https://a/1"),
+            notice("notice", "2024-01-16", "https://a/2"),
+        ];
+        sink.write(&notices[..1]).unwrap();
+        // Append mode reads the gzipped file back before merging
+        sink.write(&notices[1..]).unwrap();
+
+        let path = dir.path().join("신촌캠퍼스/cs/notice.json.gz");
+        assert_eq!(sink.files(&notices)[0], path);
+        let read_back = read_notices(&path).unwrap();
+        let links: Vec<&str> = read_back.iter().map(|n| n.link.as_str()).collect();
+        assert_eq!(links, ["https://a/2", "https://a/1"]);
+        assert_eq!(read_back[1], notices[0]);
+    }
+
+    #[test]
+    fn test_group_by_date_writes_one_file_per_date() {
+        let dir = tempdir().unwrap();
+        let sink =
+            JsonSink::new(dir.path().join("notices.json"), false).with_group_by(GroupBy::Date);
+        let notices = [
+            notice("notice", "2024.01.15", "https://a/1"),
+            notice("job", "2024-01-15", "https://a/2"),
+            notice("notice", "2024-01-16", "https://a/3"),
+            notice("notice", "상시", "https://a/4"),
+        ];
+
+        sink.write(&notices).unwrap();
+
+        assert_eq!(read(&dir.path().join("2024-01-15.json")).len(), 2);
+        assert_eq!(read(&dir.path().join("2024-01-16.json")).len(), 1);
+        assert_eq!(
+            read(&dir.path().join("undated.json"))[0].link,
+            "https://a/4"
+        );
+        assert!(!dir.path().join("notices.json").exists());
+    }
+
+    #[test]
+    fn test_append_mode_merges_runs_without_duplicates() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("notices.json");
+        let sink = JsonSink::new(&path, true).with_append(true);
+
+        // First run: no existing file
+        sink.write(&[
+            notice("notice", "2024-01-14", "https://a/1"),
+            notice("notice", "2024-01-15", "https://a/2"),
+        ])
+        .unwrap();
+        let mut updated = notice("notice", "2024-01-15", "https://a/2");
+        updated.title = "수정된 제목".to_string();
+        sink.write(&[notice("notice", "2024-01-16", "https://a/3"), updated])
+            .unwrap();
+
+        let merged = read(&path);
+        let links: Vec<_> = merged.iter().map(|n| n.link.as_str()).collect();
+        assert_eq!(links, ["https://a/3", "https://a/2", "https://a/1"]);
+        assert_eq!(merged[1].title, "수정된 제목");
+
+        // Without append mode the file is replaced
+        JsonSink::new(&path, true)
+            .write(&[notice("notice", "2024-01-17", "https://a/4")])
+            .unwrap();
+        assert_eq!(read(&path).len(), 1);
+    }
+
+    #[test]
+    fn test_group_by_board_tree() {
+        let dir = tempdir().unwrap();
+        let sink =
+            JsonSink::new(dir.path().join("notices.json"), true).with_group_by(GroupBy::BoardTree);
+
+        sink.write(&[
+            notice("notice", "2024-01-15", "https://a/1"),
+            notice("job", "2024-01-15", "https://a/2"),
+        ])
+        .unwrap();
+
+        let board_dir = dir.path().join("신촌캠퍼스").join("cs");
+        assert_eq!(read(&board_dir.join("notice.json")).len(), 1);
+        assert_eq!(read(&board_dir.join("job.json")).len(), 1);
+    }
+
+    /// Every file under `dir` with its contents, by relative path.
+    fn tree(dir: &Path) -> BTreeMap<PathBuf, String> {
+        let mut files = BTreeMap::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            for entry in std::fs::read_dir(&current).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else {
+                    let content = std::fs::read_to_string(&path).unwrap();
+                    files.insert(path.strip_prefix(dir).unwrap().to_path_buf(), content);
+                }
+            }
+        }
+        files
+    }
+
+    #[test]
+    fn test_parallel_writes_match_sequential() {
+        let notices: Vec<Notice> = (0..200)
+            .map(|idx| {
+                let mut notice = notice(
+                    &format!("board{}", idx % 40),
+                    "2024-01-15",
+                    &format!("https://a/{idx}"),
+                );
+                notice.department_id = format!("dept{}", idx % 4);
+                notice
+            })
+            .collect();
+        let write = |threads: usize| {
+            let dir = tempdir().unwrap();
+            JsonSink::new(dir.path().join("notices.json"), true)
+                .with_group_by(GroupBy::BoardTree)
+                .with_write_threads(threads)
+                .write(&notices)
+                .unwrap();
+            tree(dir.path())
+        };
+
+        let sequential = write(1);
+        assert_eq!(sequential.len(), 40);
+        assert_eq!(write(8), sequential);
+    }
+
+    #[test]
+    fn test_parallel_write_reports_failure() {
+        let dir = tempdir().unwrap();
+        // A file where a campus directory must go
+        std::fs::write(dir.path().join("신촌캠퍼스"), "").unwrap();
+        let notices: Vec<Notice> = (0..20)
+            .map(|idx| notice(&format!("board{idx}"), "", &format!("https://a/{idx}")))
+            .collect();
+
+        let result = JsonSink::new(dir.path().join("notices.json"), false)
+            .with_group_by(GroupBy::BoardTree)
+            .with_write_threads(4)
+            .write(&notices);
+        assert!(result.is_err());
+    }
+}
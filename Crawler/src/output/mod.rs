@@ -0,0 +1,247 @@
+// src/output/mod.rs
+
+//! Output sinks for crawled notices.
+//!
+//! Each output format implements [`OutputSink`]; [`build_sinks`] assembles the
+//! enabled sinks from [`OutputConfig`](crate::models::OutputConfig) so adding a
+//! format never touches the crawl pipeline.
+
+pub mod console;
+pub mod json;
+pub mod opml;
+#[cfg(feature = "warc")]
+pub mod warc;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
+
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::models::{Config, EmptyBoard, Notice, SortBy};
+use crate::utils::clock::Clock;
+use crate::utils::date::parse_date;
+#[cfg(not(feature = "xlsx"))]
+use crate::utils::log;
+
+pub use console::{ConsoleSink, present_notices_to_console};
+pub use json::{JsonEnvelope, JsonSink};
+pub use opml::write_opml;
+#[cfg(feature = "warc")]
+pub use warc::{ArchivingFetcher, WarcWriter};
+#[cfg(feature = "xlsx")]
+pub use xlsx::XlsxSink;
+
+/// A destination for crawled notices.
+pub trait OutputSink: Send + Sync {
+    /// Short name used in logs.
+    fn name(&self) -> &str;
+
+    /// Write the given notices to this sink.
+    fn write(&self, notices: &[Notice]) -> Result<()>;
+
+    /// Files that writing `notices` produces (none for non-file sinks).
+    fn files(&self, _notices: &[Notice]) -> Vec<PathBuf> {
+        Vec::new()
+    }
+}
+
+/// Build the sinks enabled by `config`, resolving file paths against `base`.
+///
+/// `empty_boards` get empty files when `output.write_empty_boards` is set;
+/// file metadata is timestamped from the run's `clock`.
+pub fn build_sinks(
+    config: &Config,
+    base: &Path,
+    empty_boards: &[EmptyBoard],
+    clock: &Arc<dyn Clock>,
+) -> Vec<Box<dyn OutputSink>> {
+    let mut sinks: Vec<Box<dyn OutputSink>> = Vec::new();
+    let output = &config.output;
+
+    if output.console_enabled {
+        sinks.push(Box::new(ConsoleSink::new(output.clone())));
+    }
+    if output.json_enabled {
+        sinks.push(Box::new(
+            JsonSink::new(
+                config.output_dir(base).join(&output.json_file),
+                output.json_pretty,
+            )
+            .with_group_by(output.group_by)
+            .with_append(output.append_mode)
+            .with_format(output.json_format)
+            .with_gzip(output.gzip_output)
+            .with_clock(Arc::clone(clock))
+            .with_empty_boards(if output.write_empty_boards {
+                empty_boards.to_vec()
+            } else {
+                Vec::new()
+            }),
+        ));
+    }
+    if let Some(path) = &output.xlsx_path {
+        #[cfg(feature = "xlsx")]
+        sinks.push(Box::new(
+            XlsxSink::new(config.output_dir(base).join(path)).with_sheets(output.xlsx_sheets),
+        ));
+        #[cfg(not(feature = "xlsx"))]
+        log::warn(&format!(
+            "output.xlsx_path ({path}) is set but this build lacks the `xlsx` feature; not writing it"
+        ));
+    }
+
+    sinks
+}
+
+/// Order notices by `output.sort_by`; ties keep their crawl order.
+pub fn sort_notices(notices: &mut [Notice], sort_by: SortBy) {
+    match sort_by {
+        SortBy::None => {}
+        SortBy::DateDesc => notices.sort_by_cached_key(|notice| {
+            let date = parse_date(&notice.date);
+            (date.is_none(), Reverse(date))
+        }),
+        SortBy::DateAsc => notices.sort_by_cached_key(|notice| {
+            let date = parse_date(&notice.date);
+            (date.is_none(), date)
+        }),
+        SortBy::Department => notices.sort_by_cached_key(|notice| {
+            let date = parse_date(&notice.date);
+            (
+                notice.department_name.clone(),
+                date.is_none(),
+                Reverse(date),
+            )
+        }),
+    }
+}
+
+/// Truncate notices to the global `output.limit`, returning how many were dropped.
+pub fn apply_limit<T>(notices: &mut Vec<T>, limit: Option<usize>) -> usize {
+    let Some(limit) = limit else {
+        return 0;
+    };
+    let dropped = notices.len().saturating_sub(limit);
+    notices.truncate(limit);
+    dropped
+}
+
+/// Write notices to every sink, stopping at the first failure.
+pub fn write_all(sinks: &[Box<dyn OutputSink>], notices: &[Notice]) -> Result<()> {
+    for sink in sinks {
+        sink.write(notices)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Records the titles of every notice it receives.
+    #[derive(Default, Clone)]
+    struct RecordingSink {
+        received: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl OutputSink for RecordingSink {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        fn write(&self, notices: &[Notice]) -> Result<()> {
+            let mut received = self.received.lock().unwrap();
+            received.extend(notices.iter().map(|n| n.title.clone()));
+            Ok(())
+        }
+    }
+
+    fn notice(title: &str) -> Notice {
+        Notice {
+            campus: "campus".to_string(),
+            department_id: "dept".to_string(),
+            department_name: "Dept".to_string(),
+            board_id: "board".to_string(),
+            board_name: "Board".to_string(),
+            title: title.to_string(),
+            date: "2024-01-01".to_string(),
+            link: format!("https://example.com/{title}"),
+            ..Notice::default()
+        }
+    }
+
+    #[test]
+    fn test_write_all_delivers_notices_to_sinks() {
+        let recorder = RecordingSink::default();
+        let sinks: Vec<Box<dyn OutputSink>> = vec![Box::new(recorder.clone())];
+        write_all(&sinks, &[notice("a"), notice("b")]).unwrap();
+        assert_eq!(*recorder.received.lock().unwrap(), vec!["a", "b"]);
+    }
+
+    fn dated(title: &str, department: &str, date: &str) -> Notice {
+        Notice {
+            department_name: department.to_string(),
+            date: date.to_string(),
+            ..notice(title)
+        }
+    }
+
+    fn sorted_titles(sort_by: SortBy) -> Vec<String> {
+        let mut notices = vec![
+            dated("a", "수학과", "2024-03-02"),
+            dated("b", "물리학과", ""),
+            dated("c", "컴퓨터과학과", "2024.03.05"),
+            dated("d", "물리학과", "2024-02-28"),
+        ];
+        sort_notices(&mut notices, sort_by);
+        notices.into_iter().map(|n| n.title).collect()
+    }
+
+    #[test]
+    fn test_sort_notices_none_keeps_crawl_order() {
+        assert_eq!(sorted_titles(SortBy::None), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_sort_notices_by_date_puts_undated_last() {
+        assert_eq!(sorted_titles(SortBy::DateDesc), ["c", "a", "d", "b"]);
+        assert_eq!(sorted_titles(SortBy::DateAsc), ["d", "a", "c", "b"]);
+    }
+
+    #[test]
+    fn test_sort_notices_by_department_then_newest() {
+        assert_eq!(sorted_titles(SortBy::Department), ["d", "b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_apply_limit_truncates_to_n() {
+        let mut notices = vec![notice("a"), notice("b"), notice("c")];
+        assert_eq!(apply_limit(&mut notices, None), 0);
+        assert_eq!(notices.len(), 3);
+
+        assert_eq!(apply_limit(&mut notices, Some(2)), 1);
+        let titles: Vec<_> = notices.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, ["a", "b"]);
+
+        assert_eq!(apply_limit(&mut notices, Some(5)), 0);
+        assert_eq!(notices.len(), 2);
+    }
+
+    #[test]
+    fn test_build_sinks_follows_config() {
+        let mut config = Config::default();
+        let clock: Arc<dyn Clock> = Arc::new(crate::utils::clock::SystemClock);
+        assert!(build_sinks(&config, Path::new("."), &[], &clock).is_empty());
+
+        config.output.console_enabled = true;
+        config.output.json_enabled = true;
+        let names: Vec<String> = build_sinks(&config, Path::new("."), &[], &clock)
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["console", "json"]);
+    }
+}
@@ -0,0 +1,204 @@
+// src/output/opml.rs
+
+//! OPML outline of per-board feeds.
+//!
+//! Groups boards as campus → department → board outlines so all feeds can be
+//! imported into a feed reader at once. Feed locations come from the
+//! `output.opml_feed_url` template.
+
+use std::path::Path;
+
+use crate::error::Result;
+use crate::models::Campus;
+use crate::utils::fs;
+use crate::utils::url::encode_path_segment;
+
+/// Render an OPML 2.0 document with one outline per board.
+///
+/// `feed_url` is a template with `{campus}`, `{department_id}` and
+/// `{board_id}` placeholders giving each board's feed URL or path. Values are
+/// percent-encoded as path segments and never re-expanded.
+pub fn render_opml(campuses: &[Campus], title: &str, feed_url: &str) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<opml version=\"2.0\">\n");
+    out.push_str(&format!(
+        "  <head>\n    <title>{}</title>\n  </head>\n",
+        escape(title)
+    ));
+    out.push_str("  <body>\n");
+
+    for campus in campuses {
+        out.push_str(&format!(
+            "    <outline text=\"{}\">\n",
+            escape(&campus.campus)
+        ));
+        for dept_ref in campus.all_departments() {
+            let dept = dept_ref.dept;
+            if dept.boards.is_empty() {
+                continue;
+            }
+            out.push_str(&format!(
+                "      <outline text=\"{}\">\n",
+                escape(&dept.name)
+            ));
+            for board in &dept.boards {
+                let xml_url = fill_feed_url(feed_url, |name| match name {
+                    "campus" => Some(campus.campus.as_str()),
+                    "department_id" => Some(dept.id.as_str()),
+                    "board_id" => Some(board.id.as_str()),
+                    _ => None,
+                });
+                out.push_str(&format!(
+                    "        <outline type=\"rss\" text=\"{}\" title=\"{}\" xmlUrl=\"{}\" htmlUrl=\"{}\"/>\n",
+                    escape(&board.name),
+                    escape(&format!("{} {}", dept.name, board.name)),
+                    escape(&xml_url),
+                    escape(&board.url),
+                ));
+            }
+            out.push_str("      </outline>\n");
+        }
+        out.push_str("    </outline>\n");
+    }
+
+    out.push_str("  </body>\n</opml>\n");
+    out
+}
+
+/// Write the OPML outline to `path`.
+pub fn write_opml(path: &Path, campuses: &[Campus], title: &str, feed_url: &str) -> Result<()> {
+    fs::write(path, render_opml(campuses, title, feed_url))
+}
+
+/// Expand `{name}` placeholders in `template` in a single scan, encoding each
+/// value; unknown placeholders are kept literally.
+fn fill_feed_url<'a>(template: &str, value: impl Fn(&str) -> Option<&'a str>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let name = &after[..end];
+        match value(name) {
+            Some(value) => {
+                out.push_str(&encode_path_segment(value));
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Escape text for XML attribute and element content.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::{Html, Selector};
+
+    use super::*;
+    use crate::models::{Board, College, Department};
+
+    fn board(id: &str, name: &str) -> Board {
+        Board {
+            id: id.to_string(),
+            name: name.to_string(),
+            url: format!("https://cs.yonsei.ac.kr/{id}?a=1&b=2"),
+            ..Board::default()
+        }
+    }
+
+    #[test]
+    fn test_opml_has_one_outline_per_board() {
+        let campuses = vec![Campus {
+            campus: "신촌캠퍼스".to_string(),
+            enabled: true,
+            colleges: vec![College {
+                name: "공과대학".to_string(),
+                departments: vec![Department {
+                    id: "cs".to_string(),
+                    name: "컴퓨터과학과".to_string(),
+                    url: "https://cs.yonsei.ac.kr".to_string(),
+                    boards: vec![
+                        board("notice", "공지사항"),
+                        board("scholarship", "장학 & 채용"),
+                    ],
+                }],
+            }],
+            departments: vec![Department {
+                id: "empty".to_string(),
+                name: "No boards".to_string(),
+                url: "https://empty.yonsei.ac.kr".to_string(),
+                boards: Vec::new(),
+            }],
+        }];
+
+        let opml = render_opml(
+            &campuses,
+            "uRing feeds",
+            "feeds/{department_id}/{board_id}.xml",
+        );
+
+        let document = Html::parse_document(&opml);
+        let feeds: Vec<_> = document
+            .select(&Selector::parse("outline[type=rss]").unwrap())
+            .collect();
+        assert_eq!(feeds.len(), 2);
+        assert_eq!(feeds[0].attr("xmlurl"), Some("feeds/cs/notice.xml"));
+        assert_eq!(feeds[1].attr("text"), Some("장학 & 채용"));
+        assert_eq!(
+            feeds[1].attr("htmlurl"),
+            Some("https://cs.yonsei.ac.kr/scholarship?a=1&b=2")
+        );
+        assert!(opml.contains("장학 &amp; 채용"));
+        assert!(!opml.contains("No boards"));
+    }
+
+    #[test]
+    fn test_feed_url_values_are_encoded_and_not_reexpanded() {
+        let campuses = vec![Campus {
+            campus: "신촌캠퍼스".to_string(),
+            enabled: true,
+            departments: vec![Department {
+                id: "{board_id}".to_string(),
+                name: "컴퓨터과학과".to_string(),
+                url: "https://cs.yonsei.ac.kr".to_string(),
+                boards: vec![board("a/b", "공지사항")],
+            }],
+            ..Campus::default()
+        }];
+
+        let opml = render_opml(
+            &campuses,
+            "uRing feeds",
+            "https://feeds.example.com/{campus}/{department_id}/{board_id}.xml?v={unknown}",
+        );
+
+        let document = Html::parse_document(&opml);
+        let feed = document
+            .select(&Selector::parse("outline[type=rss]").unwrap())
+            .next()
+            .unwrap();
+        assert_eq!(
+            feed.attr("xmlurl"),
+            Some(
+                "https://feeds.example.com/%EC%8B%A0%EC%B4%8C%EC%BA%A0%ED%8D%BC%EC%8A%A4/%7Bboard_id%7D/a%2Fb.xml?v={unknown}"
+            )
+        );
+    }
+}
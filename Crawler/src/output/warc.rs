@@ -0,0 +1,400 @@
+// src/output/warc.rs
+
+//! WARC archive of fetched pages.
+//!
+//! [`ArchivingFetcher`] wraps any [`HtmlFetcher`] and appends a request and a
+//! response record per fetched page (board listings and notice details) to a
+//! WARC/1.1 file, so a crawl can be replayed or audited later.
+//!
+//! Request records carry the real method and form body. Response bodies are
+//! stored as received, before any text decoding, so they keep the charset
+//! their `Content-Type` names; the HTTP client has already undone any
+//! compression, so `Content-Encoding`, `Content-Length` and
+//! `Transfer-Encoding` are rewritten to describe the stored payload.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::models::HttpMethod;
+use crate::utils::clock::Clock;
+use crate::utils::http::{HtmlFetcher, HtmlResponse, RawResponse, RequestOptions};
+use crate::utils::{fs, log};
+
+/// Headers that describe the wire encoding rather than the stored body.
+const WIRE_HEADERS: [&str; 3] = ["content-encoding", "content-length", "transfer-encoding"];
+
+/// Appends WARC records to a file.
+#[derive(Debug)]
+pub struct WarcWriter {
+    file: Mutex<BufWriter<File>>,
+    /// Stamps each record's `WARC-Date`
+    clock: Arc<dyn Clock>,
+}
+
+impl WarcWriter {
+    /// Open (or create) `path` for appending and write a `warcinfo` record.
+    /// Records are dated by `clock`.
+    pub fn create(path: &Path, clock: Arc<dyn Clock>) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let writer = Self {
+            file: Mutex::new(BufWriter::new(file)),
+            clock,
+        };
+
+        let info = format!(
+            "software: uRing Crawler/{}\r\nformat: WARC File Format 1.1\r\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        writer.write_records(&[Record {
+            warc_type: "warcinfo",
+            target_uri: None,
+            content_type: "application/warc-fields",
+            block: info.into_bytes(),
+            concurrent_to: None,
+        }])?;
+        Ok(writer)
+    }
+
+    /// Append a request/response record pair for `response`, fetched with
+    /// `options`.
+    pub fn record(&self, response: &RawResponse, options: &RequestOptions) -> Result<()> {
+        let request_id = record_id();
+        let request = Record {
+            warc_type: "request",
+            target_uri: Some(&response.url),
+            content_type: "application/http; msgtype=request",
+            block: request_block(&response.url, options),
+            concurrent_to: None,
+        };
+        let response = Record {
+            warc_type: "response",
+            target_uri: Some(&response.url),
+            content_type: "application/http; msgtype=response",
+            block: response_block(response),
+            concurrent_to: Some(&request_id),
+        };
+
+        let date = self.clock.now();
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        request.write_to(&mut *file, &request_id, date)?;
+        response.write_to(&mut *file, &record_id(), date)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    fn write_records(&self, records: &[Record<'_>]) -> Result<()> {
+        let date = self.clock.now();
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        for record in records {
+            record.write_to(&mut *file, &record_id(), date)?;
+        }
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// [`HtmlFetcher`] that archives every successful response to a [`WarcWriter`].
+///
+/// Archiving failures are logged and never fail the fetch.
+pub struct ArchivingFetcher {
+    inner: Arc<dyn HtmlFetcher>,
+    writer: Arc<WarcWriter>,
+}
+
+impl ArchivingFetcher {
+    pub fn new(inner: Arc<dyn HtmlFetcher>, writer: Arc<WarcWriter>) -> Self {
+        Self { inner, writer }
+    }
+}
+
+#[async_trait]
+impl HtmlFetcher for ArchivingFetcher {
+    async fn fetch_html(&self, url: &str) -> Result<String> {
+        Ok(self.fetch_response(url).await?.body)
+    }
+
+    async fn fetch_bytes(&self, url: &str) -> Result<Bytes> {
+        Ok(self
+            .fetch_bytes_with(url, &RequestOptions::default())
+            .await?
+            .body)
+    }
+
+    async fn fetch_text(&self, url: &str) -> Result<String> {
+        self.inner.fetch_text(url).await
+    }
+
+    async fn fetch_response(&self, url: &str) -> Result<HtmlResponse> {
+        self.fetch_response_with(url, &RequestOptions::default())
+            .await
+    }
+
+    async fn fetch_response_with(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+    ) -> Result<HtmlResponse> {
+        Ok(self.fetch_bytes_with(url, options).await?.into_text())
+    }
+
+    async fn fetch_bytes_with(&self, url: &str, options: &RequestOptions) -> Result<RawResponse> {
+        let response = self.inner.fetch_bytes_with(url, options).await?;
+        if let Err(err) = self.writer.record(&response, options) {
+            log::warn(&format!("Failed to archive {url} to WARC: {err}"));
+        }
+        Ok(response)
+    }
+}
+
+struct Record<'a> {
+    warc_type: &'a str,
+    target_uri: Option<&'a str>,
+    content_type: &'a str,
+    block: Vec<u8>,
+    concurrent_to: Option<&'a str>,
+}
+
+impl Record<'_> {
+    fn write_to(&self, out: &mut impl Write, id: &str, date: DateTime<Utc>) -> std::io::Result<()> {
+        write!(out, "WARC/1.1\r\n")?;
+        write!(out, "WARC-Type: {}\r\n", self.warc_type)?;
+        write!(out, "WARC-Record-ID: {id}\r\n")?;
+        write!(
+            out,
+            "WARC-Date: {}\r\n",
+            date.to_rfc3339_opts(SecondsFormat::Secs, true)
+        )?;
+        if let Some(uri) = self.target_uri {
+            write!(out, "WARC-Target-URI: {uri}\r\n")?;
+        }
+        if let Some(concurrent) = self.concurrent_to {
+            write!(out, "WARC-Concurrent-To: {concurrent}\r\n")?;
+        }
+        write!(out, "Content-Type: {}\r\n", self.content_type)?;
+        write!(out, "Content-Length: {}\r\n\r\n", self.block.len())?;
+        out.write_all(&self.block)?;
+        write!(out, "\r\n\r\n")
+    }
+}
+
+fn record_id() -> String {
+    format!("<urn:uuid:{}>", Uuid::new_v4())
+}
+
+/// The request line, `Host` and (for form posts) the urlencoded body.
+///
+/// Credentials are left out of the archive.
+fn request_block(url: &str, options: &RequestOptions) -> Vec<u8> {
+    let (target, host) = match url::Url::parse(url) {
+        Ok(parsed) => {
+            let mut target = parsed.path().to_string();
+            if let Some(query) = parsed.query() {
+                target.push('?');
+                target.push_str(query);
+            }
+            (target, parsed.host_str().unwrap_or("").to_string())
+        }
+        Err(_) => (url.to_string(), String::new()),
+    };
+    let method = match options.method {
+        HttpMethod::Get => "GET",
+        HttpMethod::Post => "POST",
+    };
+    let mut block = format!("{method} {target} HTTP/1.1\r\nHost: {host}\r\n");
+    if let Some(user_agent) = &options.user_agent {
+        block.push_str(&format!("User-Agent: {user_agent}\r\n"));
+    }
+    let body = options.form.as_ref().map(|form| {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(form)
+            .finish()
+    });
+    if let Some(body) = &body {
+        block.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        block.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    block.push_str("\r\n");
+    block.push_str(body.as_deref().unwrap_or(""));
+    block.into_bytes()
+}
+
+fn response_block(response: &RawResponse) -> Vec<u8> {
+    let reason = StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or("");
+    let mut block = format!("HTTP/1.1 {} {reason}\r\n", response.status);
+    for (name, value) in &response.headers {
+        if !WIRE_HEADERS.contains(&name.as_str()) {
+            block.push_str(&format!("{name}: {value}\r\n"));
+        }
+    }
+    block.push_str(&format!("content-length: {}\r\n\r\n", response.body.len()));
+    let mut block = block.into_bytes();
+    block.extend_from_slice(&response.body);
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::error::AppError;
+    use crate::models::{Board, CmsSelectors, Config, Department, DepartmentRef};
+    use crate::services::NoticeCrawler;
+    use crate::utils::clock::{FixedClock, SystemClock};
+
+    const BOARD_URL: &str = "https://dept.yonsei.ac.kr/board/list.do";
+
+    struct FixtureFetcher(HashMap<String, String>);
+
+    #[async_trait]
+    impl HtmlFetcher for FixtureFetcher {
+        async fn fetch_html(&self, url: &str) -> Result<String> {
+            self.0
+                .get(url)
+                .cloned()
+                .ok_or_else(|| AppError::UpstreamHttp {
+                    url: url.to_string(),
+                    status: 404,
+                })
+        }
+
+        async fn fetch_response(&self, url: &str) -> Result<HtmlResponse> {
+            Ok(HtmlResponse {
+                url: url.to_string(),
+                status: 200,
+                headers: BTreeMap::from([
+                    ("content-encoding".to_string(), "gzip".to_string()),
+                    ("content-type".to_string(), "text/html".to_string()),
+                ]),
+                body: self.fetch_html(url).await?,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crawl_writes_response_record_per_page() {
+        let pages = HashMap::from([
+            (
+                BOARD_URL.to_string(),
+                r#"<table><tr><td class="subject"><a href="view.do?articleNo=1">개강 안내</a></td><td class="date">2024-03-01</td></tr>
+                   <tr><td class="subject"><a href="view.do?articleNo=2">휴강 안내</a></td><td class="date">2024-03-02</td></tr></table>"#
+                    .to_string(),
+            ),
+            (
+                "https://dept.yonsei.ac.kr/board/view.do?articleNo=1".to_string(),
+                r#"<div class="content">본문 1</div>"#.to_string(),
+            ),
+            (
+                "https://dept.yonsei.ac.kr/board/view.do?articleNo=2".to_string(),
+                r#"<div class="content">본문 2</div>"#.to_string(),
+            ),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("crawl.warc");
+        let clock = FixedClock(Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap());
+        let writer = Arc::new(WarcWriter::create(&path, Arc::new(clock)).unwrap());
+        let fetcher = ArchivingFetcher::new(Arc::new(FixtureFetcher(pages)), writer);
+
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+        let board = Board {
+            id: "notice".to_string(),
+            name: "공지사항".to_string(),
+            url: BOARD_URL.to_string(),
+            selectors: CmsSelectors {
+                row_selector: "tr".into(),
+                title_selector: "td.subject a".to_string(),
+                date_selector: "td.date".to_string(),
+                body_selector: Some("div.content".to_string()),
+                ..CmsSelectors::default()
+            },
+            ..Board::default()
+        };
+        let dept = Department {
+            id: "dept".to_string(),
+            name: "테스트학과".to_string(),
+            url: "https://dept.yonsei.ac.kr".to_string(),
+            boards: Vec::new(),
+        };
+        let context = DepartmentRef {
+            campus: "신촌캠퍼스",
+            college: None,
+            dept: &dept,
+        };
+        let notices = crawler.fetch(&board, context).await.unwrap();
+        assert_eq!(notices.len(), 2);
+
+        let warc = std::fs::read_to_string(&path).unwrap();
+        assert!(warc.starts_with("WARC/1.1\r\nWARC-Type: warcinfo\r\n"));
+        assert_eq!(warc.matches("WARC-Date: ").count(), 7);
+        assert_eq!(
+            warc.matches("WARC-Date: 2024-03-01T09:30:00Z\r\n").count(),
+            7
+        );
+        assert_eq!(warc.matches("WARC-Type: request\r\n").count(), 3);
+        assert_eq!(warc.matches("WARC-Type: response\r\n").count(), 3);
+        assert!(warc.contains(&format!("WARC-Target-URI: {BOARD_URL}\r\n")));
+        assert!(
+            warc.contains("GET /board/view.do?articleNo=2 HTTP/1.1\r\nHost: dept.yonsei.ac.kr\r\n")
+        );
+        assert!(!warc.contains("content-encoding"));
+        assert!(warc.contains("HTTP/1.1 200 OK\r\ncontent-type: text/html\r\n"));
+    }
+
+    #[test]
+    fn test_record_keeps_method_form_and_raw_body() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("crawl.warc");
+        let writer = WarcWriter::create(&path, Arc::new(SystemClock)).unwrap();
+        // EUC-KR "공지", which UTF-8 decoding would replace
+        let body = b"<p>\xB0\xF8\xC1\xF6</p>";
+        let response = RawResponse {
+            url: BOARD_URL.to_string(),
+            status: 200,
+            headers: BTreeMap::from([(
+                "content-type".to_string(),
+                "text/html; charset=euc-kr".to_string(),
+            )]),
+            body: Bytes::from_static(body),
+        };
+        let options = RequestOptions {
+            method: HttpMethod::Post,
+            form: Some(BTreeMap::from([
+                ("page".to_string(), "2".to_string()),
+                ("q".to_string(), "장학 금".to_string()),
+            ])),
+            ..RequestOptions::default()
+        };
+        writer.record(&response, &options).unwrap();
+
+        let warc = std::fs::read(&path).unwrap();
+        let contains = |needle: &[u8]| warc.windows(needle.len()).any(|w| w == needle);
+        let form = "page=2&q=%EC%9E%A5%ED%95%99+%EA%B8%88";
+        let request = format!(
+            "POST /board/list.do HTTP/1.1\r\nHost: dept.yonsei.ac.kr\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{form}",
+            form.len()
+        );
+        assert!(contains(request.as_bytes()));
+        assert!(contains(
+            b"content-length: 11\r\n\r\n<p>\xB0\xF8\xC1\xF6</p>"
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+// src/output/xlsx.rs
+
+//! Excel workbook output.
+//!
+//! Writes an `.xlsx` workbook with one worksheet per campus or per
+//! department. Each sheet has a frozen header row, one row per notice, links
+//! as hyperlinks and columns sized to their widest cell.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+
+use crate::error::Result;
+use crate::models::{Notice, XlsxSheets};
+use crate::output::OutputSink;
+use crate::output::console::display_width;
+use crate::utils::fs;
+
+/// Column headers, in cell order.
+const HEADERS: [&str; 8] = [
+    "Campus",
+    "College",
+    "Department",
+    "Board",
+    "Title",
+    "Author",
+    "Date",
+    "Link",
+];
+
+/// Zero-based index of the hyperlink column.
+const LINK_COLUMN: u16 = 7;
+
+/// Excel's limit on worksheet name length, in characters.
+const MAX_SHEET_NAME: usize = 31;
+
+/// Widest column written, in character units.
+const MAX_COLUMN_WIDTH: usize = 80;
+
+/// Writes notices to an `.xlsx` workbook.
+pub struct XlsxSink {
+    path: PathBuf,
+    sheets: XlsxSheets,
+}
+
+impl XlsxSink {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            sheets: XlsxSheets::default(),
+        }
+    }
+
+    /// Split worksheets by `sheets`.
+    pub fn with_sheets(self, sheets: XlsxSheets) -> Self {
+        Self { sheets, ..self }
+    }
+}
+
+impl OutputSink for XlsxSink {
+    fn name(&self) -> &str {
+        "xlsx"
+    }
+
+    fn write(&self, notices: &[Notice]) -> Result<()> {
+        fs::write(&self.path, render_workbook(notices, self.sheets)?)
+    }
+
+    fn files(&self, _notices: &[Notice]) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
+}
+
+/// Render the workbook for `notices` as `.xlsx` bytes.
+pub fn render_workbook(notices: &[Notice], sheets: XlsxSheets) -> Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let header = Format::new().set_bold();
+    for (name, rows) in sheet_groups(notices, sheets) {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(name)?;
+        write_sheet(worksheet, &rows, &header)?;
+    }
+    Ok(workbook.save_to_buffer()?)
+}
+
+/// Notices split into named worksheets, in sheet order.
+///
+/// An empty workbook still gets one (empty) sheet, since Excel cannot open
+/// a workbook without any.
+fn sheet_groups(notices: &[Notice], sheets: XlsxSheets) -> Vec<(String, Vec<&Notice>)> {
+    let mut groups: BTreeMap<&str, Vec<&Notice>> = BTreeMap::new();
+    for notice in notices {
+        let key = match sheets {
+            XlsxSheets::Campus => notice.campus.as_str(),
+            XlsxSheets::Department => notice.department_name.as_str(),
+        };
+        groups.entry(key).or_default().push(notice);
+    }
+    if groups.is_empty() {
+        groups.insert("Notices", Vec::new());
+    }
+
+    let mut names: Vec<String> = Vec::new();
+    let mut named = Vec::new();
+    for (key, rows) in groups {
+        let name = unique_sheet_name(key, &names);
+        names.push(name.clone());
+        named.push((name, rows));
+    }
+    named
+}
+
+fn write_sheet(worksheet: &mut Worksheet, rows: &[&Notice], header: &Format) -> Result<()> {
+    let mut widths: Vec<usize> = HEADERS.iter().map(|h| column_width(h)).collect();
+    for (col, title) in (0u16..).zip(HEADERS) {
+        worksheet.write_string_with_format(0, col, title, header)?;
+    }
+    for (row, notice) in (1u32..).zip(rows) {
+        for (col, value) in (0u16..).zip(row_cells(notice)) {
+            widths[usize::from(col)] = widths[usize::from(col)].max(column_width(value));
+            if value.is_empty() {
+                continue;
+            }
+            // Links Excel refuses (too long, unknown scheme) stay plain text
+            if col != LINK_COLUMN || worksheet.write_url(row, col, value).is_err() {
+                worksheet.write_string(row, col, value)?;
+            }
+        }
+    }
+    for (col, width) in (0u16..).zip(widths) {
+        worksheet.set_column_width(col, width as f64)?;
+    }
+    worksheet.set_freeze_panes(1, 0)?;
+    Ok(())
+}
+
+/// Cell values of one notice row, in [`HEADERS`] order.
+fn row_cells(notice: &Notice) -> [&str; 8] {
+    [
+        &notice.campus,
+        &notice.college,
+        &notice.department_name,
+        &notice.board_name,
+        &notice.title,
+        &notice.author,
+        &notice.date,
+        &notice.link,
+    ]
+}
+
+/// A valid, unique worksheet name for `name`.
+///
+/// Excel rejects `[]:*?/\`, leading or trailing apostrophes and names over
+/// 31 characters; names are compared case-insensitively.
+fn unique_sheet_name(name: &str, taken: &[String]) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| if "[]:*?/\\".contains(c) { '_' } else { c })
+        .collect();
+    let cleaned = cleaned.trim_matches('\'').trim();
+    let base = if cleaned.is_empty() { "Sheet" } else { cleaned };
+
+    let is_taken = |candidate: &str| {
+        taken
+            .iter()
+            .any(|t| t.to_lowercase() == candidate.to_lowercase())
+    };
+    let truncate = |text: &str, len: usize| text.chars().take(len).collect::<String>();
+
+    let candidate = truncate(base, MAX_SHEET_NAME);
+    if !is_taken(&candidate) {
+        return candidate;
+    }
+    (2..)
+        .map(|n| {
+            let suffix = format!(" ({n})");
+            format!(
+                "{}{suffix}",
+                truncate(base, MAX_SHEET_NAME - suffix.chars().count())
+            )
+        })
+        .find(|candidate| !is_taken(candidate))
+        .expect("unbounded suffixes")
+}
+
+/// Column width fitting `text`, counting CJK characters as two units.
+fn column_width(text: &str) -> usize {
+    (display_width(text) + 2).min(MAX_COLUMN_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(campus: &str, department: &str, title: &str) -> Notice {
+        Notice {
+            campus: campus.to_string(),
+            department_id: "dept".to_string(),
+            department_name: department.to_string(),
+            board_id: "notice".to_string(),
+            board_name: "공지사항".to_string(),
+            title: title.to_string(),
+            date: "2024-03-01".to_string(),
+            link: format!("https://example.com/{title}"),
+            ..Notice::default()
+        }
+    }
+
+    #[test]
+    fn test_workbook_has_sheet_per_group_with_rows() {
+        let notices = vec![
+            notice("신촌캠퍼스", "컴퓨터과학과", "a"),
+            notice("국제캠퍼스", "글로벌인재학부", "b"),
+            notice("신촌캠퍼스", "수학과", "c&d"),
+        ];
+
+        let groups = sheet_groups(&notices, XlsxSheets::Campus);
+        let sizes: Vec<_> = groups
+            .iter()
+            .map(|(name, rows)| (name.as_str(), rows.len()))
+            .collect();
+        assert_eq!(sizes, [("국제캠퍼스", 1), ("신촌캠퍼스", 2)]);
+        assert_eq!(sheet_groups(&notices, XlsxSheets::Department).len(), 3);
+        assert_eq!(sheet_groups(&[], XlsxSheets::Campus)[0].0, "Notices");
+
+        let bytes = render_workbook(&notices, XlsxSheets::Campus).unwrap();
+        assert!(bytes.starts_with(b"PK\x03\x04"));
+    }
+
+    #[test]
+    fn test_unwritable_link_falls_back_to_text() {
+        let mut long = notice("신촌캠퍼스", "컴퓨터과학과", "a");
+        long.link = format!("https://example.com/{}", "x".repeat(3000));
+        assert!(render_workbook(&[long], XlsxSheets::Campus).is_ok());
+    }
+
+    #[test]
+    fn test_sheet_names_are_valid_and_unique() {
+        let taken = vec!["Notices".to_string()];
+        assert_eq!(unique_sheet_name("a/b:c", &taken), "a_b_c");
+        assert_eq!(unique_sheet_name("notices", &taken), "notices (2)");
+        let long = "가".repeat(40);
+        assert_eq!(unique_sheet_name(&long, &taken).chars().count(), 31);
+    }
+
+    #[test]
+    fn test_column_width_counts_cjk_double() {
+        assert_eq!(column_width("abc"), 5);
+        assert_eq!(column_width("공지"), 6);
+        assert_eq!(column_width(&"x".repeat(200)), MAX_COLUMN_WIDTH);
+    }
+}
@@ -2,16 +2,23 @@
 
 //! Notice crawling pipeline.
 
+use std::path::Path;
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 
-use crate::error::Result;
-use crate::models::{Campus, Config, CrawlStats, LocaleConfig};
-use crate::services::NoticeCrawler;
+use crate::error::{AppError, Result};
+use crate::models::{
+    Campus, Config, CrawlOutcome, CrawlState, CrawlStats, FailureLogEntry, LocaleConfig,
+    RunManifest, format_count,
+};
+use crate::output::{apply_limit, build_sinks, sort_notices, write_all, write_opml};
+use crate::services::{NoticeCrawler, NoticeHook, RuleCategorizer};
 use crate::storage::NoticeStorage;
-use crate::utils::log;
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::http::{HtmlFetcher, ReqwestHtmlFetcher};
+use crate::utils::{fs, log};
 
 /// Run the notice crawler.
 pub async fn run_crawler(
@@ -21,9 +28,23 @@ pub async fn run_crawler(
     campuses: &[Campus],
     client: &Client,
 ) -> Result<()> {
-    let start_time = Utc::now();
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+    let start_time = clock.now();
     log::header(&locale.messages.crawler_starting);
 
+    let filter = &config.crawler.campuses;
+    for name in filter {
+        if !campuses.iter().any(|campus| &campus.campus == name) {
+            log::warn(&format!("Campus {name} is not in the site map"));
+        }
+    }
+    let selected: Vec<Campus> = campuses
+        .iter()
+        .filter(|campus| campus.is_selected(filter))
+        .cloned()
+        .collect();
+    let campuses = selected.as_slice();
+
     let total_depts: usize = campuses.iter().map(|c| c.department_count()).sum();
     let total_boards: usize = campuses.iter().map(|c| c.board_count()).sum();
 
@@ -37,12 +58,40 @@ pub async fn run_crawler(
 
     log::info(&locale.messages.crawler_fetching);
 
-    // Initialize the crawler with Config and Client
-    let crawler = NoticeCrawler::new(Arc::clone(&config), client.clone())?;
+    // Initialize the crawler with Config, Client and state from previous runs
+    let base = std::env::current_dir()?;
+    let state_path = config.state_path(&base);
+    let fetcher = archiving_fetcher(
+        &config,
+        Arc::new(
+            ReqwestHtmlFetcher::new(client.clone(), config.crawler.clone())
+                .with_clock(Arc::clone(&clock)),
+        ),
+        Arc::clone(&clock),
+    )?;
+    let crawler = NoticeCrawler::with_fetcher(Arc::clone(&config), fetcher)
+        .with_state(CrawlState::load(&state_path)?)
+        .with_clock(Arc::clone(&clock))
+        .with_hooks(config_hooks(&config));
 
     // Run the crawler to fetch all notices
-    let outcome = crawler.fetch_all(campuses).await?;
-    let end_time = Utc::now();
+    let mut outcome = crawler.fetch_all(campuses).await?;
+    let end_time = clock.now();
+    if outcome.cut_off {
+        log::warn(&format!(
+            "Run cut off by crawler.max_run_secs ({}s); saving {} notices collected so far",
+            config.crawler.max_run_secs.unwrap_or_default(),
+            outcome.notices.len()
+        ));
+    }
+    sort_notices(&mut outcome.notices, config.output.sort_by);
+    let dropped = apply_limit(&mut outcome.notices, config.output.limit);
+    if dropped > 0 {
+        log::info(&format!(
+            "Output limit reached: keeping {} notices, dropped {dropped}",
+            outcome.notices.len()
+        ));
+    }
 
     // Calculate success rates
     let calc_rate = |total: usize, fail: usize| -> f32 {
@@ -72,17 +121,67 @@ pub async fn run_crawler(
 
     let summary = storage.write_snapshot(&outcome, campuses, &stats).await?;
 
+    let sinks = build_sinks(config.as_ref(), &base, &outcome.empty_boards, &clock);
+    write_all(&sinks, &outcome.notices)?;
+
+    // Only once the notices are stored, so a failed write doesn't mark them
+    // seen (or start board cooldowns) for the next run
+    crawler.state().save(&state_path)?;
+
+    let mut outputs = vec![summary.snapshot_location.clone()];
+    outputs.extend(
+        sinks
+            .iter()
+            .flat_map(|sink| sink.files(&outcome.notices))
+            .map(|path| path.display().to_string()),
+    );
+
+    // `Config::validate` requires a feed URL alongside the OPML path
+    if let (Some(opml_path), Some(feed_url)) =
+        (&config.output.opml_path, &config.output.opml_feed_url)
+    {
+        let path = config.output_dir(&base).join(opml_path);
+        write_opml(&path, campuses, "uRing notice feeds", feed_url)?;
+        log::sub_item(&format!("OPML outline: {}", path.display()));
+        outputs.push(path.display().to_string());
+    }
+
+    if let Some(path) = config.failure_log_path(&base) {
+        let count = append_failure_log(&path, &outcome, start_time)?;
+        if count > 0 {
+            log::sub_item(&format!(
+                "Logged {count} failed boards to {}",
+                path.display()
+            ));
+        }
+    }
+
+    // The crawl's outputs are already stored; a missing manifest shouldn't
+    // fail the run
+    if let Some(path) = config.manifest_path(&base)
+        && let Err(err) =
+            write_run_manifest(&path, &RunManifest::new(&outcome, start_time, outputs))
+    {
+        log::warn(&format!(
+            "Failed to write run manifest {}: {err}",
+            path.display()
+        ));
+    }
+
     log::success(
-        &locale
-            .messages
-            .storage_saved
-            .replace("{count}", &summary.notice_count.to_string())
+        &format_count(&locale.messages.storage_saved, summary.notice_count)
             .replace("{path}", &summary.snapshot_location),
     );
 
     if config.logging.show_progress {
         log::sub_item(&format!("Snapshot pointer: {}", summary.pointer_location));
         log::sub_item(&format!("Snapshot timestamp: {}", summary.timestamp));
+        for timing in outcome.slowest_boards(3) {
+            log::sub_item(&format!(
+                "Slow board: {}/{} ({} ms)",
+                timing.department_id, timing.board_id, timing.duration_ms
+            ));
+        }
     }
 
     log::success(&locale.messages.crawler_complete);
@@ -94,5 +193,233 @@ pub async fn run_crawler(
         ));
     }
 
+    if outcome.board_skipped > 0 {
+        log::info(&format!(
+            "Skipped {} boards still within their min_interval_secs",
+            outcome.board_skipped
+        ));
+    }
+
+    if !outcome.open_circuits.is_empty() {
+        log::warn(&format!(
+            "Circuit breaker opened for: {}",
+            outcome.open_circuits.join(", ")
+        ));
+    }
+
+    if config.crawler.strict {
+        check_strict(&outcome)?;
+    }
+
+    Ok(())
+}
+
+/// Strict mode: fail when any board failed or the run produced no notices.
+pub fn check_strict(outcome: &CrawlOutcome) -> Result<()> {
+    if outcome.board_failures > 0 {
+        return Err(AppError::crawl(
+            "strict mode",
+            format!(
+                "{} of {} boards failed",
+                outcome.board_failures, outcome.board_total
+            ),
+        ));
+    }
+    if outcome.notices.is_empty() {
+        return Err(AppError::crawl("strict mode", "no notices were produced"));
+    }
     Ok(())
 }
+
+/// Wrap `fetcher` so every page is archived to `paths.warc_file`, if set,
+/// with records dated by `clock`.
+#[cfg(feature = "warc")]
+fn archiving_fetcher(
+    config: &Config,
+    fetcher: Arc<dyn HtmlFetcher>,
+    clock: Arc<dyn Clock>,
+) -> Result<Arc<dyn HtmlFetcher>> {
+    use crate::output::{ArchivingFetcher, WarcWriter};
+
+    let Some(path) = &config.paths.warc_file else {
+        return Ok(fetcher);
+    };
+    log::sub_item(&format!("Archiving fetched pages to {path}"));
+    let writer = Arc::new(WarcWriter::create(Path::new(path), clock)?);
+    Ok(Arc::new(ArchivingFetcher::new(fetcher, writer)))
+}
+
+#[cfg(not(feature = "warc"))]
+fn archiving_fetcher(
+    config: &Config,
+    fetcher: Arc<dyn HtmlFetcher>,
+    _clock: Arc<dyn Clock>,
+) -> Result<Arc<dyn HtmlFetcher>> {
+    if config.paths.warc_file.is_some() {
+        log::warn("paths.warc_file is set but this build lacks the `warc` feature; not archiving");
+    }
+    Ok(fetcher)
+}
+
+/// Post-processing hooks enabled by `config`.
+pub fn config_hooks(config: &Config) -> Vec<Box<dyn NoticeHook>> {
+    let mut hooks: Vec<Box<dyn NoticeHook>> = Vec::new();
+    if !config.category_rules.is_empty() {
+        hooks.push(Box::new(RuleCategorizer::new(
+            config.category_rules.clone(),
+        )));
+    }
+    hooks
+}
+
+/// Write the run manifest, replacing the previous run's.
+pub fn write_run_manifest(path: &Path, manifest: &RunManifest) -> Result<()> {
+    fs::save_json(path, manifest)
+}
+
+/// Append the run's failed boards to the NDJSON failure log.
+///
+/// Returns the number of lines written.
+pub fn append_failure_log(
+    path: &Path,
+    outcome: &CrawlOutcome,
+    run_at: DateTime<Utc>,
+) -> Result<usize> {
+    let entries = FailureLogEntry::from_outcome(outcome, run_at);
+    if !entries.is_empty() {
+        fs::append_json_lines(path, &entries)?;
+    }
+    Ok(entries.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::error::BoardErrorKind;
+    use crate::models::{CrawlError, CrawlStage, EmptyBoard, Notice};
+    use crate::output::{JsonSink, OutputSink};
+
+    fn notice(department_id: &str, board_id: &str, id: &str) -> Notice {
+        Notice {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: department_id.to_string(),
+            department_name: "Dept".to_string(),
+            board_id: board_id.to_string(),
+            board_name: "Board".to_string(),
+            title: format!("Notice {id}"),
+            date: "2024-03-01".to_string(),
+            link: format!("https://dept.yonsei.ac.kr/{board_id}/{id}"),
+            source_id: Some(id.to_string()),
+            ..Notice::default()
+        }
+    }
+
+    #[test]
+    fn run_manifest_lists_counts_and_outputs() {
+        let dir = tempdir().unwrap();
+        let outcome = CrawlOutcome {
+            notices: vec![
+                notice("cs", "notice", "1"),
+                notice("cs", "notice", "2"),
+                notice("math", "news", "3"),
+            ],
+            errors: vec![CrawlError {
+                stage: CrawlStage::BoardList,
+                board_id: Some("jobs".to_string()),
+                board_name: None,
+                url: None,
+                notice_id: None,
+                message: "Upstream HTTP 503".to_string(),
+                retryable: true,
+                kind: Some(BoardErrorKind::Status(503)),
+            }],
+            empty_boards: vec![EmptyBoard {
+                campus: "신촌캠퍼스".to_string(),
+                department_id: "math".to_string(),
+                board_id: "jobs".to_string(),
+            }],
+            ..CrawlOutcome::default()
+        };
+        let json_path = dir.path().join("notices.json");
+        let sinks: Vec<Box<dyn OutputSink>> = vec![Box::new(JsonSink::new(&json_path, true))];
+        write_all(&sinks, &outcome.notices).unwrap();
+        let outputs = sinks
+            .iter()
+            .flat_map(|sink| sink.files(&outcome.notices))
+            .map(|path| path.display().to_string())
+            .collect();
+
+        let path = dir.path().join("run_manifest.json");
+        write_run_manifest(&path, &RunManifest::new(&outcome, Utc::now(), outputs)).unwrap();
+
+        let manifest: RunManifest = fs::load_json_optional(&path).unwrap().unwrap();
+        assert_eq!(manifest.notice_count, 3);
+        assert_eq!(manifest.board_counts["cs/notice"], 2);
+        assert_eq!(manifest.board_counts["math/news"], 1);
+        assert_eq!(manifest.outputs, [json_path.display().to_string()]);
+        assert!(json_path.exists());
+        assert_eq!(manifest.errors.len(), 1);
+        assert_eq!(manifest.empty_boards, ["math/jobs"]);
+    }
+
+    #[test]
+    fn check_strict_rejects_failed_boards_and_empty_runs() {
+        let clean = CrawlOutcome {
+            notices: vec![notice("cs", "notice", "1")],
+            board_total: 2,
+            ..CrawlOutcome::default()
+        };
+        assert!(check_strict(&clean).is_ok());
+
+        let failed = CrawlOutcome {
+            board_failures: 1,
+            ..clean.clone()
+        };
+        assert!(check_strict(&failed).is_err());
+
+        assert!(check_strict(&CrawlOutcome::default()).is_err());
+    }
+
+    #[test]
+    fn failure_log_appends_across_runs() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("logs/failures.ndjson");
+        let outcome = CrawlOutcome {
+            errors: vec![
+                CrawlError {
+                    stage: CrawlStage::BoardList,
+                    board_id: Some("scholarship".to_string()),
+                    board_name: Some("장학공지".to_string()),
+                    url: Some("https://dept.yonsei.ac.kr/board/list.do".to_string()),
+                    notice_id: None,
+                    message: "Upstream HTTP 503".to_string(),
+                    retryable: true,
+                    kind: Some(BoardErrorKind::Status(503)),
+                },
+                CrawlError {
+                    stage: CrawlStage::NoticeDetail,
+                    board_id: Some("scholarship".to_string()),
+                    board_name: None,
+                    url: None,
+                    notice_id: Some("1".to_string()),
+                    message: "detail failed".to_string(),
+                    retryable: false,
+                    kind: None,
+                },
+            ],
+            ..CrawlOutcome::default()
+        };
+
+        append_failure_log(&path, &outcome, Utc::now()).unwrap();
+        append_failure_log(&path, &outcome, Utc::now()).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let entry: FailureLogEntry = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(entry.board_id.as_deref(), Some("scholarship"));
+        assert!(matches!(entry.category, CrawlStage::BoardList));
+    }
+}
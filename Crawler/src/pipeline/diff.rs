@@ -0,0 +1,179 @@
+// src/pipeline/diff.rs
+
+//! Offline comparison of two output directories.
+//!
+//! Loads every notice file under each directory and reports, per board, the
+//! notices that appeared or disappeared between them, so operators can
+//! review a change without running a crawl.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::error::{AppError, Result};
+use crate::models::{Notice, PathsConfig};
+use crate::output::json::{find_notice_files, read_notices};
+use crate::utils::log;
+
+/// Notices added to and removed from one board.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BoardDiff {
+    pub added: Vec<Notice>,
+    pub removed: Vec<Notice>,
+}
+
+/// Changes between two output directories, keyed by
+/// `<department_id>/<board_id>`; unchanged boards are left out.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NoticeDiff {
+    pub boards: BTreeMap<String, BoardDiff>,
+}
+
+impl NoticeDiff {
+    pub fn added_count(&self) -> usize {
+        self.boards.values().map(|board| board.added.len()).sum()
+    }
+
+    pub fn removed_count(&self) -> usize {
+        self.boards.values().map(|board| board.removed.len()).sum()
+    }
+
+    /// Human-readable report, one section per changed board.
+    pub fn render_text(&self) -> String {
+        let mut lines = Vec::new();
+        for (board, diff) in &self.boards {
+            lines.push(format!(
+                "{board}: +{} -{}",
+                diff.added.len(),
+                diff.removed.len()
+            ));
+            for (sign, notices) in [('+', &diff.added), ('-', &diff.removed)] {
+                for notice in notices {
+                    lines.push(format!(
+                        "  {sign} {} {} ({})",
+                        notice.date, notice.title, notice.link
+                    ));
+                }
+            }
+        }
+        lines.push(format!(
+            "{} added, {} removed across {} boards",
+            self.added_count(),
+            self.removed_count(),
+            self.boards.len()
+        ));
+        lines.join("\n")
+    }
+}
+
+/// Compare the notices under `old_dir` with those under `new_dir`.
+///
+/// Notices are matched by [`Notice::canonical_id`], so a notice present in
+/// both a combined file and a per-board file counts once.
+pub fn diff_dirs(old_dir: &Path, new_dir: &Path, paths: &PathsConfig) -> Result<NoticeDiff> {
+    let old = load_dir(old_dir, paths)?;
+    let new = load_dir(new_dir, paths)?;
+
+    let mut diff = NoticeDiff::default();
+    let board_key = |notice: &Notice| format!("{}/{}", notice.department_id, notice.board_id);
+    for (id, notice) in &new {
+        if !old.contains_key(id) {
+            let board = diff.boards.entry(board_key(notice)).or_default();
+            board.added.push(notice.clone());
+        }
+    }
+    for (id, notice) in &old {
+        if !new.contains_key(id) {
+            let board = diff.boards.entry(board_key(notice)).or_default();
+            board.removed.push(notice.clone());
+        }
+    }
+    for board in diff.boards.values_mut() {
+        board.added.sort_by(|a, b| b.date.cmp(&a.date));
+        board.removed.sort_by(|a, b| b.date.cmp(&a.date));
+    }
+    Ok(diff)
+}
+
+/// Every notice in the JSON files under `dir`, by canonical id.
+///
+/// The crawler's own files (see [`find_notice_files`]) are skipped; any
+/// other file that does not parse as notices is skipped with a warning.
+fn load_dir(dir: &Path, paths: &PathsConfig) -> Result<HashMap<String, Notice>> {
+    if !dir.is_dir() {
+        return Err(AppError::config(format!(
+            "Not a directory: {}",
+            dir.display()
+        )));
+    }
+    let mut notices = HashMap::new();
+    for path in find_notice_files(dir, paths)? {
+        match read_notices(&path) {
+            Ok(file) => notices.extend(file.into_iter().map(|n| (n.canonical_id(), n))),
+            Err(err) => log::warn(&format!("Skipping {}: {err}", path.display())),
+        }
+    }
+    Ok(notices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GroupBy;
+    use crate::output::{JsonSink, OutputSink};
+    use tempfile::tempdir;
+
+    fn notice(board_id: &str, id: u32) -> Notice {
+        Notice {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: "cs".to_string(),
+            department_name: "컴퓨터과학과".to_string(),
+            board_id: board_id.to_string(),
+            board_name: board_id.to_string(),
+            title: format!("공지 {id}"),
+            date: format!("2024-03-{id:02}"),
+            link: format!("https://cs.yonsei.ac.kr/{board_id}/{id}"),
+            source_id: Some(id.to_string()),
+            ..Notice::default()
+        }
+    }
+
+    #[test]
+    fn reports_added_and_removed_notices_per_board() {
+        let root = tempdir().unwrap();
+        let write = |dir: &str, notices: &[Notice]| {
+            JsonSink::new(root.path().join(dir).join("notices.json"), true)
+                .with_group_by(GroupBy::BoardTree)
+                .write(notices)
+                .unwrap();
+        };
+        write(
+            "old",
+            &[notice("notice", 1), notice("notice", 2), notice("job", 3)],
+        );
+        write(
+            "new",
+            &[notice("notice", 2), notice("notice", 4), notice("job", 3)],
+        );
+        // The run manifest is not notice output
+        let paths = PathsConfig {
+            manifest_file: Some("run_manifest.json".to_string()),
+            ..PathsConfig::default()
+        };
+        std::fs::write(root.path().join("new/run_manifest.json"), "{}").unwrap();
+
+        let diff = diff_dirs(&root.path().join("old"), &root.path().join("new"), &paths).unwrap();
+
+        assert_eq!(diff.boards.len(), 1);
+        let board = &diff.boards["cs/notice"];
+        assert_eq!(board.added[0].title, "공지 4");
+        assert_eq!(board.removed[0].title, "공지 1");
+        assert_eq!((diff.added_count(), diff.removed_count()), (1, 1));
+        assert!(
+            diff.render_text()
+                .ends_with("1 added, 1 removed across 1 boards")
+        );
+        assert!(diff_dirs(&root.path().join("missing"), root.path(), &paths).is_err());
+    }
+}
@@ -8,10 +8,7 @@ use reqwest::Client;
 use crate::error::Result;
 use crate::models::{Campus, Config, LocaleConfig, ManualReviewItem, Seed};
 use crate::services::{BoardDiscoveryService, DepartmentCrawler, SelectorDetector};
-use crate::utils::log;
-
-/// Maximum concurrency for board discovery.
-const CONCURRENCY_LIMIT: usize = 14;
+use crate::utils::{fs, log};
 
 /// Run the mapper to discover departments and boards.
 pub async fn run_mapper(
@@ -33,9 +30,12 @@ pub async fn run_mapper(
     // Departments Discovery
     log::step(1, 2, &locale.messages.mapper_step_departments);
 
-    let dept_crawler = DepartmentCrawler::new(client);
+    let dept_crawler = DepartmentCrawler::new(client, &config.crawler);
     let mut campuses = dept_crawler.crawl_all(&seed.campuses).await?;
 
+    let base = std::env::current_dir()?;
+    fs::save_json(&config.departments_path(&base), &campuses)?;
+
     if campuses.is_empty() {
         log::error(
             &locale
@@ -60,6 +60,7 @@ pub async fn run_mapper(
         &config.discovery,
     ));
 
+    let concurrency = config.crawler.max_concurrent_discovery.max(1);
     let mut all_manual_reviews: Vec<ManualReviewItem> = Vec::new();
 
     for campus in &mut campuses {
@@ -103,10 +104,10 @@ pub async fn run_mapper(
                         }
 
                         // Return a tuple of (processed department, review items)
-                        (dept, result.manual_review)
+                        (dept, result.manual_reviews)
                     }
                 })
-                .buffer_unordered(CONCURRENCY_LIMIT) // Run N tasks concurrently
+                .buffer_unordered(concurrency) // Run N tasks concurrently
                 .collect::<Vec<_>>()
                 .await
                 .into_iter()
@@ -128,6 +129,9 @@ pub async fn run_mapper(
         ));
     }
 
+    let review_path = config.manual_review_path(&base);
+    fs::save_json(&review_path, &all_manual_reviews)?;
+
     let total_depts: usize = campuses.iter().map(|c| c.department_count()).sum();
     let total_boards: usize = campuses.iter().map(|c| c.board_count()).sum();
     log::summary(
@@ -3,8 +3,11 @@
 //! Pipeline entry points for CLI commands.
 
 pub mod crawl;
+pub mod diff;
 pub mod map;
+#[allow(clippy::module_inception)]
 pub mod pipeline;
+pub mod self_test;
 pub mod validate;
 
 pub use pipeline::run_pipeline;
@@ -29,7 +29,7 @@ pub async fn run_pipeline(
     // Step 2: Persist config and site map for this run
     log::step(2, 3, "Config - Persisting config and site map");
     storage
-        .write_config_bundle(config.as_ref(), seed, &locale, &campuses)
+        .write_config_bundle(config.as_ref(), seed, locale, &campuses)
         .await?;
 
     // Step 3: Crawl notices from the discovered boards
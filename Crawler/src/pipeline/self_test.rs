@@ -0,0 +1,233 @@
+// src/pipeline/self_test.rs
+
+//! Board health check.
+//!
+//! Fetches a small sample of boards (the requested ones, or the first board
+//! of each domain) and checks that each still yields notices, so a site
+//! redesign that breaks selectors fails CI or monitoring without a full crawl.
+
+use std::collections::HashSet;
+
+use crate::error::{AppError, Result};
+use crate::models::{Board, Campus, DepartmentRef};
+use crate::services::NoticeCrawler;
+use crate::utils::{log, url};
+
+/// Outcome of checking one board.
+#[derive(Debug, Clone)]
+pub struct BoardCheck {
+    pub department_id: String,
+    pub board_id: String,
+    pub url: String,
+    pub notice_count: usize,
+    /// Why the board failed (`None` = passed)
+    pub failure: Option<String>,
+}
+
+/// Results of a self-test run.
+#[derive(Debug, Clone, Default)]
+pub struct SelfTestReport {
+    pub checks: Vec<BoardCheck>,
+}
+
+impl SelfTestReport {
+    /// Checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &BoardCheck> {
+        self.checks.iter().filter(|check| check.failure.is_some())
+    }
+
+    /// Succeed if every board passed, otherwise fail with a summary.
+    pub fn into_result(self) -> Result<Self> {
+        let failed = self.failures().count();
+        if failed == 0 {
+            return Ok(self);
+        }
+        Err(AppError::validation(format!(
+            "Self-test failed: {failed} of {} boards returned no notices",
+            self.checks.len()
+        )))
+    }
+}
+
+/// Pick the boards to check.
+///
+/// `board_ids` selects boards by id or `dept/board` key; when empty, the
+/// first board of each domain is used.
+pub fn sample_boards<'a>(
+    campuses: &'a [Campus],
+    board_ids: &[String],
+) -> Vec<(DepartmentRef<'a>, &'a Board)> {
+    let mut seen_domains = HashSet::new();
+    campuses
+        .iter()
+        .flat_map(|campus| campus.all_departments())
+        .flat_map(|dept_ref| dept_ref.dept.boards.iter().map(move |b| (dept_ref, b)))
+        .filter(|(dept_ref, board)| {
+            if board_ids.is_empty() {
+                return seen_domains.insert(url::get_domain(&board.url));
+            }
+            let key = format!("{}/{}", dept_ref.dept.id, board.id);
+            board_ids.iter().any(|id| *id == board.id || *id == key)
+        })
+        .collect()
+}
+
+/// Check each sampled board and log a summary.
+pub async fn run_self_test(
+    crawler: &NoticeCrawler,
+    campuses: &[Campus],
+    board_ids: &[String],
+) -> SelfTestReport {
+    let sample = sample_boards(campuses, board_ids);
+    log::header(&format!("Self-test: checking {} boards", sample.len()));
+
+    let mut report = SelfTestReport::default();
+    for (dept_ref, board) in sample {
+        let (notice_count, failure) = match crawler.fetch_list(board, dept_ref).await {
+            Ok(extraction) if extraction.notices.is_empty() => (
+                0,
+                Some(
+                    extraction
+                        .diagnostic()
+                        .unwrap_or_else(|| "no notices extracted".to_string()),
+                ),
+            ),
+            Ok(extraction) => (extraction.notices.len(), None),
+            Err(err) => (0, Some(err.to_string())),
+        };
+
+        let label = format!("{}/{}", dept_ref.dept.id, board.id);
+        match &failure {
+            None => log::sub_item(&format!("OK   {label}: {notice_count} notices")),
+            Some(reason) => log::warn(&format!("FAIL {label} ({}): {reason}", board.url)),
+        }
+        report.checks.push(BoardCheck {
+            department_id: dept_ref.dept.id.clone(),
+            board_id: board.id.clone(),
+            url: board.url.clone(),
+            notice_count,
+            failure,
+        });
+    }
+
+    let failed = report.failures().count();
+    log::info(&format!(
+        "Self-test: {} passed, {failed} failed",
+        report.checks.len() - failed
+    ));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::models::{CmsSelectors, Config, Department};
+    use crate::utils::http::HtmlFetcher;
+
+    struct FixtureFetcher(HashMap<&'static str, &'static str>);
+
+    #[async_trait]
+    impl HtmlFetcher for FixtureFetcher {
+        async fn fetch_html(&self, url: &str) -> Result<String> {
+            self.0
+                .get(url)
+                .map(|html| html.to_string())
+                .ok_or_else(|| AppError::UpstreamHttp {
+                    url: url.to_string(),
+                    status: 404,
+                })
+        }
+    }
+
+    fn board(id: &str, url: &str) -> Board {
+        Board {
+            id: id.to_string(),
+            name: id.to_string(),
+            url: url.to_string(),
+            selectors: CmsSelectors {
+                row_selector: "tr".into(),
+                title_selector: "a".to_string(),
+                date_selector: "td.date".to_string(),
+                ..CmsSelectors::default()
+            },
+            ..Board::default()
+        }
+    }
+
+    fn campuses() -> Vec<Campus> {
+        vec![Campus {
+            campus: "신촌캠퍼스".to_string(),
+            departments: vec![
+                Department {
+                    id: "cs".to_string(),
+                    name: "컴퓨터과학과".to_string(),
+                    url: "https://cs.yonsei.ac.kr".to_string(),
+                    boards: vec![
+                        board("notice", "https://cs.yonsei.ac.kr/notice"),
+                        board("job", "https://cs.yonsei.ac.kr/job"),
+                    ],
+                },
+                Department {
+                    id: "math".to_string(),
+                    name: "수학과".to_string(),
+                    url: "https://math.yonsei.ac.kr".to_string(),
+                    boards: vec![board("notice", "https://math.yonsei.ac.kr/notice")],
+                },
+            ],
+            ..Campus::default()
+        }]
+    }
+
+    #[test]
+    fn test_sample_is_one_board_per_domain() {
+        let campuses = campuses();
+        let sample = sample_boards(&campuses, &[]);
+        let urls: Vec<_> = sample.iter().map(|(_, b)| b.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://cs.yonsei.ac.kr/notice",
+                "https://math.yonsei.ac.kr/notice"
+            ]
+        );
+
+        let sample = sample_boards(&campuses, &["cs/job".to_string()]);
+        assert_eq!(sample.len(), 1);
+        assert_eq!(sample[0].1.id, "job");
+    }
+
+    #[tokio::test]
+    async fn test_broken_board_fails_self_test() {
+        let fetcher = FixtureFetcher(HashMap::from([
+            (
+                "https://cs.yonsei.ac.kr/notice",
+                r#"<table><tr><td><a href="/view?id=1">개강 안내</a></td><td class="date">2024-03-01</td></tr></table>"#,
+            ),
+            // Redesigned page: the old row layout is gone
+            (
+                "https://math.yonsei.ac.kr/notice",
+                r#"<ul class="new-board"><li>개강 안내</li></ul>"#,
+            ),
+        ]));
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+
+        let report = run_self_test(&crawler, &campuses(), &[]).await;
+        assert_eq!(report.checks.len(), 2);
+        assert_eq!(report.checks[0].notice_count, 1);
+        let failed: Vec<_> = report
+            .failures()
+            .map(|c| c.department_id.as_str())
+            .collect();
+        assert_eq!(failed, ["math"]);
+
+        let err = report.into_result().unwrap_err();
+        assert!(err.to_string().contains("1 of 2 boards"));
+    }
+}
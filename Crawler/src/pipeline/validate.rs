@@ -1,26 +1,33 @@
 // src/pipeline/validate.rs
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::config::load_all;
-use crate::error::Result;
-use crate::models::LocaleConfig;
+use crate::error::{AppError, Result};
+use crate::models::{LocaleConfig, PathsConfig};
+use crate::output::json::{find_notice_files, read_notices};
 use crate::utils::log;
 
+/// Something wrong with one output file.
+#[derive(Debug, Clone)]
+pub struct OutputProblem {
+    pub path: PathBuf,
+    pub message: String,
+}
+
 /// Validate configuration and seed data.
 /// Checks for both syntax errors (parsing) and logical issues (empty lists, invalid values).
-pub fn run_validate(locale: &LocaleConfig, base_path: &PathBuf) -> Result<()> {
+pub fn run_validate(locale: &LocaleConfig, base_path: &Path) -> Result<()> {
     log::header(&locale.messages.validate_starting);
 
     // Load config and seed files
-    let (config, seed) = load_all(base_path).map_err(|e| {
+    let (config, seed) = load_all(base_path).inspect_err(|e| {
         log::error(
             &locale
                 .messages
                 .validate_failed
                 .replace("{error}", &e.to_string()),
         );
-        e
     })?;
 
     // Validate config and log results
@@ -93,3 +100,115 @@ pub fn run_validate(locale: &LocaleConfig, base_path: &PathBuf) -> Result<()> {
 
     Ok(())
 }
+
+/// Lint the notice JSON files under `dir`.
+///
+/// Every `.json`/`.json.gz` file other than the crawler's own (manifest,
+/// state; see [`find_notice_files`]) must parse as notices (either JSON
+/// format), and every notice needs a title and a link. Problems are logged
+/// and returned; an unreadable directory is an error.
+pub fn validate_output(dir: &Path, paths: &PathsConfig) -> Result<Vec<OutputProblem>> {
+    if !dir.is_dir() {
+        return Err(AppError::config(format!(
+            "Not a directory: {}",
+            dir.display()
+        )));
+    }
+    let files = find_notice_files(dir, paths)?;
+    let mut problems = Vec::new();
+    for path in &files {
+        let mut report = |message: String| {
+            log::warn(&format!("{}: {message}", path.display()));
+            problems.push(OutputProblem {
+                path: path.clone(),
+                message,
+            });
+        };
+        let notices = match read_notices(path) {
+            Ok(notices) => notices,
+            Err(err) => {
+                report(format!("not a notice file: {err}"));
+                continue;
+            }
+        };
+        for (idx, notice) in notices.iter().enumerate() {
+            let missing: Vec<&str> = [("title", &notice.title), ("link", &notice.link)]
+                .into_iter()
+                .filter(|(_, value)| value.trim().is_empty())
+                .map(|(field, _)| field)
+                .collect();
+            if !missing.is_empty() {
+                report(format!(
+                    "notice {idx} has an empty {}",
+                    missing.join(" and ")
+                ));
+            }
+        }
+    }
+    log::info(&format!(
+        "Checked {} output files: {} problems",
+        files.len(),
+        problems.len()
+    ));
+    Ok(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Notice;
+    use tempfile::tempdir;
+
+    #[test]
+    fn reports_malformed_files_and_incomplete_notices() {
+        let dir = tempdir().unwrap();
+        let notice = |title: &str, link: &str| Notice {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: "cs".to_string(),
+            department_name: "컴퓨터과학과".to_string(),
+            board_id: "notice".to_string(),
+            board_name: "공지사항".to_string(),
+            title: title.to_string(),
+            date: "2024-03-01".to_string(),
+            link: link.to_string(),
+            ..Notice::default()
+        };
+        let good = [notice("졸업 안내", "https://cs.yonsei.ac.kr/1")];
+        crate::utils::fs::save_json(&dir.path().join("good.json"), &good).unwrap();
+        std::fs::create_dir(dir.path().join("board")).unwrap();
+        std::fs::write(dir.path().join("board/broken.json"), "[{\"title\": ").unwrap();
+        // The crawler's own files are not notice output
+        let paths = PathsConfig {
+            manifest_file: Some("run_manifest.json".to_string()),
+            ..PathsConfig::default()
+        };
+        let manifest = crate::models::RunManifest::new(
+            &crate::models::CrawlOutcome::default(),
+            chrono::Utc::now(),
+            Vec::new(),
+        );
+        crate::utils::fs::save_json(&dir.path().join("run_manifest.json"), &manifest).unwrap();
+        crate::utils::fs::save_json(
+            &dir.path().join(&paths.state_file),
+            &crate::models::CrawlState::default(),
+        )
+        .unwrap();
+
+        let problems = validate_output(dir.path(), &paths).unwrap();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].path.ends_with("board/broken.json"));
+        assert!(problems[0].message.starts_with("not a notice file"));
+
+        let incomplete = [notice("", "https://cs.yonsei.ac.kr/2"), notice(" ", "")];
+        crate::utils::fs::save_json(&dir.path().join("incomplete.json"), &incomplete).unwrap();
+        let messages: Vec<String> = validate_output(dir.path(), &paths)
+            .unwrap()
+            .into_iter()
+            .map(|problem| problem.message)
+            .collect();
+        assert_eq!(messages.len(), 3);
+        assert!(messages.contains(&"notice 0 has an empty title".to_string()));
+        assert!(messages.contains(&"notice 1 has an empty title and link".to_string()));
+        assert!(validate_output(&dir.path().join("missing"), &paths).is_err());
+    }
+}
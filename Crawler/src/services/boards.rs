@@ -25,6 +25,8 @@ pub struct BoardDiscoveryService<'a> {
     keywords: Vec<KeywordMapping>,
     selector_detector: SelectorDetector,
     config: DiscoveryConfig,
+    blacklist_regex: Vec<Regex>,
+    whitelist: Vec<Regex>,
 }
 
 impl<'a> BoardDiscoveryService<'a> {
@@ -40,6 +42,8 @@ impl<'a> BoardDiscoveryService<'a> {
             keywords,
             selector_detector,
             config: config.clone(),
+            blacklist_regex: compile_patterns("blacklist_regex", &config.blacklist_regex),
+            whitelist: compile_patterns("whitelist_patterns", &config.whitelist_patterns),
         }
     }
 
@@ -53,11 +57,12 @@ impl<'a> BoardDiscoveryService<'a> {
         let mut result = BoardDiscoveryResult::default();
 
         if !Self::is_valid_url(dept_url) {
-            result.manual_review = Some(ManualReviewItem {
+            result.manual_reviews.push(ManualReviewItem {
                 campus: campus.to_string(),
                 name: dept_name.to_string(),
                 url: dept_url.to_string(),
                 reason: "Homepage URL is invalid".to_string(),
+                candidates: Vec::new(),
             });
             return result;
         }
@@ -65,11 +70,12 @@ impl<'a> BoardDiscoveryService<'a> {
         let document = match self.fetch_department_page(dept_url).await {
             Ok(doc) => doc,
             Err(e) => {
-                result.manual_review = Some(ManualReviewItem {
+                result.manual_reviews.push(ManualReviewItem {
                     campus: campus.to_string(),
                     name: dept_name.to_string(),
                     url: dept_url.to_string(),
                     reason: format!("Failed to fetch homepage: {e}"),
+                    candidates: Vec::new(),
                 });
                 return result;
             }
@@ -82,17 +88,30 @@ impl<'a> BoardDiscoveryService<'a> {
         let sitemap_doc = self.find_sitemap(&document, dept_url).await;
         let source_doc = sitemap_doc.as_ref().unwrap_or(&document);
 
-        result.boards = self
+        let mut extracted = self
             .extract_boards(source_doc, dept_url, &default_selectors)
             .await;
 
-        if result.boards.is_empty() && sitemap_doc.is_some() {
+        if extracted.is_empty() && sitemap_doc.is_some() {
             log::info("    Sitemap yielded no results, falling back to homepage");
-            result.boards = self
+            extracted = self
                 .extract_boards(&document, dept_url, &default_selectors)
                 .await;
         }
 
+        for (board, candidates) in extracted {
+            if !candidates.is_empty() {
+                result.manual_reviews.push(ManualReviewItem {
+                    campus: campus.to_string(),
+                    name: format!("{dept_name} / {}", board.name),
+                    url: board.url.clone(),
+                    reason: "Selectors inferred from page structure".to_string(),
+                    candidates,
+                });
+            }
+            result.boards.push(board);
+        }
+
         result
     }
 
@@ -125,56 +144,99 @@ impl<'a> BoardDiscoveryService<'a> {
         None
     }
 
-    fn is_valid_board_link(&self, text: &str, href: &str) -> bool {
+    /// Propose boards from the anchors on a department landing page.
+    ///
+    /// Links are filtered and deduplicated as in [`Self::discover`], mapped to
+    /// boards by keyword, and given fallback selectors without fetching.
+    pub fn propose_boards(&self, document: &Html, base_url: &str) -> Vec<Board> {
+        let mut id_counts: HashMap<String, usize> = HashMap::new();
+        self.collect_board_links(document, base_url)
+            .into_iter()
+            .filter_map(|(text, url)| {
+                let mapping = self.keywords.iter().find(|m| text.contains(&m.keyword))?;
+                Some(Board {
+                    id: unique_id(&mut id_counts, &mapping.id),
+                    name: if text.is_empty() {
+                        mapping.display_name.clone()
+                    } else {
+                        text
+                    },
+                    url,
+                    selectors: CmsSelectors::fallback(),
+                    ..Board::default()
+                })
+            })
+            .collect()
+    }
+
+    /// Scan anchors for same-domain candidate board links as `(text, url)`.
+    ///
+    /// Blacklisted (article) links, over-long names, script/fragment links and
+    /// duplicates by normalized URL are skipped.
+    fn collect_board_links(&self, document: &Html, base_url: &str) -> Vec<(String, String)> {
+        let base_domain = url::get_domain(base_url);
+        let link_selector = Selector::parse("a[href]").unwrap();
+
+        let mut seen_urls = HashSet::new();
+        let mut links = Vec::new();
+
+        for element in document.select(&link_selector) {
+            let text = element.text().collect::<String>().trim().to_string();
+            let Some(href) = element.value().attr("href") else {
+                continue;
+            };
+            if href.contains("javascript") || href == "#" {
+                continue;
+            }
+
+            let full_url = url::resolve(base_url, href);
+            if !self.is_valid_board_link(&text, href, &full_url) {
+                continue;
+            }
+            if let (Some(base_dom), Some(link_dom)) = (&base_domain, url::get_domain(&full_url))
+                && base_dom != &link_dom
+            {
+                continue;
+            }
+
+            if seen_urls.insert(url::normalize_for_dedup(&full_url)) {
+                links.push((text, full_url));
+            }
+        }
+        links
+    }
+
+    /// Accept a link only if it matches the whitelist (when one is set),
+    /// then reject it if it matches a blacklist substring (on the raw href) or
+    /// regex (on the resolved URL), or its name is too long for a board.
+    fn is_valid_board_link(&self, text: &str, href: &str, full_url: &str) -> bool {
+        if !self.whitelist.is_empty() && !self.whitelist.iter().any(|re| re.is_match(full_url)) {
+            return false;
+        }
         if self
             .config
             .blacklist_patterns
             .iter()
             .any(|p| href.contains(p))
+            || self.blacklist_regex.iter().any(|re| re.is_match(full_url))
         {
             return false;
         }
         text.chars().count() <= self.config.max_board_name_length
     }
 
+    /// Extract boards from a page, each paired with the ranked selector
+    /// candidates that need review (empty when a known CMS matched).
     async fn extract_boards(
         &self,
         document: &Html,
         base_url: &str,
         default_selectors: &Option<CmsSelectors>,
-    ) -> Vec<Board> {
+    ) -> Vec<(Board, Vec<Board>)> {
         let mut id_counts: HashMap<String, usize> = HashMap::new();
-        let base_domain = url::get_domain(base_url);
-        let link_selector = Selector::parse("a[href]").unwrap();
 
-        let mut seen_urls = HashSet::new();
-        let mut links_to_process = Vec::new();
-
-        for element in document.select(&link_selector) {
-            let text = element.text().collect::<String>().trim().to_string();
-            if let Some(href) = element.value().attr("href") {
-                if !self.is_valid_board_link(&text, href) {
-                    continue;
-                }
-                let full_url = url::resolve(base_url, href);
-                if seen_urls.contains(&full_url) || href.contains("javascript") || href == "#" {
-                    continue;
-                }
-
-                if let (Some(base_dom), Some(link_dom)) = (&base_domain, url::get_domain(&full_url))
-                {
-                    if base_dom != &link_dom {
-                        continue;
-                    }
-                }
-
-                if seen_urls.insert(full_url.clone()) {
-                    links_to_process.push((text, full_url));
-                }
-            }
-        }
-
-        let board_futures: Vec<_> = links_to_process
+        let board_futures: Vec<_> = self
+            .collect_board_links(document, base_url)
             .into_iter()
             .map(|(text, url)| self.try_create_board(text, url, default_selectors))
             .collect();
@@ -182,16 +244,15 @@ impl<'a> BoardDiscoveryService<'a> {
         let results: Vec<_> = future::join_all(board_futures).await;
         results
             .into_iter()
-            .filter_map(|b| b)
-            .fold(Vec::new(), |mut acc, mut board| {
-                let count = id_counts.entry(board.id.clone()).or_insert(0);
-                *count += 1;
-                if *count > 1 {
-                    board.id = format!("{}_{}", board.id, *count);
+            .flatten()
+            .map(|(mut board, mut candidates)| {
+                board.id = unique_id(&mut id_counts, &board.id);
+                for candidate in &mut candidates {
+                    candidate.id = board.id.clone();
                 }
-                acc.push(board);
-                acc
+                (board, candidates)
             })
+            .collect()
     }
 
     async fn try_create_board(
@@ -199,37 +260,213 @@ impl<'a> BoardDiscoveryService<'a> {
         text: String,
         url: String,
         default_selectors: &Option<CmsSelectors>,
-    ) -> Option<Board> {
+    ) -> Option<(Board, Vec<Board>)> {
         let mapping = self.keywords.iter().find(|m| text.contains(&m.keyword))?;
-        let selectors = self.detect_board_selectors(&url, default_selectors).await?;
         let board_name = if text.is_empty() {
             mapping.display_name.clone()
         } else {
             text
         };
-        Some(Board {
-            id: mapping.id.clone(),
-            name: board_name,
-            url,
-            selectors,
-        })
+        let (selectors, candidates) = self
+            .detect_board_selectors(&mapping.id, &board_name, &url, default_selectors)
+            .await;
+        Some((
+            Board {
+                id: mapping.id.clone(),
+                name: board_name,
+                url,
+                selectors,
+                ..Board::default()
+            },
+            candidates,
+        ))
     }
 
+    /// Resolve selectors for a board page.
+    ///
+    /// Known CMS patterns win; otherwise the best structural candidate is
+    /// used and all candidates are returned for manual review.
     async fn detect_board_selectors(
         &self,
+        id: &str,
+        name: &str,
         url: &str,
         default_selectors: &Option<CmsSelectors>,
-    ) -> Option<CmsSelectors> {
+    ) -> (CmsSelectors, Vec<Board>) {
         if let Some(selectors) = default_selectors {
-            return Some(selectors.clone());
+            return (selectors.clone(), Vec::new());
         }
 
-        if let Ok(board_doc) = fetch_page_async(self.client, url).await {
-            if let Some(selectors) = self.selector_detector.detect(&board_doc, url) {
-                return Some(selectors);
-            }
+        let Ok(board_doc) = fetch_page_async(self.client, url).await else {
+            return (CmsSelectors::fallback(), Vec::new());
+        };
+
+        if let Some(selectors) = self.selector_detector.detect(&board_doc, url) {
+            return (selectors, Vec::new());
         }
 
-        Some(CmsSelectors::fallback())
+        let candidates = self
+            .selector_detector
+            .propose_boards(&board_doc, id, name, url);
+        let selectors = candidates
+            .first()
+            .map(|board| board.selectors.clone())
+            .unwrap_or_else(CmsSelectors::fallback);
+        (selectors, candidates)
+    }
+}
+
+/// Compile configured regexes, skipping (and logging) invalid ones.
+fn compile_patterns(name: &str, patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| match Regex::new(pattern) {
+            Ok(regex) => Some(regex),
+            Err(err) => {
+                log::warn(&format!("Ignoring invalid {name} '{pattern}': {err}"));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Suffix repeated board ids with their occurrence count (`notice`, `notice_2`).
+fn unique_id(counts: &mut HashMap<String, usize>, id: &str) -> String {
+    let count = counts.entry(id.to_string()).or_insert(0);
+    *count += 1;
+    if *count > 1 {
+        format!("{id}_{count}")
+    } else {
+        id.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LANDING_PAGE: &str = r#"
+        <nav>
+          <a href="/intro">학과소개</a>
+          <a href="/board/notice">공지사항</a>
+          <a href="/board/notice/#list">공지사항</a>
+          <a href="/board/scholarship">장학공지</a>
+          <a href="/board/notice/view.do?articleNo=123">[공지] 2024학년도 수강신청 일정 안내</a>
+          <a href="/board/notice?mode=view&articleNo=99">휴학 공지</a>
+          <a href="https://other.ac.kr/notice">타학과 공지사항</a>
+          <a href="javascript:void(0)">공지사항 팝업</a>
+        </nav>"#;
+
+    fn keywords() -> Vec<KeywordMapping> {
+        vec![
+            KeywordMapping {
+                keyword: "장학".to_string(),
+                id: "scholarship".to_string(),
+                display_name: "장학공지".to_string(),
+            },
+            KeywordMapping {
+                keyword: "공지".to_string(),
+                id: "notice".to_string(),
+                display_name: "공지사항".to_string(),
+            },
+        ]
+    }
+
+    fn service<'a>(client: &'a Client, config: &DiscoveryConfig) -> BoardDiscoveryService<'a> {
+        BoardDiscoveryService::new(client, keywords(), SelectorDetector::default(), config)
+    }
+
+    #[test]
+    fn test_propose_boards_skips_articles_and_duplicates() {
+        let client = Client::new();
+        let service = service(&client, &DiscoveryConfig::default());
+        let document = Html::parse_document(LANDING_PAGE);
+        let boards = service.propose_boards(&document, "https://dept.yonsei.ac.kr/");
+
+        let urls: Vec<&str> = boards.iter().map(|b| b.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://dept.yonsei.ac.kr/board/notice",
+                "https://dept.yonsei.ac.kr/board/scholarship",
+            ]
+        );
+        assert_eq!(boards[0].id, "notice");
+        assert_eq!(boards[1].id, "scholarship");
+    }
+
+    #[test]
+    fn test_blacklist_regex_anchored_vs_substring() {
+        let html = r#"
+            <a href="/curriculum/sequence/notice">교과공지</a>
+            <a href="/board/notice/list.do?seq=15">공지 15</a>"#;
+        let document = Html::parse_document(html);
+        let client = Client::new();
+
+        // The substring "seq" also rejects the legitimate /sequence/ board.
+        let substring = DiscoveryConfig {
+            blacklist_patterns: vec!["seq".to_string()],
+            ..DiscoveryConfig::default()
+        };
+        let boards = service(&client, &substring).propose_boards(&document, "https://a.ac.kr/");
+        assert!(boards.is_empty());
+
+        // Anchored to the query parameter, only the article link is rejected.
+        let anchored = DiscoveryConfig {
+            blacklist_patterns: Vec::new(),
+            blacklist_regex: vec![r"[?&]seq=\d+".to_string()],
+            ..DiscoveryConfig::default()
+        };
+        let boards = service(&client, &anchored).propose_boards(&document, "https://a.ac.kr/");
+        let urls: Vec<&str> = boards.iter().map(|b| b.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://a.ac.kr/curriculum/sequence/notice"]);
+    }
+
+    const WHITELIST_PAGE: &str = r#"
+        <a href="/bbs/notice/list.do">공지사항</a>
+        <a href="/bbs/notice/view.do?articleNo=3">공지 3</a>
+        <a href="/about/notice.html">공지 안내</a>
+        <a href="/bbs/scholarship/list.do">장학공지</a>"#;
+
+    #[test]
+    fn test_whitelist_only() {
+        let client = Client::new();
+        let config = DiscoveryConfig {
+            blacklist_patterns: Vec::new(),
+            whitelist_patterns: vec!["/bbs/".to_string()],
+            ..DiscoveryConfig::default()
+        };
+        let document = Html::parse_document(WHITELIST_PAGE);
+        let boards = service(&client, &config).propose_boards(&document, "https://a.ac.kr/");
+
+        let urls: Vec<&str> = boards.iter().map(|b| b.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://a.ac.kr/bbs/notice/list.do",
+                "https://a.ac.kr/bbs/notice/view.do?articleNo=3",
+                "https://a.ac.kr/bbs/scholarship/list.do",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_whitelist_then_blacklist() {
+        let client = Client::new();
+        let config = DiscoveryConfig {
+            whitelist_patterns: vec!["/bbs/".to_string()],
+            ..DiscoveryConfig::default()
+        };
+        let document = Html::parse_document(WHITELIST_PAGE);
+        let boards = service(&client, &config).propose_boards(&document, "https://a.ac.kr/");
+
+        let urls: Vec<&str> = boards.iter().map(|b| b.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://a.ac.kr/bbs/notice/list.do",
+                "https://a.ac.kr/bbs/scholarship/list.do",
+            ]
+        );
     }
 }
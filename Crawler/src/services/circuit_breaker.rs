@@ -0,0 +1,190 @@
+// src/services/circuit_breaker.rs
+
+//! Per-domain circuit breaker.
+//!
+//! After a configured number of consecutive failures on a domain, the circuit
+//! "opens" and further requests to that domain are skipped, either for the
+//! rest of the run or until an exponentially growing cooldown elapses. The
+//! circuit is then half-open: one trial request decides whether it closes or
+//! opens again.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::models::CrawlerConfig;
+
+#[derive(Debug, Default)]
+struct DomainState {
+    consecutive_failures: u32,
+    /// Number of times the circuit has opened (drives the backoff)
+    trips: u32,
+    /// `None` while closed; `Some(None)` when open for the rest of the run
+    open_until: Option<Option<Instant>>,
+    /// The cooldown elapsed and a trial request is in flight
+    half_open: bool,
+}
+
+/// Tracks consecutive failures per domain and skips domains that keep failing.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Option<Duration>,
+    domains: Mutex<HashMap<String, DomainState>>,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker that opens after `threshold` consecutive failures
+    /// (0 disables it). Without a cooldown an open circuit never closes.
+    pub fn new(threshold: u32, cooldown: Option<Duration>) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            domains: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a breaker from the crawler settings.
+    pub fn from_config(config: &CrawlerConfig) -> Self {
+        let cooldown = (config.circuit_breaker_cooldown_secs > 0)
+            .then(|| Duration::from_secs(config.circuit_breaker_cooldown_secs));
+        Self::new(config.circuit_breaker_threshold, cooldown)
+    }
+
+    /// Whether a request to `domain` may proceed.
+    ///
+    /// Once a cooldown elapses exactly one trial request is let through and
+    /// the rest wait for its outcome; a failed trial re-opens the circuit
+    /// with a doubled cooldown.
+    pub fn allow(&self, domain: &str) -> bool {
+        let mut domains = self.domains.lock().unwrap();
+        let Some(state) = domains.get_mut(domain) else {
+            return true;
+        };
+        match state.open_until {
+            None => true,
+            Some(Some(until)) if !state.half_open && Instant::now() >= until => {
+                state.half_open = true;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    /// Record a successful request, closing the circuit for `domain`.
+    pub fn record_success(&self, domain: &str) {
+        if let Some(state) = self.domains.lock().unwrap().get_mut(domain) {
+            state.consecutive_failures = 0;
+            state.open_until = None;
+            state.half_open = false;
+        }
+    }
+
+    /// Record a failed request; returns `true` if this opened the circuit.
+    pub fn record_failure(&self, domain: &str) -> bool {
+        if self.threshold == 0 {
+            return false;
+        }
+        let mut domains = self.domains.lock().unwrap();
+        let state = domains.entry(domain.to_string()).or_default();
+        state.consecutive_failures += 1;
+        if !state.half_open
+            && (state.open_until.is_some() || state.consecutive_failures < self.threshold)
+        {
+            return false;
+        }
+
+        state.half_open = false;
+        state.trips += 1;
+        state.open_until = Some(self.cooldown.map(|base| {
+            let factor = 2u32.saturating_pow(state.trips - 1);
+            Instant::now() + base.saturating_mul(factor)
+        }));
+        true
+    }
+
+    /// Domains whose circuit has opened at least once, sorted.
+    pub fn opened_domains(&self) -> Vec<String> {
+        let mut opened: Vec<String> = self
+            .domains
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, state)| state.trips > 0)
+            .map(|(domain, _)| domain.clone())
+            .collect();
+        opened.sort();
+        opened
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_threshold_and_success_resets() {
+        let breaker = CircuitBreaker::new(3, None);
+        assert!(!breaker.record_failure("a.ac.kr"));
+        breaker.record_success("a.ac.kr");
+        assert!(!breaker.record_failure("a.ac.kr"));
+        assert!(!breaker.record_failure("a.ac.kr"));
+        assert!(breaker.record_failure("a.ac.kr"));
+
+        assert!(!breaker.allow("a.ac.kr"));
+        assert!(breaker.allow("b.ac.kr"));
+        assert_eq!(breaker.opened_domains(), vec!["a.ac.kr".to_string()]);
+    }
+
+    #[test]
+    fn test_cooldown_lets_trial_through_and_reopens() {
+        let breaker = CircuitBreaker::new(1, Some(Duration::ZERO));
+        assert!(breaker.record_failure("a.ac.kr"));
+        // Zero cooldown has already elapsed: one trial request is allowed.
+        assert!(breaker.allow("a.ac.kr"));
+        assert!(breaker.record_failure("a.ac.kr"));
+        assert_eq!(breaker.domains.lock().unwrap()["a.ac.kr"].trips, 2);
+    }
+
+    #[test]
+    fn test_half_open_allows_a_single_trial() {
+        let breaker = CircuitBreaker::new(1, Some(Duration::ZERO));
+        assert!(breaker.record_failure("a.ac.kr"));
+
+        let allowed = (0..5).filter(|_| breaker.allow("a.ac.kr")).count();
+        assert_eq!(allowed, 1);
+
+        // The trial succeeded: the circuit closes for everyone
+        breaker.record_success("a.ac.kr");
+        assert!((0..5).all(|_| breaker.allow("a.ac.kr")));
+    }
+
+    #[test]
+    fn test_failed_trial_reopens_even_below_threshold() {
+        let breaker = CircuitBreaker::new(3, Some(Duration::from_secs(60)));
+        for _ in 0..3 {
+            breaker.record_failure("a.ac.kr");
+        }
+        {
+            let mut domains = breaker.domains.lock().unwrap();
+            let state = domains.get_mut("a.ac.kr").unwrap();
+            state.open_until = Some(Some(Instant::now()));
+            state.consecutive_failures = 0;
+        }
+
+        assert!(breaker.allow("a.ac.kr"));
+        assert!(breaker.record_failure("a.ac.kr"));
+        assert!(!breaker.allow("a.ac.kr"));
+        assert_eq!(breaker.domains.lock().unwrap()["a.ac.kr"].trips, 2);
+    }
+
+    #[test]
+    fn test_zero_threshold_disables() {
+        let breaker = CircuitBreaker::new(0, None);
+        for _ in 0..10 {
+            assert!(!breaker.record_failure("a.ac.kr"));
+        }
+        assert!(breaker.allow("a.ac.kr"));
+        assert!(breaker.opened_domains().is_empty());
+    }
+}
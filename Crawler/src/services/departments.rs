@@ -3,6 +3,11 @@
 //! Department crawler service.
 //!
 //! Crawls campus pages to discover departments and their homepage URLs.
+//! Campuses may instead point at an index page (HTML sitemap) or an XML
+//! `sitemap.xml` (optionally gzipped) listing department homepages.
+
+use std::collections::HashSet;
+use std::time::Duration;
 
 use futures::stream::{self, StreamExt, TryStreamExt};
 use regex::Regex;
@@ -10,25 +15,42 @@ use reqwest::Client;
 use scraper::{ElementRef, Html, Selector};
 
 use crate::error::Result;
-use crate::models::{Campus, CampusInfo, College, Department};
-use crate::utils::{http::fetch_page_async, log};
+use crate::models::{Campus, CampusInfo, College, CrawlerConfig, Department};
+use crate::utils::{
+    http::fetch_page_async,
+    log,
+    sitemap::{self, fetch_sitemap_async},
+    url,
+};
 
 /// Service for crawling campus department information.
 pub struct DepartmentCrawler<'a> {
     client: &'a Client,
+    max_concurrent: usize,
+    request_delay: Duration,
 }
 
 impl<'a> DepartmentCrawler<'a> {
-    /// Create a new department crawler.
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
+    /// Create a new department crawler honoring the discovery concurrency
+    /// and request delay settings.
+    pub fn new(client: &'a Client, config: &CrawlerConfig) -> Self {
+        Self {
+            client,
+            max_concurrent: config.max_concurrent_discovery.max(1),
+            request_delay: Duration::from_millis(config.request_delay_ms),
+        }
+    }
+
+    /// Number of campuses crawled concurrently.
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
     }
 
     /// Crawl all campuses and return their departments.
     pub async fn crawl_all(&self, campuses: &[CampusInfo]) -> Result<Vec<Campus>> {
         stream::iter(campuses)
             .map(|info| self.crawl_campus(info))
-            .buffer_unordered(5) // Concurrently crawl up to 5 campuses
+            .buffer_unordered(self.max_concurrent)
             .try_collect()
             .await
     }
@@ -36,14 +58,33 @@ impl<'a> DepartmentCrawler<'a> {
     /// Crawl a single campus.
     async fn crawl_campus(&self, info: &CampusInfo) -> Result<Campus> {
         log::info(&format!("Crawling {}...", info.name));
-        let document = fetch_page_async(self.client, &info.url).await?;
+        if !self.request_delay.is_zero() {
+            tokio::time::sleep(self.request_delay).await;
+        }
 
         let mut campus = Campus {
             campus: info.name.clone(),
+            enabled: true,
             colleges: Vec::new(),
             departments: Vec::new(),
         };
 
+        if let Some(sitemap_url) = &info.sitemap_url {
+            let body = fetch_sitemap_async(self.client, sitemap_url).await?;
+            let dept_info = if Self::is_xml_sitemap(sitemap_url, &body) {
+                Self::extract_departments_from_xml(&body, sitemap_url)
+            } else {
+                Self::extract_departments_from_index(&Html::parse_document(&body), sitemap_url)
+            };
+            self.group_into_colleges(&mut campus, dept_info);
+
+            let count = campus.department_count();
+            log::info(&format!("  Found {count} departments in {}", info.name));
+            return Ok(campus);
+        }
+
+        let document = fetch_page_async(self.client, &info.url).await?;
+
         let Some(main_elem) = self.find_main_content(&document) else {
             log::error(&format!(
                 "  Cannot find main content area for {}",
@@ -67,8 +108,104 @@ impl<'a> DepartmentCrawler<'a> {
         document.select(&main_selector).next()
     }
 
+    fn is_xml_sitemap(url: &str, body: &str) -> bool {
+        let head = body.trim_start();
+        let url = url.to_lowercase();
+        url.ends_with(".xml")
+            || url.ends_with(".xml.gz")
+            || head.starts_with("<?xml")
+            || head.starts_with("<urlset")
+            || sitemap::is_sitemap_index(head)
+    }
+
+    /// Extract department homepages from an XML sitemap.
+    ///
+    /// Every `<loc>` on a host other than the sitemap's own is taken as a
+    /// department homepage (one per host), named after its subdomain.
+    fn extract_departments_from_xml(xml: &str, sitemap_url: &str) -> Vec<(String, String, String)> {
+        let index_host = url::get_domain(sitemap_url);
+        let mut seen_hosts = HashSet::new();
+
+        sitemap::parse_sitemap(xml)
+            .into_iter()
+            .filter_map(|loc| {
+                let parsed = ::url::Url::parse(&loc).ok()?;
+                let host = parsed.host_str()?.to_lowercase();
+                if Some(&host) == index_host.as_ref() || !seen_hosts.insert(host.clone()) {
+                    return None;
+                }
+                let name = host.split('.').next().unwrap_or(&host).to_string();
+                Some((
+                    String::new(),
+                    name,
+                    format!("{}://{host}/", parsed.scheme()),
+                ))
+            })
+            .collect()
+    }
+
+    /// Extract department homepages from an HTML index page.
+    ///
+    /// Anchors pointing off the index host are departments; the nearest
+    /// preceding heading ending in "대학" names their college.
+    fn extract_departments_from_index(
+        document: &Html,
+        index_url: &str,
+    ) -> Vec<(String, String, String)> {
+        let college_pattern = Regex::new(r"([가-힣]+대학)$").unwrap();
+        let index_host = url::get_domain(index_url);
+        let mut seen_urls = HashSet::new();
+        let mut current_college = String::new();
+        let mut results = Vec::new();
+
+        for element in document
+            .root_element()
+            .descendants()
+            .filter_map(ElementRef::wrap)
+        {
+            let text = element.text().collect::<String>().trim().to_string();
+            match element.value().name() {
+                "h1" | "h2" | "h3" | "h4" if college_pattern.is_match(&text) => {
+                    current_college = text;
+                }
+                "a" => {
+                    let Some(href) = element.value().attr("href") else {
+                        continue;
+                    };
+                    let dept_url = url::resolve(index_url, href);
+                    if text.is_empty()
+                        || !dept_url.starts_with("http")
+                        || url::get_domain(&dept_url) == index_host
+                        || !seen_urls.insert(url::normalize_for_dedup(&dept_url))
+                    {
+                        continue;
+                    }
+                    results.push((current_college.clone(), text, dept_url));
+                }
+                _ => {}
+            }
+        }
+        results
+    }
+
+    /// Group `(college, department, url)` tuples into the campus; departments
+    /// without a college are attached to the campus directly.
     fn group_into_colleges(&self, campus: &mut Campus, dept_info: Vec<(String, String, String)>) {
         for (college_name, dept_name, dept_url) in dept_info {
+            if college_name.is_empty() {
+                if campus.departments.iter().any(|d| d.name == dept_name) {
+                    continue;
+                }
+                let dept_id = Self::generate_department_id(&dept_name, &dept_url);
+                campus.departments.push(Department {
+                    id: dept_id,
+                    name: dept_name,
+                    url: dept_url,
+                    boards: Vec::new(),
+                });
+                continue;
+            }
+
             // Find or create college
             let college_idx = campus
                 .colleges
@@ -182,15 +319,93 @@ impl<'a> DepartmentCrawler<'a> {
 
     /// Generate a unique department ID from name or URL.
     fn generate_department_id(name: &str, url: &str) -> String {
-        if url != "NOT_FOUND" {
-            if let Ok(re) = Regex::new(r"https?://([^.]+)\.yonsei\.ac\.kr") {
-                if let Some(caps) = re.captures(url) {
-                    if let Some(subdomain) = caps.get(1) {
-                        return format!("yonsei_{}", subdomain.as_str().to_lowercase());
-                    }
-                }
-            }
+        if url != "NOT_FOUND"
+            && let Ok(re) = Regex::new(r"https?://([^.]+)\.yonsei\.ac\.kr")
+            && let Some(caps) = re.captures(url)
+            && let Some(subdomain) = caps.get(1)
+        {
+            return format!("yonsei_{}", subdomain.as_str().to_lowercase());
         }
         format!("yonsei_{}", name.to_lowercase().replace(' ', "_"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_departments_from_xml_sitemap() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+              <url><loc>https://www.yonsei.ac.kr/sc/186/subview.do</loc></url>
+              <url><loc>https://cs.yonsei.ac.kr/</loc></url>
+              <url><loc>https://cs.yonsei.ac.kr/board/list.do?id=1&amp;page=2</loc></url>
+              <url><loc> https://math.yonsei.ac.kr/index.do </loc></url>
+            </urlset>"#;
+
+        let depts = DepartmentCrawler::extract_departments_from_xml(
+            xml,
+            "https://www.yonsei.ac.kr/sitemap.xml",
+        );
+        assert_eq!(
+            depts,
+            vec![
+                (
+                    String::new(),
+                    "cs".to_string(),
+                    "https://cs.yonsei.ac.kr/".to_string()
+                ),
+                (
+                    String::new(),
+                    "math".to_string(),
+                    "https://math.yonsei.ac.kr/".to_string()
+                ),
+            ]
+        );
+        assert!(DepartmentCrawler::is_xml_sitemap(
+            "https://x/sitemap.xml",
+            ""
+        ));
+    }
+
+    #[test]
+    fn test_group_html_index_into_colleges() {
+        let html = r#"
+            <h2>문과대학</h2>
+            <ul>
+              <li><a href="https://korean.yonsei.ac.kr">국어국문학과</a></li>
+              <li><a href="https://history.yonsei.ac.kr/">사학과</a></li>
+            </ul>
+            <h2>공과대학</h2>
+            <ul>
+              <li><a href="https://cs.yonsei.ac.kr">컴퓨터과학과</a></li>
+              <li><a href="https://cs.yonsei.ac.kr/#main">컴퓨터과학과 바로가기</a></li>
+              <li><a href="/sc/contact.do">문의</a></li>
+            </ul>"#;
+
+        let document = Html::parse_document(html);
+        let dept_info = DepartmentCrawler::extract_departments_from_index(
+            &document,
+            "https://www.yonsei.ac.kr/sc/sitemap.do",
+        );
+        assert!(!DepartmentCrawler::is_xml_sitemap(
+            "https://www.yonsei.ac.kr/sc/sitemap.do",
+            html
+        ));
+
+        let client = Client::new();
+        let crawler = DepartmentCrawler::new(&client, &CrawlerConfig::default());
+        let mut campus = Campus {
+            campus: "신촌캠퍼스".to_string(),
+            ..Campus::default()
+        };
+        crawler.group_into_colleges(&mut campus, dept_info);
+
+        assert_eq!(campus.colleges.len(), 2);
+        assert_eq!(campus.colleges[0].name, "문과대학");
+        assert_eq!(campus.colleges[0].departments.len(), 2);
+        assert_eq!(campus.colleges[1].departments.len(), 1);
+        assert_eq!(campus.colleges[1].departments[0].id, "yonsei_cs");
+    }
+}
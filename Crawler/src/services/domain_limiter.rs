@@ -0,0 +1,112 @@
+// src/services/domain_limiter.rs
+
+//! Per-domain concurrency limit.
+//!
+//! Caps in-flight requests to each host independently of the global
+//! `max_concurrent`, so one university with many boards cannot take every
+//! slot while other hosts proceed.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::models::CrawlerConfig;
+
+/// Hands out per-domain permits, one semaphore per domain.
+#[derive(Debug)]
+pub struct DomainLimiter {
+    limit: Option<usize>,
+    semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl DomainLimiter {
+    /// Allow at most `limit` concurrent requests per domain (`None` = no limit).
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit: limit.map(|limit| limit.max(1)),
+            semaphores: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a limiter from the crawler settings.
+    pub fn from_config(config: &CrawlerConfig) -> Self {
+        Self::new(config.max_concurrent_per_domain)
+    }
+
+    /// Wait for a slot on `domain`; the slot is released when the permit drops.
+    ///
+    /// Returns `None` immediately when there is no limit.
+    pub async fn acquire(&self, domain: &str) -> Option<OwnedSemaphorePermit> {
+        let limit = self.limit?;
+        let semaphore = {
+            let mut semaphores = self.semaphores.lock().unwrap_or_else(|e| e.into_inner());
+            Arc::clone(
+                semaphores
+                    .entry(domain.to_string())
+                    .or_insert_with(|| Arc::new(Semaphore::new(limit))),
+            )
+        };
+        semaphore.acquire_owned().await.ok()
+    }
+}
+
+/// Reorder `items` round-robin across their domains, keeping each domain's
+/// own order.
+///
+/// Permits are awaited inside slots already taken from `max_concurrent`, so
+/// a run of same-host jobs would otherwise fill every slot while all but one
+/// wait on the domain limit.
+pub(crate) fn interleave_by_domain<T>(items: Vec<T>, domain: impl Fn(&T) -> String) -> Vec<T> {
+    let total = items.len();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut queues: Vec<VecDeque<T>> = Vec::new();
+    for item in items {
+        let slot = *index.entry(domain(&item)).or_insert_with(|| {
+            queues.push(VecDeque::new());
+            queues.len() - 1
+        });
+        queues[slot].push_back(item);
+    }
+    let mut interleaved = Vec::with_capacity(total);
+    while interleaved.len() < total {
+        interleaved.extend(queues.iter_mut().filter_map(VecDeque::pop_front));
+    }
+    interleaved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleave_alternates_domains_in_order() {
+        let urls = ["a/1", "a/2", "a/3", "b/1", "c/1", "b/2"];
+        let domain = |url: &&str| url.split('/').next().unwrap().to_string();
+        assert_eq!(
+            interleave_by_domain(urls.to_vec(), domain),
+            ["a/1", "b/1", "c/1", "a/2", "b/2", "a/3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn limits_each_domain_separately() {
+        let limiter = DomainLimiter::new(Some(1));
+        let held = limiter.acquire("a.yonsei.ac.kr").await;
+        assert!(held.is_some());
+
+        // Another domain is not blocked by the first
+        assert!(limiter.acquire("b.yonsei.ac.kr").await.is_some());
+
+        let blocked = tokio::time::timeout(
+            std::time::Duration::from_millis(20),
+            limiter.acquire("a.yonsei.ac.kr"),
+        )
+        .await;
+        assert!(blocked.is_err());
+
+        drop(held);
+        assert!(limiter.acquire("a.yonsei.ac.kr").await.is_some());
+        assert!(DomainLimiter::new(None).acquire("a").await.is_none());
+    }
+}
@@ -0,0 +1,129 @@
+// src/services/hooks.rs
+
+//! Post-processing hooks for crawled notices.
+//!
+//! Hooks run on every notice after extraction and before output, so users of
+//! the library can translate, enrich or rewrite notices without touching the
+//! crawler itself.
+
+use crate::models::{CategoryRule, Notice, map_category};
+
+/// A transformation applied to each crawled notice.
+pub trait NoticeHook: Send + Sync {
+    fn apply(&self, notice: &mut Notice);
+}
+
+/// Run `hooks`, in order, over every notice.
+pub fn process_notices(mut notices: Vec<Notice>, hooks: &[Box<dyn NoticeHook>]) -> Vec<Notice> {
+    for notice in &mut notices {
+        for hook in hooks {
+            hook.apply(notice);
+        }
+    }
+    notices
+}
+
+/// Sets `Notice::category` from keywords in the board name (see
+/// [`map_category`]), leaving categories set by earlier hooks alone.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeywordCategorizer;
+
+impl NoticeHook for KeywordCategorizer {
+    fn apply(&self, notice: &mut Notice) {
+        if notice.category.is_none() {
+            notice.category = Some(map_category(&notice.board_name).as_str().to_string());
+        }
+    }
+}
+
+/// Sets `Notice::category` from the first [`CategoryRule`] matching the
+/// title; notices matching no rule keep their category.
+#[derive(Debug, Clone)]
+pub struct RuleCategorizer {
+    rules: Vec<CategoryRule>,
+}
+
+impl RuleCategorizer {
+    pub fn new(rules: Vec<CategoryRule>) -> Self {
+        Self { rules }
+    }
+}
+
+impl NoticeHook for RuleCategorizer {
+    fn apply(&self, notice: &mut Notice) {
+        if let Some(rule) = self.rules.iter().find(|rule| rule.matches(&notice.title)) {
+            notice.category = Some(rule.category.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct UppercaseTitles;
+
+    impl NoticeHook for UppercaseTitles {
+        fn apply(&self, notice: &mut Notice) {
+            notice.title = notice.title.to_uppercase();
+        }
+    }
+
+    fn notice(board_name: &str, title: &str) -> Notice {
+        Notice {
+            campus: "신촌캠퍼스".to_string(),
+            department_id: "cs".to_string(),
+            department_name: "컴퓨터과학과".to_string(),
+            board_id: "notice".to_string(),
+            board_name: board_name.to_string(),
+            title: title.to_string(),
+            date: "2024-03-01".to_string(),
+            link: "https://cs.yonsei.ac.kr/notice/1".to_string(),
+            ..Notice::default()
+        }
+    }
+
+    #[test]
+    fn hooks_run_in_order_on_every_notice() {
+        let hooks: Vec<Box<dyn NoticeHook>> =
+            vec![Box::new(UppercaseTitles), Box::new(KeywordCategorizer)];
+        let notices = process_notices(
+            vec![
+                notice("장학공지", "Spring scholarship"),
+                notice("공지사항", "Midterm schedule"),
+            ],
+            &hooks,
+        );
+
+        let titles: Vec<_> = notices.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, ["SPRING SCHOLARSHIP", "MIDTERM SCHEDULE"]);
+        assert_eq!(notices[0].category.as_deref(), Some("scholarship"));
+        assert_eq!(notices[1].category.as_deref(), Some("other"));
+    }
+
+    #[test]
+    fn rule_categorizer_uses_first_matching_rule() {
+        let rule = |category: &str, keywords: &[&str]| CategoryRule {
+            category: category.to_string(),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        };
+        let hook = RuleCategorizer::new(vec![
+            rule("scholarship", &["장학"]),
+            rule("recruitment", &["채용", "Intern"]),
+            rule("event", &["채용설명회", "특강"]),
+        ]);
+
+        let mut recruiting = notice("공지사항", "2024 채용설명회 안내");
+        hook.apply(&mut recruiting);
+        // Also matches the third rule; rule order breaks the tie
+        assert_eq!(recruiting.category.as_deref(), Some("recruitment"));
+
+        let mut intern = notice("공지사항", "Summer internship");
+        hook.apply(&mut intern);
+        assert_eq!(intern.category.as_deref(), Some("recruitment"));
+
+        let mut unmatched = notice("공지사항", "도서관 휴관 안내");
+        hook.apply(&mut unmatched);
+        assert_eq!(unmatched.category, None);
+    }
+}
@@ -0,0 +1,82 @@
+// src/services/jitter.rs
+
+//! Randomized retry backoff.
+//!
+//! Boards on the same host that fail together would otherwise retry on the
+//! same schedule and hit the server together again; "full jitter" waits a
+//! random time between zero and the computed backoff instead.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Source of jittered retry delays, shared by all requests of a crawl.
+#[derive(Debug)]
+pub struct RetryJitter {
+    rng: Mutex<StdRng>,
+}
+
+impl RetryJitter {
+    /// Jitter seeded from the operating system.
+    pub fn new() -> Self {
+        Self::from_rng(StdRng::from_os_rng())
+    }
+
+    /// Jitter with a fixed seed, producing the same delays on every run.
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: StdRng) -> Self {
+        Self {
+            rng: Mutex::new(rng),
+        }
+    }
+
+    /// A random delay between zero and `backoff`, inclusive.
+    pub fn full(&self, backoff: Duration) -> Duration {
+        let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+        backoff.mul_f64(rng.random_range(0.0..=1.0))
+    }
+}
+
+impl Default for RetryJitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn seeded_jitter_spreads_concurrent_retries() {
+        let backoff = Duration::from_secs(5);
+        let jitter = Arc::new(RetryJitter::seeded(42));
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let jitter = Arc::clone(&jitter);
+                tokio::spawn(async move { jitter.full(backoff) })
+            })
+            .collect();
+        let mut delays = Vec::new();
+        for task in tasks {
+            delays.push(task.await.unwrap());
+        }
+
+        assert!(delays.iter().all(|delay| *delay <= backoff));
+        delays.sort();
+        delays.dedup();
+        assert_eq!(delays.len(), 8, "retries should not share a delay");
+
+        // The same seed replays the same sequence
+        let (a, b) = (RetryJitter::seeded(42), RetryJitter::seeded(42));
+        for _ in 0..4 {
+            assert_eq!(a.full(backoff), b.full(backoff));
+        }
+    }
+}
@@ -5,15 +5,32 @@
 //! This module contains the business logic for:
 //! - Board discovery (`BoardDiscoveryService`)
 //! - Department crawling (`DepartmentCrawler`)
-//! - Notice fetching (`NoticeCrawler`)
+//! - Notice fetching (`NoticeCrawler`), also as a stream (`NoticeStream`)
 //! - CMS selector detection (`SelectorDetector`)
+//! - Per-domain failure tracking (`CircuitBreaker`)
+//! - Per-domain concurrency limits (`DomainLimiter`)
+//! - Concurrency ramp-up at the start of a run (`ConcurrencyRamp`)
+//! - Randomized retry backoff (`RetryJitter`)
+//! - Notice post-processing (`NoticeHook`)
 
 mod boards;
+mod circuit_breaker;
 mod departments;
+mod domain_limiter;
+mod hooks;
+mod jitter;
 mod notices;
+mod ramp;
 mod selectors;
+mod stream;
 
 pub use boards::BoardDiscoveryService;
+pub use circuit_breaker::CircuitBreaker;
 pub use departments::DepartmentCrawler;
-pub use notices::NoticeCrawler;
+pub use domain_limiter::DomainLimiter;
+pub use hooks::{KeywordCategorizer, NoticeHook, RuleCategorizer, process_notices};
+pub use jitter::RetryJitter;
+pub use notices::{BoardExtraction, NoticeCrawler, extract_notices};
+pub use ramp::ConcurrencyRamp;
 pub use selectors::SelectorDetector;
+pub use stream::NoticeStream;
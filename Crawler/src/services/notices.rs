@@ -5,60 +5,244 @@
 //! Fetches notices from department boards using configured CSS selectors.
 
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
-use std::time::Duration;
+use std::path::Path;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
 
-use futures::stream::{self, StreamExt};
+use chrono::{NaiveDateTime, SecondsFormat};
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
+use regex::Regex;
 use reqwest::Client;
-use scraper::Selector;
+use scraper::{Html, Selector};
+use sha2::{Digest, Sha256};
 
-use crate::error::{AppError, Result};
+use crate::error::{AppError, BoardErrorKind, Result};
 use crate::models::{
-    Board, Campus, Config, CrawlError, CrawlOutcome, CrawlStage, DepartmentRef, Notice,
+    Board, BoardFormat, BoardTiming, Campus, CleaningConfig, Config, CrawlError, CrawlOutcome,
+    CrawlStage, CrawlState, DepartmentRef, DiscoveryConfig, EmptyBoard, HttpMethod, JsonPaths,
+    Notice,
 };
-use crate::utils::url::extract_notice_id;
-use crate::utils::{http, log, resolve_url};
+use crate::services::domain_limiter::interleave_by_domain;
+use crate::services::{
+    CircuitBreaker, ConcurrencyRamp, DomainLimiter, NoticeHook, NoticeStream, RetryJitter,
+    process_notices,
+};
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::date::parse_date;
+use crate::utils::http::{
+    HtmlFetcher, HtmlResponse, RawResponse, RequestOptions, ReqwestHtmlFetcher, decode_text,
+};
+use crate::utils::json_path;
+use crate::utils::progress::Progress;
+use crate::utils::robots::{parse_crawl_delay, robots_url};
+use crate::utils::url::{
+    extract_notice_id, get_domain, is_valid_file_link, is_valid_link, set_query_param,
+};
+use crate::utils::{fs, log, resolve_url};
+
+/// First anchor inside a title cell, used when a board has no `link_selector`.
+static ANCHOR_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("a").expect("valid anchor selector"));
+
+static BASE_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("base[href]").expect("valid base selector"));
+
+/// Backoff after the first HTTP 429 without a usable `Retry-After` header,
+/// doubled on each further attempt and then jittered.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// Longest `Retry-After` honored; longer requests are cut to this.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(120);
 
 #[derive(Clone)]
 struct BoardSelectors {
-    row: Selector,
-    title: Selector,
-    date: Selector,
+    /// Row, title and date selectors; empty/unset for JSON boards
+    rows: Vec<Selector>,
+    title: Option<Selector>,
+    date: Option<Selector>,
     author: Option<Selector>,
     link: Option<Selector>,
     body: Option<Selector>,
+    link_regex: Option<Regex>,
+}
+
+/// Result of extracting notices from one board list page.
+#[derive(Debug, Clone, Default)]
+pub struct BoardExtraction {
+    /// Notices parsed from the matched rows
+    pub notices: Vec<Notice>,
+
+    /// Number of elements matched by the row selector
+    pub rows_matched: usize,
+
+    /// The page was unchanged since the last run and the previous notices
+    /// were reused without parsing (`crawler.skip_unchanged`)
+    pub unchanged: bool,
+
+    /// Notices whose date was cleared as implausible
+    pub implausible_dates: usize,
+
+    /// Rows dropped as repeats of a notice already on the board (e.g. a
+    /// pinned notice listed again in its regular position)
+    pub duplicate_rows: usize,
 }
 
-struct BoardListResult {
-    notices: Vec<Notice>,
-    row_total: usize,
-    row_failures: usize,
+impl BoardExtraction {
+    /// Number of rows that produced a notice.
+    pub fn notices_extracted(&self) -> usize {
+        self.notices.len()
+    }
+
+    /// Number of matched rows that failed to parse; repeated rows dropped
+    /// as duplicates are not failures.
+    pub fn rows_failed(&self) -> usize {
+        self.rows_matched - self.notices.len() - self.duplicate_rows
+    }
+
+    /// Why the extraction is empty, if it is.
+    pub fn empty_kind(&self) -> Option<BoardErrorKind> {
+        match (self.rows_matched, self.notices_extracted()) {
+            (0, _) => Some(BoardErrorKind::EmptyRows),
+            (_, 0) => Some(BoardErrorKind::NoNotices),
+            _ => None,
+        }
+    }
+
+    /// Explain an empty extraction, pointing at the selector most likely wrong.
+    pub fn diagnostic(&self) -> Option<String> {
+        match (self.rows_matched, self.notices_extracted()) {
+            (0, _) => Some("0 rows matched — check row_selector".to_string()),
+            (rows, 0) => Some(format!(
+                "{rows} rows matched but 0 notices extracted — check title/date selectors"
+            )),
+            _ if self.implausible_dates > 0 => Some(format!(
+                "{} implausible dates cleared — check date_selector",
+                self.implausible_dates
+            )),
+            _ => None,
+        }
+    }
 }
 
 /// Service for crawling notices from department boards.
 pub struct NoticeCrawler {
     config: Arc<Config>,
-    client: Client,
+    fetcher: Arc<dyn HtmlFetcher>,
+    state: Mutex<CrawlState>,
+    clock: Arc<dyn Clock>,
+    hooks: Vec<Box<dyn NoticeHook>>,
+    /// robots.txt `Crawl-delay` per domain, fetched on first use
+    crawl_delays: Mutex<HashMap<String, Option<Duration>>>,
+    jitter: RetryJitter,
 }
 
 impl NoticeCrawler {
     /// Create a new notice crawler with the given configuration.
     pub fn new(config: Arc<Config>, client: Client) -> Result<Self> {
-        Ok(Self { config, client })
+        let fetcher = ReqwestHtmlFetcher::new(client, config.crawler.clone());
+        Ok(Self::with_fetcher(config, Arc::new(fetcher)))
     }
 
-    /// Fetch all notices from all campuses concurrently.
-    pub async fn fetch_all(&self, campuses: &[Campus]) -> Result<CrawlOutcome> {
-        let concurrency = self.config.crawler.max_concurrent.max(1);
-        let board_lookup = Arc::new(Self::build_board_lookup(campuses));
-        let (selector_cache, selector_errors, invalid_boards) =
-            Self::build_selector_cache(campuses);
-        let selector_cache = Arc::new(selector_cache);
+    /// Create a notice crawler that fetches pages through `fetcher`.
+    pub fn with_fetcher(config: Arc<Config>, fetcher: Arc<dyn HtmlFetcher>) -> Self {
+        Self {
+            config,
+            fetcher,
+            state: Mutex::new(CrawlState::default()),
+            clock: Arc::new(SystemClock),
+            hooks: Vec::new(),
+            crawl_delays: Mutex::new(HashMap::new()),
+            jitter: RetryJitter::new(),
+        }
+    }
 
-        // Stage 1: Fetch all notice lists from boards concurrently, but bounded by concurrency.
-        let board_jobs_all: Vec<_> = campuses
+    /// Use `clock` for timestamps such as `Notice::fetched_at`.
+    pub fn with_clock(self, clock: Arc<dyn Clock>) -> Self {
+        Self { clock, ..self }
+    }
+
+    /// Run `hooks`, in order, on every notice of a crawl before it is returned.
+    pub fn with_hooks(self, hooks: Vec<Box<dyn NoticeHook>>) -> Self {
+        Self { hooks, ..self }
+    }
+
+    /// Draw retry backoff jitter from `jitter` (e.g. a seeded one in tests).
+    pub fn with_jitter(self, jitter: RetryJitter) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// Start from previously saved crawl state (e.g. loaded from the state file).
+    pub fn with_state(self, state: CrawlState) -> Self {
+        Self {
+            state: Mutex::new(state),
+            ..self
+        }
+    }
+
+    /// Snapshot of the crawl state, updated as boards succeed.
+    pub fn state(&self) -> CrawlState {
+        self.lock_state().clone()
+    }
+
+    /// Number of boards (and notice details) fetched concurrently.
+    ///
+    /// Enforced by `buffer_unordered` on each stage's stream; during
+    /// `crawler.ramp_up_secs` a [`ConcurrencyRamp`] holds it lower.
+    fn concurrency(&self) -> usize {
+        self.config.crawler.max_concurrent.max(1)
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, CrawlState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Fetch the notices of a single board, including bodies when the board
+    /// has a body selector.
+    ///
+    /// `context` supplies the campus/college/department the notices belong to.
+    /// List page failures are returned as [`AppError::Board`]; a failed
+    /// notice body returns the fetcher's error.
+    pub async fn fetch(&self, board: &Board, context: DepartmentRef<'_>) -> Result<Vec<Notice>> {
+        let selectors = Self::compile_selectors(board, &mut Vec::new())
+            .map_err(|err| selector_failure(board, err))?;
+        let list = self
+            .fetch_board_list(context, board, &selectors)
+            .await
+            .map_err(|err| err.into_board_error(&board.id, &board.url))?;
+
+        let mut notices = Vec::with_capacity(list.notices.len());
+        for notice in list.notices {
+            notices.push(self.fetch_body(notice, board, &selectors).await?);
+        }
+        Ok(notices)
+    }
+
+    /// Fetch and extract a single board's list page(s) without notice bodies.
+    ///
+    /// Failures are returned as [`AppError::Board`].
+    pub async fn fetch_list(
+        &self,
+        board: &Board,
+        context: DepartmentRef<'_>,
+    ) -> Result<BoardExtraction> {
+        let selectors = Self::compile_selectors(board, &mut Vec::new())
+            .map_err(|err| selector_failure(board, err))?;
+        self.fetch_board_list(context, board, &selectors)
+            .await
+            .map_err(|err| err.into_board_error(&board.id, &board.url))
+    }
+
+    /// Boards of the campuses selected by `crawler.campuses`, skipping
+    /// disabled campuses.
+    pub fn boards_to_crawl<'a>(
+        &self,
+        campuses: &'a [Campus],
+    ) -> Vec<(DepartmentRef<'a>, &'a Board)> {
+        campuses
             .iter()
-            .flat_map(|c| c.all_departments())
+            .filter(|campus| campus.is_selected(&self.config.crawler.campuses))
+            .flat_map(|campus| campus.all_departments())
             .flat_map(|dept_ref| {
                 dept_ref
                     .dept
@@ -66,47 +250,223 @@ impl NoticeCrawler {
                     .iter()
                     .map(move |board| (dept_ref, board))
             })
-            .collect();
-        let board_jobs: Vec<_> = board_jobs_all
-            .into_iter()
-            .filter(|(_, board)| !invalid_boards.contains(board.id.as_str()))
-            .collect();
+            .collect()
+    }
+
+    /// Stream the notices of all selected boards as each board completes,
+    /// without collecting the whole crawl in memory.
+    ///
+    /// Each board is fetched as by [`fetch`](Self::fetch), up to
+    /// `max_concurrent` at a time, and its notices run through the hooks.
+    /// Notices already yielded by another board are skipped. Unlike
+    /// [`fetch_all`](Self::fetch_all) there is no run report: cooldowns,
+    /// circuit breaking and the run deadline do not apply.
+    pub fn notice_stream<'a>(&'a self, campuses: &'a [Campus]) -> NoticeStream<'a> {
+        let mut seen = HashSet::new();
+        let notices = stream::iter(self.boards_to_crawl(campuses))
+            .map(move |(dept_ref, board)| async move {
+                let notices = self.fetch(board, dept_ref).await?;
+                Ok(process_notices(notices, &self.hooks))
+            })
+            .buffer_unordered(self.concurrency())
+            .flat_map(|result: Result<Vec<Notice>>| match result {
+                Ok(notices) => stream::iter(notices.into_iter().map(Ok)).left_stream(),
+                Err(error) => stream::once(future::ready(Err(error))).right_stream(),
+            })
+            .filter(move |result| {
+                future::ready(match result {
+                    Ok(notice) => seen.insert(notice.canonical_id()),
+                    Err(_) => true,
+                })
+            });
+        NoticeStream::new(notices)
+    }
+
+    /// Fetch all notices from all campuses concurrently.
+    pub async fn fetch_all(&self, campuses: &[Campus]) -> Result<CrawlOutcome> {
+        let concurrency = self.concurrency();
+        let board_lookup = Arc::new(Self::build_board_lookup(campuses));
+        let (selector_cache, selector_errors, invalid_boards) =
+            Self::build_selector_cache(campuses);
+        let selector_cache = Arc::new(selector_cache);
+        let breaker = CircuitBreaker::from_config(&self.config.crawler);
+        let limiter = DomainLimiter::from_config(&self.config.crawler);
+        let ramp = ConcurrencyRamp::from_config(&self.config.crawler);
+        let deadline = self
+            .config
+            .crawler
+            .max_run_secs
+            .map(|secs| tokio::time::Instant::now() + Duration::from_secs(secs));
+
+        // Stage 1: Fetch all notice lists from boards concurrently, but bounded by concurrency.
+        let board_jobs_all = self.boards_to_crawl(campuses);
+        let cycle_start = self.clock.now();
+        let (mut board_jobs, cooling_down): (Vec<_>, Vec<_>) = {
+            let state = self.lock_state();
+            board_jobs_all
+                .into_iter()
+                .filter(|(_, board)| !invalid_boards.contains(board.id.as_str()))
+                .partition(|(dept_ref, board)| {
+                    board.min_interval_secs.is_none_or(|secs| {
+                        let key = CrawlState::board_key(&dept_ref.dept.id, &board.id);
+                        !state.in_cooldown(&key, secs, cycle_start)
+                    })
+                })
+        };
+        for (_, board) in &cooling_down {
+            log::debug(&format!("Board {} is in cooldown; skipped", board.name));
+        }
+        // Stable, so equal priorities keep site map order
+        board_jobs.sort_by_key(|(_, board)| std::cmp::Reverse(board.priority));
+        // Under a per-domain limit, hosts take turns within each priority
+        let per_domain_limit = self.config.crawler.max_concurrent_per_domain.is_some();
+        if per_domain_limit {
+            board_jobs = board_jobs
+                .chunk_by(|(_, a), (_, b)| a.priority == b.priority)
+                .flat_map(|tier| {
+                    interleave_by_domain(tier.to_vec(), |(_, board)| {
+                        get_domain(&board.url).unwrap_or_default()
+                    })
+                })
+                .collect();
+        }
 
         let mut outcome = CrawlOutcome {
             board_total: board_jobs.len() + invalid_boards.len(),
             board_failures: invalid_boards.len(),
+            board_skipped: cooling_down.len(),
             errors: selector_errors,
             ..CrawlOutcome::default()
         };
 
+        let progress = Progress::new(board_jobs.len());
+        let show_progress = self.config.logging.show_progress;
         let mut notice_buffer = Vec::new();
         let mut board_stream = stream::iter(board_jobs)
             .map(|(dept_ref, board)| {
                 let selector_cache = Arc::clone(&selector_cache);
+                let breaker = &breaker;
+                let limiter = &limiter;
+                let ramp = &ramp;
                 async move {
+                    let domain = get_domain(&board.url).unwrap_or_default();
+                    if !breaker.allow(&domain) {
+                        let error = AppError::crawl(
+                            "circuit_breaker",
+                            format!("Circuit open for {domain}; board skipped"),
+                        );
+                        return (dept_ref, board, CrawlStage::CircuitOpen, None, Err(error));
+                    }
+
                     let selectors = selector_cache.get(&board.id).cloned().ok_or_else(|| {
                         AppError::crawl("selector_cache", "Missing selector cache entry")
                     });
+                    let _ramp_permit = ramp.acquire().await;
+                    let _permit = limiter.acquire(&domain).await;
+                    let started = Instant::now();
                     let result = match selectors {
                         Ok(selectors) => self.fetch_board_list(dept_ref, board, &selectors).await,
                         Err(err) => Err(err),
                     };
-                    (board, result)
+                    let elapsed = started.elapsed();
+                    match &result {
+                        Ok(_) => breaker.record_success(&domain),
+                        Err(_) => {
+                            if breaker.record_failure(&domain) {
+                                log::warn(&format!(
+                                    "Circuit opened for {domain}; skipping its remaining boards"
+                                ));
+                            }
+                        }
+                    }
+                    (
+                        dept_ref,
+                        board,
+                        CrawlStage::BoardList,
+                        Some(elapsed),
+                        result,
+                    )
                 }
             })
             .buffer_unordered(concurrency);
 
-        while let Some((board, result)) = board_stream.next().await {
+        while let Some((dept_ref, board, stage, elapsed, result)) =
+            next_before(&mut board_stream, deadline, &mut outcome.cut_off).await
+        {
+            let snapshot = progress.complete();
+            if let Some(elapsed) = elapsed {
+                outcome.board_timings.push(BoardTiming {
+                    department_id: dept_ref.dept.id.clone(),
+                    board_id: board.id.clone(),
+                    board_name: board.name.clone(),
+                    duration_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+                });
+            }
+            if show_progress {
+                log::progress(&format!("{snapshot} {}", board.name));
+            }
             match result {
                 Ok(list_result) => {
-                    outcome.notice_total += list_result.row_total;
-                    outcome.notice_failures += list_result.row_failures;
+                    // An empty page is likely a broken selector; don't start a cooldown
+                    if list_result.empty_kind().is_none() {
+                        let key = CrawlState::board_key(&dept_ref.dept.id, &board.id);
+                        self.lock_state().record_success(&key, self.clock.now());
+                    }
+                    if let Some(diagnostic) = list_result.diagnostic() {
+                        log::warn(&format!(
+                            "Board {} ({}): {diagnostic}",
+                            board.name, board.url
+                        ));
+                        outcome.errors.push(CrawlError {
+                            stage: CrawlStage::BoardList,
+                            board_id: Some(board.id.clone()),
+                            board_name: Some(board.name.clone()),
+                            url: Some(board.url.clone()),
+                            notice_id: None,
+                            message: diagnostic,
+                            retryable: false,
+                            kind: list_result.empty_kind(),
+                        });
+                    }
+                    if let Some(expected) = board.expected_min_notices
+                        && list_result.notices_extracted() < expected
+                    {
+                        let message = format!(
+                            "{} notices extracted, expected at least {expected}",
+                            list_result.notices_extracted()
+                        );
+                        log::warn(&format!("Board {} ({}): {message}", board.name, board.url));
+                        outcome.errors.push(CrawlError {
+                            stage: CrawlStage::BelowExpectedCount,
+                            board_id: Some(board.id.clone()),
+                            board_name: Some(board.name.clone()),
+                            url: Some(board.url.clone()),
+                            notice_id: None,
+                            message,
+                            retryable: false,
+                            kind: None,
+                        });
+                    }
+                    outcome.notice_total += list_result.rows_matched;
+                    outcome.notice_failures += list_result.rows_failed();
+                    if list_result.empty_kind() == Some(BoardErrorKind::NoNotices) {
+                        outcome.empty_boards.push(EmptyBoard {
+                            campus: dept_ref.campus.to_string(),
+                            department_id: dept_ref.dept.id.clone(),
+                            board_id: board.id.clone(),
+                        });
+                    }
                     notice_buffer.extend(list_result.notices);
                 }
                 Err(error) => {
                     outcome.board_failures += 1;
+                    let stage = if matches!(error, AppError::UpstreamBodyTooShort { .. }) {
+                        CrawlStage::ShortResponse
+                    } else {
+                        stage
+                    };
                     outcome.errors.push(Self::build_error(
-                        CrawlStage::BoardList,
+                        stage,
                         Some(board),
                         Some(&board.url),
                         None,
@@ -128,9 +488,33 @@ impl NoticeCrawler {
                 deduped.push(notice);
             }
         }
+        if let Some(days) = self.config.filter.suppress_duplicate_titles_days {
+            let suppressed = self.suppress_repeated_titles(&mut deduped, days);
+            if suppressed > 0 {
+                log::info(&format!(
+                    "Suppressed {suppressed} notices repeating a title from the last {days} days"
+                ));
+            }
+        }
+
+        // Kept so a cut-off run still saves notices whose details never arrived
+        let unfinished = match deadline {
+            Some(_) => deduped.clone(),
+            None => Vec::new(),
+        };
+        let mut finished = HashSet::new();
+        let mut detailed = Vec::new();
+        if outcome.cut_off {
+            deduped.clear();
+        }
 
         // Stage 2: Fetch details for each notice concurrently.
         outcome.detail_total = deduped.len();
+        if per_domain_limit {
+            deduped = interleave_by_domain(deduped, |notice| {
+                get_domain(&notice.link).unwrap_or_default()
+            });
+        }
         let detailed_notices = stream::iter(deduped)
             .map(|notice| {
                 let board_lookup = Arc::clone(&board_lookup);
@@ -139,7 +523,12 @@ impl NoticeCrawler {
                 let board_id = notice.board_id.clone();
                 let board_name = notice.board_name.clone();
                 let url = notice.link.clone();
+                let limiter = &limiter;
+                let ramp = &ramp;
                 async move {
+                    let domain = get_domain(&url).unwrap_or_default();
+                    let _ramp_permit = ramp.acquire().await;
+                    let _permit = limiter.acquire(&domain).await;
                     let result = self
                         .fetch_notice_detail(notice, &board_lookup, &selector_cache)
                         .await;
@@ -148,10 +537,13 @@ impl NoticeCrawler {
             })
             .buffer_unordered(concurrency);
 
-        let mut detailed = Vec::new();
         let mut detail_stream = detailed_notices;
-        while let Some((notice_id, board_id, board_name, url, result)) = detail_stream.next().await
+        while let Some((notice_id, board_id, board_name, url, result)) =
+            next_before(&mut detail_stream, deadline, &mut outcome.cut_off).await
         {
+            if deadline.is_some() {
+                finished.insert(notice_id.clone());
+            }
             match result {
                 Ok(notice) => detailed.push(notice),
                 Err(error) => {
@@ -172,56 +564,180 @@ impl NoticeCrawler {
                         notice_id: Some(notice_id),
                         message: error.to_string(),
                         retryable: error.is_retryable(),
+                        kind: None,
                     });
                     log::warn(&format!("Failed to fetch notice detail: {}", error));
                 }
             }
         }
 
-        outcome.notices = detailed;
+        if outcome.cut_off {
+            detailed.extend(
+                unfinished
+                    .into_iter()
+                    .filter(|notice| !finished.contains(&notice.canonical_id())),
+            );
+        }
+
+        outcome.notices = process_notices(detailed, &self.hooks);
+        outcome.open_circuits = breaker.opened_domains();
         Ok(outcome)
     }
 
+    /// Drop notices whose title a different notice on the same board had
+    /// within the last `days`, returning how many were dropped.
+    fn suppress_repeated_titles(&self, notices: &mut Vec<Notice>, days: u32) -> usize {
+        let window = chrono::Duration::days(days.into());
+        let now = self.clock.now();
+        let mut state = self.lock_state();
+        state.prune_titles(window, now);
+        let before = notices.len();
+        notices.retain(|notice| {
+            let repeated = state.is_repeated_title(notice, window, now);
+            if repeated {
+                log::debug(&format!(
+                    "Suppressing repeated title '{}' on board {}",
+                    notice.title, notice.board_id
+                ));
+            }
+            !repeated
+        });
+        before - notices.len()
+    }
+
     /// Fetch a list of notices from a single board.
+    ///
+    /// With `crawler.backfill_until` set and a board `page_param`, following
+    /// pages are fetched until one's oldest notice predates the target date,
+    /// a page yields nothing new, or `max_pages` is reached.
     async fn fetch_board_list(
         &self,
         dept_ref: DepartmentRef<'_>,
         board: &Board,
         selectors: &BoardSelectors,
-    ) -> Result<BoardListResult> {
-        self.apply_request_delay().await;
-        let document = http::fetch_page_async(&self.client, &board.url).await?;
-        let base_url = url::Url::parse(&board.url)?;
-        let mut notices = Vec::new();
-        let mut row_total = 0;
-        let mut row_failures = 0;
-
-        for row in document.select(&selectors.row) {
-            row_total += 1;
-            if let Some(notice) = self.parse_notice_row(
-                &row,
+    ) -> Result<BoardExtraction> {
+        let mut extraction = self.fetch_first_page(dept_ref, board, selectors).await?;
+        let (Some(until), Some(_)) = (self.config.crawler.backfill_until, &board.page_param) else {
+            self.stamp_fetched_at(&mut extraction.notices);
+            return Ok(extraction);
+        };
+
+        let mut seen: HashSet<String> = extraction
+            .notices
+            .iter()
+            .map(Notice::canonical_id)
+            .collect();
+        let mut reached = predates(&extraction.notices, until);
+        let mut page = 1;
+        while !reached && page < self.config.crawler.max_pages {
+            page += 1;
+            let (page_url, options) = list_request(board, Some(page));
+            self.apply_request_delay(&page_url).await;
+            let text = decode_text(&self.send(&page_url, &options).await?.body);
+            let next = extract_page(
+                &text,
                 selectors,
-                &board.selectors.attr_name,
-                dept_ref,
                 board,
-                &base_url,
-            ) {
-                notices.push(notice);
-            } else {
-                row_failures += 1;
+                &url::Url::parse(&page_url)?,
+                &self.row_context(dept_ref),
+            )?;
+            reached = predates(&next.notices, until);
+            extraction.rows_matched += next.rows_matched;
+            extraction.implausible_dates += next.implausible_dates;
+            extraction.duplicate_rows += next.duplicate_rows;
+            let before = extraction.notices.len();
+            let page_notices = next.notices.len();
+            extraction.notices.extend(
+                next.notices
+                    .into_iter()
+                    .filter(|notice| seen.insert(notice.canonical_id())),
+            );
+            extraction.duplicate_rows += page_notices - (extraction.notices.len() - before);
+            if extraction.notices.len() == before {
+                break;
             }
         }
-        Ok(BoardListResult {
-            notices,
-            row_total,
-            row_failures,
-        })
+        log::debug(&format!("Backfilled {} page(s) of {}", page, board.name));
+        self.stamp_fetched_at(&mut extraction.notices);
+        Ok(extraction)
+    }
+
+    fn row_context<'a>(&'a self, dept_ref: DepartmentRef<'a>) -> RowContext<'a> {
+        RowContext {
+            dept_ref,
+            cleaning: &self.config.cleaning,
+            discovery: &self.config.discovery,
+            now: self.clock.local_now(),
+            capture_row_html: self.config.crawler.capture_row_html,
+        }
+    }
+
+    /// Record the collection time on freshly extracted notices.
+    fn stamp_fetched_at(&self, notices: &mut [Notice]) {
+        let now = self.clock.now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        for notice in notices {
+            notice.fetched_at.clone_from(&now);
+        }
+    }
+
+    /// Fetch and extract a board's first list page.
+    async fn fetch_first_page(
+        &self,
+        dept_ref: DepartmentRef<'_>,
+        board: &Board,
+        selectors: &BoardSelectors,
+    ) -> Result<BoardExtraction> {
+        let key = CrawlState::board_key(&dept_ref.dept.id, &board.id);
+        let (url, options) = list_request(board, None);
+        self.apply_request_delay(&url).await;
+        let response = self.send(&url, &options).await?.into_text();
+        if let Some(dir) = &self.config.paths.debug_dir
+            && let Err(err) = write_debug_artifact(Path::new(dir), &key, &response)
+        {
+            log::warn(&format!("Failed to write debug artifact for {key}: {err}"));
+        }
+        let text = response.body;
+        if let Some(min_bytes) = self.config.crawler.min_content_length
+            && text.len() < min_bytes
+        {
+            return Err(AppError::UpstreamBodyTooShort {
+                url: board.url.clone(),
+                bytes: text.len(),
+                min_bytes,
+            });
+        }
+
+        let skip_unchanged = self.config.crawler.skip_unchanged;
+        let hash = page_hash(board, &text);
+        if skip_unchanged && let Some(notices) = self.lock_state().unchanged_notices(&key, &hash) {
+            log::debug(&format!("Board {} unchanged; reusing notices", board.name));
+            return Ok(BoardExtraction {
+                rows_matched: notices.len(),
+                notices,
+                unchanged: true,
+                ..BoardExtraction::default()
+            });
+        }
+
+        let base_url = url::Url::parse(&board.url)?;
+        let extraction = extract_page(
+            &text,
+            selectors,
+            board,
+            &base_url,
+            &self.row_context(dept_ref),
+        )?;
+        if skip_unchanged {
+            self.lock_state()
+                .record_content(&key, hash, extraction.notices.clone());
+        }
+        Ok(extraction)
     }
 
     /// Fetch the body for a single notice.
     async fn fetch_notice_detail(
         &self,
-        mut notice: Notice,
+        notice: Notice,
         board_lookup: &HashMap<&str, &Board>,
         selector_cache: &HashMap<String, Arc<BoardSelectors>>,
     ) -> Result<Notice> {
@@ -229,78 +745,157 @@ impl NoticeCrawler {
         let selectors = selector_cache
             .get(&board.id)
             .ok_or_else(|| AppError::crawl("selector_cache", "Missing selector cache entry"))?;
-        if let Some(body_sel) = selectors.body.as_ref() {
-            if !notice.link.is_empty() {
-                self.apply_request_delay().await;
-                let document = http::fetch_page_async(&self.client, &notice.link).await?;
-                if let Some(body_elem) = document.select(body_sel).next() {
-                    notice.body = body_elem.inner_html();
-                }
+        self.fetch_body(notice, board, selectors).await
+    }
+
+    /// Fill in the notice body from its detail page, if a body selector is set.
+    async fn fetch_body(
+        &self,
+        mut notice: Notice,
+        board: &Board,
+        selectors: &BoardSelectors,
+    ) -> Result<Notice> {
+        if let Some(body_sel) = selectors.body.as_ref()
+            && !notice.link.is_empty()
+        {
+            let options = request_options(board, &notice.link);
+            let document = self.fetch_document(&notice.link, &options).await?;
+            if let Some(body_elem) = document.select(body_sel).next() {
+                notice.body = self.config.cleaning.clean_body(&body_elem.inner_html());
             }
         }
         Ok(notice)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn parse_notice_row(
-        &self,
-        row: &scraper::ElementRef,
-        selectors: &BoardSelectors,
-        attr_name: &str,
-        dept_ref: DepartmentRef<'_>,
-        board: &Board,
-        base_url: &url::Url,
-    ) -> Option<Notice> {
-        let title_elem = row.select(&selectors.title).next()?;
-        let date_elem = row.select(&selectors.date).next()?;
-        let author_elem = selectors
-            .author
-            .as_ref()
-            .and_then(|sel| row.select(sel).next());
+    /// Fetch and parse a page after the configured request delay.
+    async fn fetch_document(&self, url: &str, options: &RequestOptions) -> Result<Html> {
+        self.apply_request_delay(url).await;
+        let response = self.send(url, options).await?;
+        Ok(Html::parse_document(&decode_text(&response.body)))
+    }
+
+    /// Fetch `url`, waiting out HTTP 429 responses up to `max_retries` times.
+    ///
+    /// A server's `Retry-After` is honored up to [`MAX_RETRY_AFTER`] (longer
+    /// values are cut to it, with a warning); otherwise the wait is an
+    /// exponential backoff with full jitter so boards that were rate limited
+    /// together do not retry together.
+    async fn send(&self, url: &str, options: &RequestOptions) -> Result<RawResponse> {
+        let mut attempt = 0;
+        loop {
+            match self.send_once(url, options).await {
+                Err(AppError::UpstreamRateLimited { retry_after, .. })
+                    if attempt < self.config.crawler.max_retries =>
+                {
+                    attempt += 1;
+                    if let Some(requested) = retry_after.filter(|&wait| wait > MAX_RETRY_AFTER) {
+                        log::warn(&format!(
+                            "{url} asked to retry after {}s; capping the wait at {}s",
+                            requested.as_secs(),
+                            MAX_RETRY_AFTER.as_secs()
+                        ));
+                    }
+                    let wait = retry_after
+                        .unwrap_or_else(|| self.jitter.full(retry_backoff(attempt)))
+                        .min(MAX_RETRY_AFTER);
+                    log::warn(&format!(
+                        "Rate limited by {url}; retrying in {:.1}s ({attempt}/{})",
+                        wait.as_secs_f64(),
+                        self.config.crawler.max_retries
+                    ));
+                    tokio::time::sleep(wait).await;
+                }
+                result => return result,
+            }
+        }
+    }
 
-        let raw_title: String = title_elem.text().collect();
-        let raw_date: String = date_elem.text().collect();
-        let raw_author: String = author_elem.map_or(String::new(), |el| el.text().collect());
+    /// Fetch `url`, using the domain's User-Agent override if one is configured.
+    async fn send_once(&self, url: &str, options: &RequestOptions) -> Result<RawResponse> {
+        match self.config.crawler.user_agent_override(url) {
+            Some(user_agent) => {
+                let options = RequestOptions {
+                    user_agent: Some(user_agent.to_string()),
+                    ..options.clone()
+                };
+                self.fetcher.fetch_bytes_with(url, &options).await
+            }
+            None => self.fetcher.fetch_bytes_with(url, options).await,
+        }
+    }
 
-        let title = self.config.cleaning.clean_title(&raw_title);
-        let date = self.config.cleaning.clean_date(&raw_date);
+    /// Build a link from the captures of `regex` in `value`.
+    ///
+    /// `{N}` placeholders in `template` are replaced by capture group N; without a
+    /// template the first capture group (or the whole match) is used as the href.
+    fn apply_link_regex(regex: &Regex, template: Option<&str>, value: &str) -> Option<String> {
+        let caps = regex.captures(value)?;
+        let Some(template) = template else {
+            return caps
+                .get(1)
+                .or_else(|| caps.get(0))
+                .map(|m| m.as_str().to_string());
+        };
+        let mut link = template.to_string();
+        // Replace higher indices first so `{1}` does not clobber `{10}`.
+        for idx in (0..caps.len()).rev() {
+            let value = caps.get(idx).map_or("", |m| m.as_str());
+            link = link.replace(&format!("{{{idx}}}"), value);
+        }
+        Some(link)
+    }
 
-        if title.is_empty() {
-            return None;
+    async fn apply_request_delay(&self, url: &str) {
+        let delay = self.request_delay(url).await;
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
         }
+    }
 
-        let link_elem = selectors
-            .link
-            .as_ref()
-            .and_then(|sel| row.select(sel).next())
-            .or(Some(title_elem));
-        let raw_link = link_elem
-            .and_then(|e| e.value().attr(attr_name))
-            .unwrap_or("");
-        let link = resolve_url(base_url, raw_link);
-        let source_id = extract_notice_id(&link);
-
-        Some(Notice {
-            campus: dept_ref.campus.to_string(),
-            college: dept_ref.college.unwrap_or("").to_string(),
-            department_id: dept_ref.dept.id.clone(),
-            department_name: dept_ref.dept.name.clone(),
-            board_id: board.id.clone(),
-            board_name: board.name.clone(),
-            title,
-            author: raw_author.trim().to_string(),
-            date,
-            link,
-            source_id,
-            body: String::new(), // Body will be fetched later
-        })
+    /// Delay before a request to `url`: `request_delay_ms`, raised to the
+    /// domain's robots.txt `Crawl-delay` (at most `max_crawl_delay_secs`)
+    /// when `respect_crawl_delay` is set.
+    async fn request_delay(&self, url: &str) -> Duration {
+        let configured = Duration::from_millis(self.config.crawler.request_delay_ms);
+        if !self.config.crawler.respect_crawl_delay {
+            return configured;
+        }
+        let max = Duration::from_secs(self.config.crawler.max_crawl_delay_secs);
+        self.crawl_delay(url)
+            .await
+            .map_or(configured, |crawl_delay| {
+                configured.max(crawl_delay.min(max))
+            })
     }
 
-    async fn apply_request_delay(&self) {
-        let delay_ms = self.config.crawler.request_delay_ms;
-        if delay_ms > 0 {
-            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    /// The robots.txt `Crawl-delay` for `url`'s domain, cached per domain.
+    async fn crawl_delay(&self, url: &str) -> Option<Duration> {
+        let domain = get_domain(url)?;
+        if let Some(cached) = self.lock_crawl_delays().get(&domain) {
+            return *cached;
+        }
+        let delay = match robots_url(url) {
+            Some(robots) => match self.fetcher.fetch_text(&robots).await {
+                Ok(body) => parse_crawl_delay(&body, &self.config.crawler.user_agent),
+                Err(err) => {
+                    log::debug(&format!("No robots.txt at {robots}: {err}"));
+                    None
+                }
+            },
+            None => None,
+        };
+        if let Some(delay) = delay {
+            log::info(&format!(
+                "{domain} asks for a Crawl-delay of {:.1}s",
+                delay.as_secs_f64()
+            ));
         }
+        self.lock_crawl_delays().insert(domain, delay);
+        delay
+    }
+
+    fn lock_crawl_delays(&self) -> std::sync::MutexGuard<'_, HashMap<String, Option<Duration>>> {
+        self.crawl_delays.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     fn build_selector_cache(
@@ -317,36 +912,10 @@ impl NoticeCrawler {
         for campus in campuses {
             for dept_ref in campus.all_departments() {
                 for board in &dept_ref.dept.boards {
-                    let row = match Self::parse_selector(&board.selectors.row_selector) {
-                        Ok(sel) => sel,
-                        Err(err) => {
-                            errors.push(Self::build_error(
-                                CrawlStage::Selector,
-                                Some(board),
-                                Some(&board.url),
-                                None,
-                                &err,
-                            ));
-                            invalid_boards.insert(board.id.clone());
-                            continue;
-                        }
-                    };
-                    let title = match Self::parse_selector(&board.selectors.title_selector) {
-                        Ok(sel) => sel,
-                        Err(err) => {
-                            errors.push(Self::build_error(
-                                CrawlStage::Selector,
-                                Some(board),
-                                Some(&board.url),
-                                None,
-                                &err,
-                            ));
-                            invalid_boards.insert(board.id.clone());
-                            continue;
+                    match Self::compile_selectors(board, &mut errors) {
+                        Ok(selectors) => {
+                            cache.insert(board.id.clone(), Arc::new(selectors));
                         }
-                    };
-                    let date = match Self::parse_selector(&board.selectors.date_selector) {
-                        Ok(sel) => sel,
                         Err(err) => {
                             errors.push(Self::build_error(
                                 CrawlStage::Selector,
@@ -356,69 +925,8 @@ impl NoticeCrawler {
                                 &err,
                             ));
                             invalid_boards.insert(board.id.clone());
-                            continue;
                         }
-                    };
-                    let author = match board.selectors.author_selector.as_ref() {
-                        Some(sel) => match Self::parse_selector(sel) {
-                            Ok(parsed) => Some(parsed),
-                            Err(err) => {
-                                errors.push(Self::build_error(
-                                    CrawlStage::Selector,
-                                    Some(board),
-                                    Some(&board.url),
-                                    None,
-                                    &err,
-                                ));
-                                None
-                            }
-                        },
-                        None => None,
-                    };
-                    let link = match board.selectors.link_selector.as_ref() {
-                        Some(sel) => match Self::parse_selector(sel) {
-                            Ok(parsed) => Some(parsed),
-                            Err(err) => {
-                                errors.push(Self::build_error(
-                                    CrawlStage::Selector,
-                                    Some(board),
-                                    Some(&board.url),
-                                    None,
-                                    &err,
-                                ));
-                                None
-                            }
-                        },
-                        None => None,
-                    };
-                    let body = match board.selectors.body_selector.as_ref() {
-                        Some(sel) => match Self::parse_selector(sel) {
-                            Ok(parsed) => Some(parsed),
-                            Err(err) => {
-                                errors.push(Self::build_error(
-                                    CrawlStage::Selector,
-                                    Some(board),
-                                    Some(&board.url),
-                                    None,
-                                    &err,
-                                ));
-                                None
-                            }
-                        },
-                        None => None,
-                    };
-
-                    cache.insert(
-                        board.id.clone(),
-                        Arc::new(BoardSelectors {
-                            row,
-                            title,
-                            date,
-                            author,
-                            link,
-                            body,
-                        }),
-                    );
+                    }
                 }
             }
         }
@@ -426,6 +934,84 @@ impl NoticeCrawler {
         (cache, errors, invalid_boards)
     }
 
+    /// Compile a board's selectors.
+    ///
+    /// Missing or invalid row/title/date selectors (HTML boards), missing
+    /// `json_paths` (JSON boards) or an invalid link regex make the board
+    /// unusable and are returned as the error; invalid optional selectors are
+    /// recorded in `errors` and skipped.
+    fn compile_selectors(board: &Board, errors: &mut Vec<CrawlError>) -> Result<BoardSelectors> {
+        let (rows, title, date) = match board.format {
+            BoardFormat::Json if board.json_paths.is_none() => {
+                return Err(AppError::config(format!(
+                    "Board {} has format = \"json\" but no json_paths",
+                    board.id
+                )));
+            }
+            BoardFormat::Json => (Vec::new(), None, None),
+            BoardFormat::Html => {
+                let selectors = &board.selectors;
+                if selectors.row_selector.is_empty()
+                    || selectors.title_selector.trim().is_empty()
+                    || selectors.date_selector.trim().is_empty()
+                {
+                    return Err(AppError::config(format!(
+                        "Board {} needs row_selector, title_selector and date_selector",
+                        board.id
+                    )));
+                }
+                let rows = selectors
+                    .row_selector
+                    .iter()
+                    .map(Self::parse_selector)
+                    .collect::<Result<Vec<_>>>()?;
+                let title = Self::parse_selector(&selectors.title_selector)?;
+                let date = Self::parse_selector(&selectors.date_selector)?;
+                (rows, Some(title), Some(date))
+            }
+        };
+        let link_regex = board
+            .link_regex
+            .as_ref()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|err| {
+                    AppError::config(format!("Invalid link_regex '{pattern}': {err}"))
+                })
+            })
+            .transpose()?;
+
+        let mut optional = |selector: Option<&String>| {
+            selector.and_then(|sel| match Self::parse_selector(sel) {
+                Ok(parsed) => Some(parsed),
+                Err(err) => {
+                    errors.push(Self::build_error(
+                        CrawlStage::Selector,
+                        Some(board),
+                        Some(&board.url),
+                        None,
+                        &err,
+                    ));
+                    None
+                }
+            })
+        };
+        let author = optional(board.selectors.author_selector.as_ref());
+        let link = optional(board.selectors.link_selector.as_ref());
+        let body = optional(board.selectors.body_selector.as_ref());
+
+        Ok(BoardSelectors {
+            rows,
+            title,
+            date,
+            author,
+            link,
+            body,
+            link_regex,
+        })
+    }
+
+    /// Build a report entry; board-level errors (no `notice_id`) are
+    /// categorized by [`BoardErrorKind`].
     fn build_error(
         stage: CrawlStage,
         board: Option<&Board>,
@@ -433,6 +1019,11 @@ impl NoticeCrawler {
         notice_id: Option<&str>,
         error: &AppError,
     ) -> CrawlError {
+        let kind = match stage {
+            _ if notice_id.is_some() => None,
+            CrawlStage::Selector => Some(BoardErrorKind::BadSelector),
+            _ => Some(error.board_error_kind()),
+        };
         CrawlError {
             stage,
             board_id: board.map(|b| b.id.clone()),
@@ -441,10 +1032,11 @@ impl NoticeCrawler {
             notice_id: notice_id.map(str::to_string),
             message: error.to_string(),
             retryable: error.is_retryable(),
+            kind,
         }
     }
 
-    fn build_board_lookup<'a>(campuses: &'a [Campus]) -> HashMap<&'a str, &'a Board> {
+    fn build_board_lookup(campuses: &[Campus]) -> HashMap<&str, &Board> {
         campuses
             .iter()
             .flat_map(|campus| campus.all_departments())
@@ -468,22 +1060,2181 @@ impl NoticeCrawler {
     }
 
     fn parse_selector(s: &str) -> Result<Selector> {
-        Selector::parse(s).map_err(|e| AppError::selector(s, format!("{e:?}")))
+        Selector::parse(s).map_err(|e| AppError::selector(s, e))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Extract notices from a board's list page HTML.
+///
+/// This is the parsing half of a board crawl, without any network access:
+/// `base_url` resolves relative links and `context` supplies the
+/// campus/college/department the notices belong to; `clock` dates relative
+/// phrases ("3일 전") and the plausibility window. Rows that fail to parse
+/// are skipped and show up as the gap between `rows_matched` and
+/// `notices_extracted()`.
+pub fn extract_notices(
+    html: &str,
+    board: &Board,
+    base_url: &url::Url,
+    cleaning: &CleaningConfig,
+    discovery: &DiscoveryConfig,
+    context: DepartmentRef<'_>,
+    clock: &dyn Clock,
+) -> Result<BoardExtraction> {
+    let selectors = NoticeCrawler::compile_selectors(board, &mut Vec::new())?;
+    extract_page(
+        html,
+        &selectors,
+        board,
+        base_url,
+        &RowContext {
+            dept_ref: context,
+            cleaning,
+            discovery,
+            now: clock.local_now(),
+            capture_row_html: false,
+        },
+    )
+}
 
-    #[test]
-    fn test_parse_selector_valid() {
-        assert!(NoticeCrawler::parse_selector("div.class").is_ok());
-        assert!(NoticeCrawler::parse_selector("tr:has(a)").is_ok());
+/// Settings shared by every row parsed from one board page.
+struct RowContext<'a> {
+    dept_ref: DepartmentRef<'a>,
+    cleaning: &'a CleaningConfig,
+    discovery: &'a DiscoveryConfig,
+    /// Reference time for relative dates
+    now: NaiveDateTime,
+    /// Keep the row's outer HTML in `Notice::raw_html`
+    capture_row_html: bool,
+}
+
+/// Backoff before retry number `attempt` (1-based) when the server gave no
+/// `Retry-After`, before jitter.
+fn retry_backoff(attempt: usize) -> Duration {
+    let exponent = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+    DEFAULT_RETRY_AFTER
+        .saturating_mul(2u32.saturating_pow(exponent))
+        .min(MAX_RETRY_AFTER)
+}
+
+/// A board whose required selectors or link regex do not compile.
+fn selector_failure(board: &Board, error: AppError) -> AppError {
+    AppError::Board {
+        board_id: board.id.clone(),
+        url: board.url.clone(),
+        kind: BoardErrorKind::BadSelector,
+        message: error.to_string(),
     }
+}
 
-    #[test]
-    fn test_parse_selector_invalid() {
-        assert!(NoticeCrawler::parse_selector("[[invalid").is_err());
+/// Whether the oldest parseable date among `notices` is before `until`.
+fn predates(notices: &[Notice], until: chrono::NaiveDate) -> bool {
+    notices
+        .iter()
+        .filter_map(|notice| parse_date(&notice.date))
+        .min()
+        .is_some_and(|oldest| oldest < until)
+}
+
+/// Next item of `stream`, or `None` once it ends or `deadline` passes (which
+/// sets `cut_off`).
+async fn next_before<S: Stream + Unpin>(
+    stream: &mut S,
+    deadline: Option<tokio::time::Instant>,
+    cut_off: &mut bool,
+) -> Option<S::Item> {
+    let Some(deadline) = deadline else {
+        return stream.next().await;
+    };
+    match tokio::time::timeout_at(deadline, stream.next()).await {
+        Ok(item) => item,
+        Err(_) => {
+            *cut_off = true;
+            None
+        }
+    }
+}
+
+/// Dump a board's raw HTML and a `.headers.json` sidecar into `dir`.
+fn write_debug_artifact(dir: &Path, key: &str, response: &HtmlResponse) -> Result<()> {
+    let stem: String = key
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    fs::write(dir.join(format!("{stem}.html")), &response.body)?;
+    fs::save_json(&dir.join(format!("{stem}.headers.json")), response)
+}
+
+/// Request options for fetching `url` on behalf of `board`.
+///
+/// Credentials are only sent to the board's own origin (scheme, host and
+/// port), so a notice linking off-site or downgrading to plain HTTP never
+/// receives them. Unparseable URLs get none.
+fn request_options(board: &Board, url: &str) -> RequestOptions {
+    let same_origin = match (url::Url::parse(url), url::Url::parse(&board.url)) {
+        (Ok(target), Ok(board_url)) => target.origin() == board_url.origin(),
+        _ => false,
+    };
+    RequestOptions {
+        basic_auth: board.basic_auth.clone().filter(|_| same_origin),
+        accept_invalid_certs: board.accept_invalid_certs,
+        follow_redirects: board.follow_redirects,
+        ..RequestOptions::default()
+    }
+}
+
+/// URL and options for a board's list page, using the board's method and
+/// form body. A backfill `page` goes in the query for GET and in the form
+/// for POST.
+fn list_request(board: &Board, page: Option<usize>) -> (String, RequestOptions) {
+    let mut url = board.url.clone();
+    let mut options = RequestOptions {
+        method: board.method,
+        form: board.form_body.clone(),
+        ..request_options(board, &board.url)
+    };
+    if let (Some(page), Some(param)) = (page, &board.page_param) {
+        match board.method {
+            HttpMethod::Get => url = set_query_param(&board.url, param, &page.to_string()),
+            HttpMethod::Post => {
+                options
+                    .form
+                    .get_or_insert_default()
+                    .insert(param.clone(), page.to_string());
+            }
+        }
+    }
+    (url, options)
+}
+
+/// Hash of a list page together with the board's selectors, so a selector
+/// fix invalidates the reused notices even if the page is the same.
+fn page_hash(board: &Board, html: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(&board.selectors).unwrap_or_default());
+    hasher.update(html.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+fn extract_rows(
+    document: &Html,
+    selectors: &BoardSelectors,
+    board: &Board,
+    base_url: &url::Url,
+    ctx: &RowContext<'_>,
+) -> BoardExtraction {
+    let base_url = &document_base(document, base_url);
+    let cleaning = ctx.cleaning.for_board(board);
+    let ctx = &RowContext {
+        cleaning: &cleaning,
+        ..*ctx
+    };
+    // An element matched by more than one row selector is a single row
+    let mut matched = HashSet::new();
+    let rows = selectors
+        .rows
+        .iter()
+        .flat_map(|selector| document.select(selector).skip(board.skip_rows))
+        .filter(|row| matched.insert(row.id()))
+        .map(|row| parse_notice_row(&row, selectors, board, base_url, ctx));
+    collect_rows(rows, board, ctx)
+}
+
+/// Extract notices from a board's JSON list response using its `json_paths`.
+///
+/// A missing or non-array `items_path` matches no rows.
+fn extract_json(
+    text: &str,
+    selectors: &BoardSelectors,
+    board: &Board,
+    base_url: &url::Url,
+    ctx: &RowContext<'_>,
+) -> Result<BoardExtraction> {
+    let paths = board
+        .json_paths
+        .as_ref()
+        .ok_or_else(|| AppError::config(format!("Board {} has no json_paths", board.id)))?;
+    let root: serde_json::Value = serde_json::from_str(text)?;
+    let cleaning = ctx.cleaning.for_board(board);
+    let ctx = &RowContext {
+        cleaning: &cleaning,
+        ..*ctx
+    };
+    let items = json_path::select(&root, &paths.items_path)
+        .and_then(serde_json::Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    let rows = items
+        .iter()
+        .skip(board.skip_rows)
+        .map(|item| parse_json_item(item, paths, selectors, board, base_url, ctx));
+    Ok(collect_rows(rows, board, ctx))
+}
+
+/// Extract notices from a list response in the board's `format`.
+fn extract_page(
+    text: &str,
+    selectors: &BoardSelectors,
+    board: &Board,
+    base_url: &url::Url,
+    ctx: &RowContext<'_>,
+) -> Result<BoardExtraction> {
+    match board.format {
+        BoardFormat::Html => Ok(extract_rows(
+            &Html::parse_document(text),
+            selectors,
+            board,
+            base_url,
+            ctx,
+        )),
+        BoardFormat::Json => extract_json(text, selectors, board, base_url, ctx),
+    }
+}
+
+/// Count parsed rows, clearing implausible dates and dropping repeated links.
+fn collect_rows(
+    rows: impl Iterator<Item = Option<Notice>>,
+    board: &Board,
+    ctx: &RowContext<'_>,
+) -> BoardExtraction {
+    let mut extraction = BoardExtraction::default();
+    let mut seen_links = HashSet::new();
+    for row in rows {
+        extraction.rows_matched += 1;
+        let Some(mut notice) = row else {
+            continue;
+        };
+        if !ctx.cleaning.is_plausible_date(&notice.date, ctx.now.date()) {
+            log::debug(&format!(
+                "Clearing implausible date {:?} of notice '{}' on board {}",
+                notice.date, notice.title, board.id
+            ));
+            notice.date.clear();
+            extraction.implausible_dates += 1;
+        }
+        if board.dedup_within_board
+            && !notice.link.is_empty()
+            && !seen_links.insert(notice.link.clone())
+        {
+            log::debug(&format!(
+                "Dropping repeated notice '{}' on board {}",
+                notice.title, board.id
+            ));
+            extraction.duplicate_rows += 1;
+            continue;
+        }
+        extraction.notices.push(notice);
+    }
+    extraction
+}
+
+/// The document's `<base href>` resolved against `page_url`, or `page_url`
+/// when the page has none. A base that switches scheme (e.g. `file:` on an
+/// https board) is ignored.
+fn document_base(document: &Html, page_url: &url::Url) -> url::Url {
+    document
+        .select(&BASE_SELECTOR)
+        .next()
+        .and_then(|base| base.value().attr("href"))
+        .and_then(|href| page_url.join(href.trim()).ok())
+        .filter(|base| base.scheme() == page_url.scheme())
+        .unwrap_or_else(|| page_url.clone())
+}
+
+fn parse_notice_row(
+    row: &scraper::ElementRef,
+    selectors: &BoardSelectors,
+    board: &Board,
+    base_url: &url::Url,
+    ctx: &RowContext<'_>,
+) -> Option<Notice> {
+    let title_elem = row.select(selectors.title.as_ref()?).next()?;
+    let date_elem = row.select(selectors.date.as_ref()?).next()?;
+    let author_elem = selectors
+        .author
+        .as_ref()
+        .and_then(|sel| row.select(sel).next());
+
+    let raw_title: String = title_elem.text().collect();
+    let raw_date: String = date_elem.text().collect();
+    let raw_author: String = author_elem.map_or(String::new(), |el| el.text().collect());
+
+    // Without a link selector, prefer an anchor nested in the title cell
+    // (`<td class="subject"><a href=…>`) over the title element itself.
+    let link_elem = match &selectors.link {
+        Some(sel) => row.select(sel).next(),
+        None => title_elem.select(&ANCHOR_SELECTOR).next(),
+    }
+    .or(Some(title_elem));
+    let attr_link = link_elem
+        .and_then(|e| {
+            std::iter::once(&board.selectors.attr_name)
+                .chain(&board.attr_fallbacks)
+                .filter_map(|attr| e.value().attr(attr))
+                .find(|value| !value.trim().is_empty())
+        })
+        .unwrap_or("");
+    build_notice(
+        RawRow {
+            title: &raw_title,
+            date: &raw_date,
+            author: &raw_author,
+            link: attr_link,
+            onclick: link_elem.and_then(|e| e.value().attr("onclick")),
+            raw_html: ctx.capture_row_html.then(|| row.html()),
+        },
+        selectors,
+        board,
+        base_url,
+        ctx,
+    )
+}
+
+/// Parse one item of a JSON list response; items without a title are skipped.
+fn parse_json_item(
+    item: &serde_json::Value,
+    paths: &JsonPaths,
+    selectors: &BoardSelectors,
+    board: &Board,
+    base_url: &url::Url,
+    ctx: &RowContext<'_>,
+) -> Option<Notice> {
+    let text = |path: &str| json_path::select_text(item, path).unwrap_or_default();
+    let link = text(&paths.link_path);
+    build_notice(
+        RawRow {
+            title: &text(&paths.title_path),
+            date: &text(&paths.date_path),
+            author: &paths.author_path.as_deref().map(text).unwrap_or_default(),
+            link: &link,
+            onclick: None,
+            raw_html: ctx.capture_row_html.then(|| item.to_string()),
+        },
+        selectors,
+        board,
+        base_url,
+        ctx,
+    )
+}
+
+/// Uncleaned fields of one list entry, HTML row or JSON item.
+struct RawRow<'r> {
+    title: &'r str,
+    date: &'r str,
+    author: &'r str,
+    /// Link attribute value (or JSON link field)
+    link: &'r str,
+    /// `onclick` handler, also searched by `link_regex`
+    onclick: Option<&'r str>,
+    raw_html: Option<String>,
+}
+
+/// Clean a list entry's fields and resolve its link into a notice.
+///
+/// Returns `None` for an empty title, or an invalid link when
+/// `discovery.require_valid_link` is set.
+fn build_notice(
+    raw: RawRow<'_>,
+    selectors: &BoardSelectors,
+    board: &Board,
+    base_url: &url::Url,
+    ctx: &RowContext<'_>,
+) -> Option<Notice> {
+    let title = ctx.cleaning.clean_title(raw.title);
+    let date = ctx.cleaning.clean_date_at(raw.date, ctx.now);
+
+    if title.is_empty() {
+        return None;
+    }
+
+    let attr_link = raw.link;
+    let regex_link = selectors.link_regex.as_ref().and_then(|regex| {
+        raw.onclick
+            .into_iter()
+            .chain(Some(attr_link))
+            .find_map(|value| {
+                NoticeCrawler::apply_link_regex(regex, board.link_template.as_deref(), value)
+            })
+    });
+    let raw_link = regex_link.as_deref().unwrap_or(attr_link);
+    let link = ctx.cleaning.clean_link(&resolve_url(base_url, raw_link));
+    // Decided by the board, never by a page-supplied `<base>`
+    let valid_link = if board.url.starts_with("file:") {
+        is_valid_file_link(raw_link, &link)
+    } else {
+        is_valid_link(raw_link, &link)
+    };
+    if ctx.discovery.require_valid_link && !valid_link {
+        log::debug(&format!(
+            "Dropping notice '{title}' with invalid link (raw href: {raw_link:?})"
+        ));
+        return None;
+    }
+    let source_id = extract_notice_id(&link);
+
+    Some(Notice {
+        campus: ctx.dept_ref.campus.to_string(),
+        college: ctx.dept_ref.college.unwrap_or("").to_string(),
+        department_id: ctx.dept_ref.dept.id.clone(),
+        department_name: ctx.dept_ref.dept.name.clone(),
+        board_id: board.id.clone(),
+        board_name: board.name.clone(),
+        title,
+        author: raw.author.trim().to_string(),
+        date,
+        link,
+        source_id,
+        body: String::new(),       // Body will be fetched later
+        fetched_at: String::new(), // Stamped by the crawler
+        category: None,
+        raw_html: raw.raw_html,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::atomic::Ordering;
+
+    use crate::models::{BasicAuth, CmsSelectors, CrawlerConfig, Department, RowSelector};
+    use crate::utils::clock::FixedClock;
+
+    /// Serves fixture HTML by URL and records every requested URL.
+    #[derive(Default)]
+    struct MockFetcher {
+        pages: HashMap<String, String>,
+        headers: std::collections::BTreeMap<String, String>,
+        requests: std::sync::Mutex<Vec<String>>,
+        options: std::sync::Mutex<Vec<(String, RequestOptions)>>,
+        delays: HashMap<String, Duration>,
+        /// 429 responses (with `Retry-After`) served before each page succeeds
+        rate_limits: std::sync::Mutex<HashMap<String, (usize, Duration)>>,
+        in_flight: std::sync::atomic::AtomicUsize,
+        max_in_flight: std::sync::atomic::AtomicUsize,
+        /// (in flight, max in flight) per domain
+        domain_in_flight: std::sync::Mutex<HashMap<String, (usize, usize)>>,
+    }
+
+    impl MockFetcher {
+        fn track_domain(&self, url: &str, entering: bool) {
+            let domain = get_domain(url).unwrap_or_default();
+            let mut domains = self.domain_in_flight.lock().unwrap();
+            let (current, max) = domains.entry(domain).or_default();
+            if entering {
+                *current += 1;
+                *max = (*max).max(*current);
+            } else {
+                *current -= 1;
+            }
+        }
+    }
+
+    #[async_trait]
+    impl HtmlFetcher for MockFetcher {
+        async fn fetch_html(&self, url: &str) -> Result<String> {
+            self.requests.lock().unwrap().push(url.to_string());
+            if let Some(delay) = self.delays.get(url) {
+                let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+                self.track_domain(url, true);
+                tokio::time::sleep(*delay).await;
+                self.track_domain(url, false);
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            }
+            if let Some((remaining, retry_after)) = self.rate_limits.lock().unwrap().get_mut(url)
+                && *remaining > 0
+            {
+                *remaining -= 1;
+                return Err(AppError::UpstreamRateLimited {
+                    url: url.to_string(),
+                    retry_after: Some(*retry_after),
+                });
+            }
+            self.pages
+                .get(url)
+                .cloned()
+                .ok_or_else(|| AppError::UpstreamHttp {
+                    url: url.to_string(),
+                    status: 404,
+                })
+        }
+
+        async fn fetch_response(&self, url: &str) -> Result<HtmlResponse> {
+            Ok(HtmlResponse {
+                url: url.to_string(),
+                status: 200,
+                headers: self.headers.clone(),
+                body: self.fetch_html(url).await?,
+            })
+        }
+
+        async fn fetch_response_with(
+            &self,
+            url: &str,
+            options: &RequestOptions,
+        ) -> Result<HtmlResponse> {
+            self.options
+                .lock()
+                .unwrap()
+                .push((url.to_string(), options.clone()));
+            self.fetch_response(url).await
+        }
+    }
+
+    const BOARD_URL: &str = "https://dept.yonsei.ac.kr/board/list.do";
+
+    const LIST_PAGE: &str = r#"
+        <table class="board_list"><tbody>
+          <tr><td class="subject"><a href="view.do?articleNo=2">장학금 신청 안내</a></td><td class="date">2024-03-02</td></tr>
+          <tr><td class="subject"><a href="view.do?articleNo=1">수강신청 일정</a></td><td class="date">2024-03-01</td></tr>
+        </tbody></table>"#;
+
+    fn test_board(body_selector: Option<&str>) -> Board {
+        Board {
+            id: "scholarship".to_string(),
+            name: "장학공지".to_string(),
+            url: BOARD_URL.to_string(),
+            selectors: CmsSelectors {
+                row_selector: "table.board_list tbody tr".into(),
+                title_selector: "td.subject a".to_string(),
+                date_selector: "td.date".to_string(),
+                body_selector: body_selector.map(str::to_string),
+                ..CmsSelectors::default()
+            },
+            ..Board::default()
+        }
+    }
+
+    fn test_crawler(pages: &[(&str, &str)]) -> NoticeCrawler {
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let fetcher = MockFetcher {
+            pages: pages
+                .iter()
+                .map(|(url, html)| (url.to_string(), html.to_string()))
+                .collect(),
+            ..MockFetcher::default()
+        };
+        NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher))
+    }
+
+    fn test_department() -> Department {
+        Department {
+            id: "yonsei_dept".to_string(),
+            name: "테스트학과".to_string(),
+            url: "https://dept.yonsei.ac.kr".to_string(),
+            boards: Vec::new(),
+        }
+    }
+
+    fn test_campus(dept: Department) -> Campus {
+        Campus {
+            campus: "신촌캠퍼스".to_string(),
+            departments: vec![dept],
+            ..Campus::default()
+        }
+    }
+
+    fn dept_ref(dept: &Department) -> DepartmentRef<'_> {
+        DepartmentRef {
+            campus: "신촌캠퍼스",
+            college: None,
+            dept,
+        }
+    }
+
+    /// A crawler over one campus whose department has a `LIST_PAGE` board per
+    /// `(id, url, delay)`, each served after its delay; `configure` adjusts the
+    /// crawler settings (request delay already zeroed).
+    fn crawler_with_boards(
+        boards: impl IntoIterator<Item = (String, String, Duration)>,
+        configure: impl FnOnce(&mut CrawlerConfig),
+    ) -> (NoticeCrawler, Arc<MockFetcher>, Campus) {
+        let mut dept = test_department();
+        let mut pages = HashMap::new();
+        let mut delays = HashMap::new();
+        for (id, url, delay) in boards {
+            let mut board = test_board(None);
+            board.id = id;
+            board.url = url;
+            pages.insert(board.url.clone(), LIST_PAGE.to_string());
+            delays.insert(board.url.clone(), delay);
+            dept.boards.push(board);
+        }
+        let fetcher = Arc::new(MockFetcher {
+            pages,
+            delays,
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        configure(&mut config.crawler);
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        (crawler, fetcher, test_campus(dept))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_single_board_with_mock_fetcher() {
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)]);
+        let dept = test_department();
+        let context = DepartmentRef {
+            campus: "신촌캠퍼스",
+            college: Some("공과대학"),
+            dept: &dept,
+        };
+
+        let notices = crawler.fetch(&test_board(None), context).await.unwrap();
+
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].title, "장학금 신청 안내");
+        assert_eq!(notices[0].date, "2024-03-02");
+        assert_eq!(
+            notices[0].link,
+            "https://dept.yonsei.ac.kr/board/view.do?articleNo=2"
+        );
+        assert_eq!(notices[0].college, "공과대학");
+        assert_eq!(notices[1].department_id, "yonsei_dept");
+    }
+
+    #[tokio::test]
+    async fn test_fetched_at_comes_from_injected_clock() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 4, 5, 6, 7).unwrap();
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)]).with_clock(Arc::new(FixedClock(now)));
+        let dept = test_department();
+        let context = dept_ref(&dept);
+
+        let notices = crawler.fetch(&test_board(None), context).await.unwrap();
+        assert_eq!(notices.len(), 2);
+        assert!(
+            notices
+                .iter()
+                .all(|notice| notice.fetched_at == "2024-03-04T05:06:07Z")
+        );
+        assert_ne!(notices[0].date, notices[0].fetched_at);
+    }
+
+    #[tokio::test]
+    async fn test_basic_auth_sent_only_to_board_host() {
+        let offsite = "https://files.example.com/view?id=9";
+        let page = format!(
+            r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a href="view.do?articleNo=2">내부 공지</a></td><td class="date">2024-03-02</td></tr>
+            <tr><td class="subject"><a href="{offsite}">외부 링크</a></td><td class="date">2024-03-01</td></tr>
+            </tbody></table>"#
+        );
+        let detail = "https://dept.yonsei.ac.kr/board/view.do?articleNo=2";
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([
+                (BOARD_URL.to_string(), page),
+                (
+                    detail.to_string(),
+                    "<div class=\"content\">본문</div>".to_string(),
+                ),
+                (
+                    offsite.to_string(),
+                    "<div class=\"content\">외부</div>".to_string(),
+                ),
+            ]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let auth = BasicAuth {
+            username: "staff".to_string(),
+            password: "s3cret".to_string(),
+        };
+        let mut board = test_board(Some("div.content"));
+        board.basic_auth = Some(auth.clone());
+        let dept = test_department();
+        let context = dept_ref(&dept);
+
+        crawler.fetch(&board, context).await.unwrap();
+
+        let options: HashMap<_, _> = fetcher.options.lock().unwrap().iter().cloned().collect();
+        assert_eq!(options[BOARD_URL].basic_auth.as_ref(), Some(&auth));
+        assert_eq!(options[detail].basic_auth.as_ref(), Some(&auth));
+        assert_eq!(options[offsite].basic_auth, None);
+        assert!(!format!("{board:?}").contains("s3cret"));
+    }
+
+    #[test]
+    fn test_basic_auth_requires_same_origin() {
+        let mut board = test_board(None);
+        board.basic_auth = Some(BasicAuth {
+            username: "staff".to_string(),
+            password: "s3cret".to_string(),
+        });
+        let auth_for = |board: &Board, url: &str| request_options(board, url).basic_auth.is_some();
+
+        assert!(auth_for(
+            &board,
+            "https://dept.yonsei.ac.kr:443/board/view.do"
+        ));
+        assert!(!auth_for(&board, "http://dept.yonsei.ac.kr/board/view.do"));
+        assert!(!auth_for(
+            &board,
+            "https://dept.yonsei.ac.kr:8443/board/view.do"
+        ));
+        assert!(!auth_for(&board, "view.do?articleNo=1"));
+
+        board.url = "not a url".to_string();
+        assert!(!auth_for(&board, "not a url"));
+    }
+
+    #[tokio::test]
+    async fn test_post_board_sends_form_body() {
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let mut board = test_board(None);
+        board.method = HttpMethod::Post;
+        board.form_body = Some(BTreeMap::from([
+            ("bbsId".to_string(), "B0001".to_string()),
+            ("searchCnd".to_string(), "all".to_string()),
+        ]));
+        let dept = test_department();
+        let context = dept_ref(&dept);
+
+        let notices = crawler.fetch(&board, context).await.unwrap();
+        assert_eq!(notices.len(), 2);
+
+        let options = fetcher.options.lock().unwrap();
+        assert_eq!(options.len(), 1);
+        let (url, sent) = &options[0];
+        assert_eq!(url, BOARD_URL);
+        assert_eq!(sent.method, HttpMethod::Post);
+        assert_eq!(sent.form, board.form_body);
+
+        board.page_param = Some("pageIndex".to_string());
+        let (_, page_two) = list_request(&board, Some(2));
+        assert_eq!(page_two.form.unwrap()["pageIndex"], "2");
+    }
+
+    #[tokio::test]
+    async fn test_crawl_board_from_file_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let list = LIST_PAGE.replace("view.do?articleNo=", "articles/");
+        std::fs::write(dir.path().join("list.html"), list).unwrap();
+        std::fs::create_dir(dir.path().join("articles")).unwrap();
+        for id in [1, 2] {
+            std::fs::write(
+                dir.path().join("articles").join(id.to_string()),
+                format!("<div class=\"content\">offline body {id}</div>"),
+            )
+            .unwrap();
+        }
+        let board_url = url::Url::from_file_path(dir.path().join("list.html")).unwrap();
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.allow_file_urls = true;
+        let fetcher = ReqwestHtmlFetcher::from_config(&config.crawler).unwrap();
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+        let mut board = test_board(Some("div.content"));
+        board.url = board_url.to_string();
+        let mut dept = test_department();
+        dept.boards.push(board);
+        let campus = test_campus(dept);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.board_failures, 0);
+        assert_eq!(outcome.notices.len(), 2);
+        // Detail pages finish in any order
+        let notice = outcome
+            .notices
+            .iter()
+            .find(|notice| notice.link.ends_with("articles/2"))
+            .unwrap();
+        assert!(notice.link.starts_with("file://"));
+        assert_eq!(notice.body, "offline body 2");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_page_is_retried_after_wait() {
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            rate_limits: std::sync::Mutex::new(HashMap::from([(
+                BOARD_URL.to_string(),
+                (1, Duration::from_millis(50)),
+            )])),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let dept = test_department();
+        let context = dept_ref(&dept);
+
+        let started = std::time::Instant::now();
+        let notices = crawler.fetch(&test_board(None), context).await.unwrap();
+
+        assert_eq!(notices.len(), 2);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert_eq!(fetcher.requests.lock().unwrap().len(), 2);
+
+        // Out of retries: the 429 surfaces as the board's error
+        fetcher
+            .rate_limits
+            .lock()
+            .unwrap()
+            .insert(BOARD_URL.to_string(), (3, Duration::ZERO));
+        let err = crawler.fetch(&test_board(None), context).await.unwrap_err();
+        assert_eq!(err.board_error_kind(), BoardErrorKind::Status(429));
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_override_applies_to_mapped_domain() {
+        let other_url = "https://other.yonsei.ac.kr/board/list.do";
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([
+                (BOARD_URL.to_string(), LIST_PAGE.to_string()),
+                (other_url.to_string(), LIST_PAGE.to_string()),
+            ]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.user_agent_overrides = HashMap::from([(
+            "dept.yonsei.ac.kr".to_string(),
+            "Mozilla/5.0 (Windows NT 10.0)".to_string(),
+        )]);
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let mut other = test_board(None);
+        other.url = other_url.to_string();
+
+        crawler.fetch(&test_board(None), context).await.unwrap();
+        crawler.fetch(&other, context).await.unwrap();
+
+        let options = fetcher.options.lock().unwrap();
+        assert_eq!(
+            options[0].1.user_agent.as_deref(),
+            Some("Mozilla/5.0 (Windows NT 10.0)")
+        );
+        assert_eq!(options[1].0, other_url);
+        assert_eq!(options[1].1.user_agent, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_fills_body_and_propagates_fetch_errors() {
+        let detail_2 = "https://dept.yonsei.ac.kr/board/view.do?articleNo=2";
+        let detail_1 = "https://dept.yonsei.ac.kr/board/view.do?articleNo=1";
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let board = test_board(Some("div.content"));
+
+        let crawler = test_crawler(&[
+            (BOARD_URL, LIST_PAGE),
+            (detail_2, r#"<div class="content"><p>본문 2</p></div>"#),
+            (detail_1, r#"<div class="content"><p>본문 1</p></div>"#),
+        ]);
+        let notices = crawler.fetch(&board, context).await.unwrap();
+        assert_eq!(notices[0].body, "<p>본문 2</p>");
+        assert_eq!(notices[1].body, "<p>본문 1</p>");
+
+        // A missing detail page surfaces as the fetcher's error.
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE), (detail_2, "<p>x</p>")]);
+        let err = crawler.fetch(&board, context).await.unwrap_err();
+        assert!(matches!(err, AppError::UpstreamHttp { status: 404, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_skips_failing_domain() {
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.max_concurrent = 1;
+        config.crawler.circuit_breaker_threshold = 2;
+
+        let healthy_url = "https://ok.yonsei.ac.kr/board/list.do";
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(healthy_url.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        });
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+
+        let mut dept = test_department();
+        for idx in 0..4 {
+            let mut board = test_board(None);
+            board.id = format!("down_{idx}");
+            board.url = format!("https://down.yonsei.ac.kr/board/{idx}");
+            dept.boards.push(board);
+        }
+        let mut healthy = test_board(None);
+        healthy.url = healthy_url.to_string();
+        dept.boards.push(healthy);
+        let campus = test_campus(dept);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        let requests = fetcher.requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![
+                "https://down.yonsei.ac.kr/board/0".to_string(),
+                "https://down.yonsei.ac.kr/board/1".to_string(),
+                healthy_url.to_string(),
+            ]
+        );
+        assert_eq!(outcome.board_failures, 4);
+        assert_eq!(outcome.notices.len(), 2);
+        assert_eq!(outcome.open_circuits, vec!["down.yonsei.ac.kr".to_string()]);
+        let skipped = outcome
+            .errors
+            .iter()
+            .filter(|e| matches!(e.stage, CrawlStage::CircuitOpen))
+            .count();
+        assert_eq!(skipped, 2);
+    }
+
+    #[tokio::test]
+    async fn test_clean_run_runs_hooks() {
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)])
+            .with_hooks(vec![Box::new(crate::services::KeywordCategorizer)]);
+        let mut dept = test_department();
+        dept.boards.push(test_board(None));
+        let campus = test_campus(dept);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.notices.len(), 2);
+        assert!(
+            outcome
+                .notices
+                .iter()
+                .all(|n| n.category.as_deref() == Some("scholarship"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_short_response_fails_board() {
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.min_content_length = Some(LIST_PAGE.len() + 1);
+        let fetcher = MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        };
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+        let mut dept = test_department();
+        dept.boards.push(test_board(None));
+        let campus = test_campus(dept);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert!(outcome.notices.is_empty());
+        assert_eq!(outcome.board_failures, 1);
+        assert!(matches!(outcome.errors[0].stage, CrawlStage::ShortResponse));
+        assert!(
+            outcome.errors[0]
+                .message
+                .starts_with("Suspiciously short response")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notice_stream_yields_notices_as_boards_complete() {
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)]);
+        let mut dept = test_department();
+        dept.boards.push(test_board(None));
+        dept.boards.push(Board {
+            id: "missing".to_string(),
+            url: "https://dept.yonsei.ac.kr/missing".to_string(),
+            ..test_board(None)
+        });
+        let mut campuses = [test_campus(dept)];
+
+        let (mut ok, mut failed) = (0, 0);
+        let mut stream = crawler.notice_stream(&campuses);
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(_) => ok += 1,
+                Err(_) => failed += 1,
+            }
+        }
+        drop(stream);
+        assert_eq!((ok, failed), (2, 1));
+
+        // The collecting helper stops at the failed board
+        assert!(
+            crawler
+                .notice_stream(&campuses)
+                .collect_all()
+                .await
+                .is_err()
+        );
+        campuses[0].departments[0].boards.pop();
+        let notices = crawler
+            .notice_stream(&campuses)
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(notices.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_board_below_expected_count_is_flagged() {
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)]);
+        let mut dept = test_department();
+        dept.boards.push(Board {
+            expected_min_notices: Some(15),
+            ..test_board(None)
+        });
+        let campus = test_campus(dept);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        // Still a successful board, but reported
+        assert_eq!(outcome.notices.len(), 2);
+        assert_eq!(outcome.board_failures, 0);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(matches!(
+            outcome.errors[0].stage,
+            CrawlStage::BelowExpectedCount
+        ));
+        assert_eq!(
+            outcome.errors[0].message,
+            "2 notices extracted, expected at least 15"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_page_skips_reextraction() {
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.skip_unchanged = true;
+        let fetcher = MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        };
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let board = test_board(None);
+        let selectors = NoticeCrawler::compile_selectors(&board, &mut Vec::new()).unwrap();
+
+        let first = crawler
+            .fetch_board_list(context, &board, &selectors)
+            .await
+            .unwrap();
+        let second = crawler
+            .fetch_board_list(context, &board, &selectors)
+            .await
+            .unwrap();
+
+        assert!(!first.unchanged);
+        assert!(second.unchanged);
+        assert_eq!(second.notices, first.notices);
+
+        // A selector change invalidates the stored hash.
+        let mut edited = board.clone();
+        edited.selectors.date_selector = "td:last-child".to_string();
+        let selectors = NoticeCrawler::compile_selectors(&edited, &mut Vec::new()).unwrap();
+        let third = crawler
+            .fetch_board_list(context, &edited, &selectors)
+            .await
+            .unwrap();
+        assert!(!third.unchanged);
+    }
+
+    #[tokio::test]
+    async fn test_debug_dir_dumps_html_and_headers() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.paths.debug_dir = Some(dir.path().to_string_lossy().into_owned());
+        let fetcher = MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            headers: [
+                ("content-type", "text/html; charset=euc-kr"),
+                ("server", "Apache"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+            ..MockFetcher::default()
+        };
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+        let dept = test_department();
+        let context = dept_ref(&dept);
+
+        crawler.fetch(&test_board(None), context).await.unwrap();
+
+        let html =
+            std::fs::read_to_string(dir.path().join("yonsei_dept_scholarship.html")).unwrap();
+        assert_eq!(html, LIST_PAGE);
+        let sidecar: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(dir.path().join("yonsei_dept_scholarship.headers.json"))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(sidecar["url"], BOARD_URL);
+        assert_eq!(
+            sidecar["headers"]["content-type"],
+            "text/html; charset=euc-kr"
+        );
+        assert_eq!(sidecar["headers"]["server"], "Apache");
+    }
+
+    #[test]
+    fn test_campus_filter_limits_boards_to_crawl() {
+        let campus = |name: &str, enabled: bool| {
+            let mut dept = test_department();
+            dept.boards.push(test_board(None));
+            Campus {
+                campus: name.to_string(),
+                enabled,
+                ..test_campus(dept)
+            }
+        };
+        let campuses = [
+            campus("신촌캠퍼스", true),
+            campus("미래캠퍼스", true),
+            campus("국제캠퍼스", false),
+        ];
+        let campus_names = |crawler: &NoticeCrawler| -> Vec<String> {
+            crawler
+                .boards_to_crawl(&campuses)
+                .iter()
+                .map(|(dept_ref, _)| dept_ref.campus.to_string())
+                .collect()
+        };
+
+        let crawler = test_crawler(&[]);
+        assert_eq!(campus_names(&crawler), ["신촌캠퍼스", "미래캠퍼스"]);
+
+        let mut config = Config::default();
+        config.crawler.campuses = vec!["미래캠퍼스".to_string(), "국제캠퍼스".to_string()];
+        let crawler =
+            NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(MockFetcher::default()));
+        assert_eq!(campus_names(&crawler), ["미래캠퍼스"]);
+    }
+
+    #[test]
+    fn test_notice_and_discovery_concurrency_are_separate() {
+        let mut config = Config::default();
+        config.crawler.max_concurrent = 9;
+        config.crawler.max_concurrent_discovery = 2;
+
+        let client = Client::new();
+        let departments = crate::services::DepartmentCrawler::new(&client, &config.crawler);
+        let notices =
+            NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(MockFetcher::default()));
+
+        assert_eq!(notices.concurrency(), 9);
+        assert_eq!(departments.max_concurrent(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_stops_at_date_boundary() {
+        let page = |dates: [&str; 2], first_id: u32| {
+            let rows: String = dates
+                .iter()
+                .zip(first_id..)
+                .map(|(date, id)| {
+                    format!(
+                        r#"<tr><td class="subject"><a href="view.do?articleNo={id}">공지 {id}</a></td><td class="date">{date}</td></tr>"#
+                    )
+                })
+                .collect();
+            format!(r#"<table class="board_list"><tbody>{rows}</tbody></table>"#)
+        };
+        let pages = [
+            (BOARD_URL.to_string(), page(["2024-03-05", "2024-03-04"], 1)),
+            (
+                format!("{BOARD_URL}?page=2"),
+                page(["2024-03-03", "2024-03-02"], 3),
+            ),
+            (
+                format!("{BOARD_URL}?page=3"),
+                page(["2024-03-01", "2024-02-28"], 5),
+            ),
+            (
+                format!("{BOARD_URL}?page=4"),
+                page(["2024-02-27", "2024-02-26"], 7),
+            ),
+        ];
+        let fetcher = Arc::new(MockFetcher {
+            pages: pages.into_iter().collect(),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.backfill_until = chrono::NaiveDate::from_ymd_opt(2024, 3, 1);
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let mut board = test_board(None);
+        board.page_param = Some("page".to_string());
+
+        let notices = crawler.fetch(&board, context).await.unwrap();
+
+        assert_eq!(notices.len(), 6);
+        assert_eq!(notices.last().unwrap().date, "2024-02-28");
+        assert_eq!(fetcher.requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_crawl_order_follows_priority() {
+        let fetcher = Arc::new(MockFetcher::default());
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.max_concurrent = 1;
+        config.crawler.circuit_breaker_threshold = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+
+        let mut dept = test_department();
+        for (id, priority) in [
+            ("low", -1),
+            ("default", 0),
+            ("high", 10),
+            ("also_default", 0),
+        ] {
+            let mut board = test_board(None);
+            board.id = id.to_string();
+            board.url = format!("https://dept.yonsei.ac.kr/{id}");
+            board.priority = priority;
+            dept.boards.push(board);
+        }
+        let campus = test_campus(dept);
+
+        crawler.fetch_all(&[campus]).await.unwrap();
+
+        let requests = fetcher.requests.lock().unwrap().clone();
+        let order: Vec<_> = requests
+            .iter()
+            .map(|url| url.rsplit('/').next().unwrap())
+            .collect();
+        assert_eq!(order, ["high", "default", "also_default", "low"]);
+    }
+
+    #[tokio::test]
+    async fn test_board_failures_are_categorized() {
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)]);
+        let mut dept = test_department();
+        let mut missing = test_board(None);
+        missing.id = "missing".to_string();
+        missing.url = "https://dept.yonsei.ac.kr/missing/list.do".to_string();
+        let mut broken = test_board(None);
+        broken.id = "broken".to_string();
+        broken.selectors.title_selector = "td[".to_string();
+        let mut unset = test_board(None);
+        unset.id = "unset".to_string();
+        unset.selectors.date_selector = String::new();
+        let mut no_rows = test_board(None);
+        no_rows.id = "no_rows".to_string();
+        no_rows.selectors.row_selector = "ul.board-list li".into();
+        let mut no_notices = test_board(None);
+        no_notices.id = "no_notices".to_string();
+        no_notices.selectors.date_selector = "td.regdate".to_string();
+        dept.boards = vec![missing, broken, unset, no_rows, no_notices];
+        let campus = test_campus(dept);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        let kind_of = |board_id: &str| {
+            outcome
+                .errors
+                .iter()
+                .find(|e| e.board_id.as_deref() == Some(board_id))
+                .and_then(|e| e.kind)
+        };
+        assert_eq!(outcome.board_failures, 3);
+        assert_eq!(kind_of("missing"), Some(BoardErrorKind::Status(404)));
+        assert_eq!(kind_of("broken"), Some(BoardErrorKind::BadSelector));
+        assert_eq!(kind_of("unset"), Some(BoardErrorKind::BadSelector));
+        // Empty boards are reported, but not counted as failures
+        assert_eq!(kind_of("no_rows"), Some(BoardErrorKind::EmptyRows));
+        assert_eq!(kind_of("no_notices"), Some(BoardErrorKind::NoNotices));
+    }
+
+    #[tokio::test]
+    async fn test_network_and_timeout_failures_are_categorized() {
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let client = Client::builder()
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let crawler = NoticeCrawler::new(Arc::new(config), client).unwrap();
+
+        // Nothing listens on port 1
+        let mut board = test_board(None);
+        board.url = "http://127.0.0.1:1/board/list.do".to_string();
+        let err = crawler.fetch_list(&board, context).await.unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::Board {
+                kind: BoardErrorKind::Network,
+                ..
+            }
+        ));
+
+        // Accepts connections but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        board.url = format!("http://{}/board/list.do", listener.local_addr().unwrap());
+        let err = crawler.fetch_list(&board, context).await.unwrap_err();
+        assert_eq!(err.board_error_kind(), BoardErrorKind::Timeout);
+
+        board.selectors.date_selector = "td[".to_string();
+        let err = crawler.fetch_list(&board, context).await.unwrap_err();
+        assert_eq!(err.board_error_kind(), BoardErrorKind::BadSelector);
+    }
+
+    #[tokio::test]
+    async fn test_robots_crawl_delay_raises_request_delay() {
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(
+                "https://dept.yonsei.ac.kr/robots.txt".to_string(),
+                "User-agent: *\nDisallow: /admin\nCrawl-delay: 1.5\n".to_string(),
+            )]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 100;
+        config.crawler.respect_crawl_delay = true;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+
+        assert_eq!(
+            crawler.request_delay(BOARD_URL).await,
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            crawler
+                .request_delay("https://dept.yonsei.ac.kr/view.do")
+                .await,
+            Duration::from_millis(1500)
+        );
+        // No robots.txt: the configured delay stands
+        assert_eq!(
+            crawler
+                .request_delay("https://other.yonsei.ac.kr/list.do")
+                .await,
+            Duration::from_millis(100)
+        );
+        // robots.txt is fetched once per domain
+        assert_eq!(fetcher.requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_robots_crawl_delay_is_clamped() {
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(
+                "https://dept.yonsei.ac.kr/robots.txt".to_string(),
+                "User-agent: *\nCrawl-delay: 86400\n".to_string(),
+            )]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.respect_crawl_delay = true;
+        config.crawler.max_crawl_delay_secs = 10;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher);
+
+        assert_eq!(
+            crawler.request_delay(BOARD_URL).await,
+            Duration::from_secs(10)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_same_host_boards_do_not_hold_every_slot() {
+        // Four consecutive boards on one host, then one elsewhere
+        let boards = ["a", "a", "a", "a", "b"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, host)| {
+                (
+                    format!("board_{idx}"),
+                    format!("https://{host}.yonsei.ac.kr/board/list.do?bbs={idx}"),
+                    Duration::from_secs(1),
+                )
+            });
+        let (crawler, fetcher, campus) = crawler_with_boards(boards, |crawler| {
+            crawler.max_concurrent = 2;
+            crawler.max_concurrent_per_domain = Some(1);
+        });
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.board_failures, 0);
+        // The other host runs alongside the first board instead of queueing
+        // behind slots waiting on the busy host
+        assert_eq!(fetcher.max_in_flight.load(Ordering::SeqCst), 2);
+        let requests = fetcher.requests.lock().unwrap();
+        assert!(requests[..2].iter().any(|url| url.contains("b.yonsei")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_in_flight_requests_match_max_concurrent() {
+        let boards = (0..8).map(|idx| {
+            (
+                format!("board_{idx}"),
+                format!("https://dept{idx}.yonsei.ac.kr/board/list.do"),
+                Duration::from_secs(1),
+            )
+        });
+        let (crawler, fetcher, campus) =
+            crawler_with_boards(boards, |crawler| crawler.max_concurrent = 3);
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.board_failures, 0);
+        assert_eq!(fetcher.max_in_flight.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_max_run_secs_returns_partial_results() {
+        let boards = [("fast", 0), ("slow", 600)].map(|(id, delay)| {
+            (
+                id.to_string(),
+                format!("https://{id}.yonsei.ac.kr/board/list.do"),
+                Duration::from_secs(delay),
+            )
+        });
+        let (crawler, _, campus) =
+            crawler_with_boards(boards, |crawler| crawler.max_run_secs = Some(1));
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert!(outcome.cut_off);
+        assert_eq!(outcome.board_total, 2);
+        assert_eq!(outcome.board_failures, 0);
+        assert!(!outcome.notices.is_empty());
+        assert!(outcome.notices.iter().all(|n| n.board_id == "fast"));
+    }
+
+    #[tokio::test]
+    async fn test_per_domain_limit_within_global_cap() {
+        let boards = (0..12).map(|idx| {
+            let host = ["cs", "math", "physics"][idx % 3];
+            (
+                format!("board_{idx}"),
+                format!("https://{host}.yonsei.ac.kr/board/{idx}"),
+                Duration::from_millis(20),
+            )
+        });
+        let (crawler, fetcher, campus) = crawler_with_boards(boards, |crawler| {
+            crawler.max_concurrent = 4;
+            crawler.max_concurrent_per_domain = Some(2);
+        });
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.board_failures, 0);
+        assert!(fetcher.max_in_flight.load(Ordering::SeqCst) <= 4);
+        let domains = fetcher.domain_in_flight.lock().unwrap();
+        assert_eq!(domains.len(), 3);
+        assert!(domains.values().all(|(_, max)| *max <= 2));
+        assert!(domains.values().any(|(_, max)| *max == 2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_ramp_up_limits_in_flight_during_warm_up() {
+        let boards = (0..8).map(|idx| {
+            (
+                format!("board_{idx}"),
+                format!("https://dept.yonsei.ac.kr/board/{idx}"),
+                Duration::from_secs(1),
+            )
+        });
+        let (crawler, fetcher, campus) = crawler_with_boards(boards, |crawler| {
+            crawler.max_concurrent = 4;
+            // A second slot opens only after 20s; the whole run takes about 8s
+            crawler.ramp_up_secs = Some(60);
+        });
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.board_failures, 0);
+        assert_eq!(fetcher.max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_board_timings_identify_slowest_board() {
+        let boards = [("fast", 0), ("slow", 60)].map(|(id, delay)| {
+            (
+                id.to_string(),
+                format!("https://dept.yonsei.ac.kr/{id}"),
+                Duration::from_millis(delay),
+            )
+        });
+        let (crawler, _, campus) = crawler_with_boards(boards, |_| {});
+
+        let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(outcome.board_timings.len(), 2);
+        let slowest = outcome.slowest_boards(1);
+        assert_eq!(slowest[0].board_id, "slow");
+        assert!(slowest[0].duration_ms >= 60);
+        let report = crate::models::CrawlOutcomeReport::from(&outcome);
+        let order: Vec<_> = report
+            .slow_boards
+            .iter()
+            .map(|t| t.board_id.as_str())
+            .collect();
+        assert_eq!(order, ["slow", "fast"]);
+    }
+
+    #[tokio::test]
+    async fn test_fixed_clock_drives_cooldown_and_relative_dates() {
+        let t0 = Utc.with_ymd_and_hms(2024, 3, 10, 3, 0, 0).unwrap();
+        let page = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a href="view.do?articleNo=3">오늘 올라온 공지</a></td><td class="date">오늘</td></tr>
+        </tbody></table>"#;
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let config = Arc::new(config);
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), page.to_string())]),
+            ..MockFetcher::default()
+        });
+        let mut dept = test_department();
+        let mut board = test_board(None);
+        board.min_interval_secs = Some(3600);
+        dept.boards.push(board);
+        let campuses = [test_campus(dept)];
+        let crawler_at = |now, state| {
+            NoticeCrawler::with_fetcher(Arc::clone(&config), fetcher.clone())
+                .with_state(state)
+                .with_clock(Arc::new(FixedClock(now)))
+        };
+
+        let first = crawler_at(t0, CrawlState::default());
+        let outcome = first.fetch_all(&campuses).await.unwrap();
+        let local_today = FixedClock(t0).local_now().date().to_string();
+        assert_eq!(outcome.notices[0].date, local_today);
+        let key = CrawlState::board_key("yonsei_dept", "scholarship");
+        assert_eq!(first.state().boards[&key].last_success, Some(t0));
+
+        let soon = crawler_at(t0 + chrono::Duration::minutes(30), first.state());
+        assert_eq!(soon.fetch_all(&campuses).await.unwrap().board_skipped, 1);
+
+        let later = crawler_at(t0 + chrono::Duration::hours(2), first.state());
+        assert_eq!(later.fetch_all(&campuses).await.unwrap().notices.len(), 1);
+        assert_eq!(fetcher.requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_title_suppressed_only_within_window() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 3, 0, 0).unwrap();
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.filter.suppress_duplicate_titles_days = Some(7);
+        let fetcher = MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        };
+        // Both titles were first seen under other links: one 3 days ago
+        // (inside the window), one 10 days ago (outside it)
+        let key = CrawlState::board_key("yonsei_dept", "scholarship");
+        let seen = |days_ago| crate::models::SeenTitle {
+            notice_id: "earlier-post".to_string(),
+            first_seen: now - chrono::Duration::days(days_ago),
+        };
+        let mut state = CrawlState::default();
+        let titles = &mut state.boards.entry(key.clone()).or_default().titles;
+        titles.insert("장학금 신청 안내".to_string(), seen(3));
+        titles.insert("수강신청 일정".to_string(), seen(10));
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher))
+            .with_state(state)
+            .with_clock(Arc::new(FixedClock(now)));
+        let mut dept = test_department();
+        dept.boards.push(test_board(None));
+        let campuses = [test_campus(dept)];
+
+        let outcome = crawler.fetch_all(&campuses).await.unwrap();
+        let titles: Vec<_> = outcome.notices.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, ["수강신청 일정"]);
+        let kept = &crawler.state().boards[&key].titles["수강신청 일정"];
+        assert_eq!(kept.notice_id, outcome.notices[0].canonical_id());
+        assert_eq!(kept.first_seen, now);
+
+        // The kept notice is not suppressed by its own title on the next run
+        let again = crawler.fetch_all(&campuses).await.unwrap();
+        assert_eq!(again.notices.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_board_in_cooldown_is_skipped_next_cycle() {
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let mut dept = test_department();
+        let mut board = test_board(None);
+        board.min_interval_secs = Some(3600);
+        dept.boards.push(board);
+        let campus = test_campus(dept);
+
+        let first = crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        let second = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(first.notices.len(), 2);
+        assert_eq!(second.board_skipped, 1);
+        assert!(second.notices.is_empty());
+        assert_eq!(fetcher.requests.lock().unwrap().len(), 1);
+        let key = CrawlState::board_key("yonsei_dept", "scholarship");
+        assert!(crawler.state().boards[&key].last_success.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_empty_board_does_not_start_cooldown() {
+        let empty = r#"<table class="board_list"><tbody></tbody></table>"#;
+        let fetcher = Arc::new(MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), empty.to_string())]),
+            ..MockFetcher::default()
+        });
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), fetcher.clone());
+        let mut dept = test_department();
+        let mut board = test_board(None);
+        board.min_interval_secs = Some(3600);
+        dept.boards.push(board);
+        let campus = test_campus(dept);
+
+        crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        let second = crawler.fetch_all(&[campus]).await.unwrap();
+
+        assert_eq!(second.board_skipped, 0);
+        assert_eq!(fetcher.requests.lock().unwrap().len(), 2);
+        let key = CrawlState::board_key("yonsei_dept", "scholarship");
+        assert!(
+            crawler
+                .state()
+                .boards
+                .get(&key)
+                .is_none_or(|board| board.last_success.is_none())
+        );
+    }
+
+    #[test]
+    fn test_extract_notices_from_table_fixture() {
+        let html = r#"
+            <table class="board_list">
+              <thead><tr><th>번호</th><th>제목</th><th>작성자</th><th>작성일</th></tr></thead>
+              <tbody>
+                <tr class="notice">
+                  <td>공지</td>
+                  <td class="subject"><a href="/board/view.do?articleNo=310&amp;utm_source=main">[필독] 2024-1학기   수강신청 안내 <img alt="첨부파일"></a></td>
+                  <td class="writer">학사팀</td>
+                  <td class="date">2024.02.26</td>
+                </tr>
+                <tr>
+                  <td>309</td>
+                  <td class="subject"><a href="view.do?articleNo=309">장학금 신청 안내</a></td>
+                  <td class="writer">장학팀</td>
+                  <td class="date">2024.02.20</td>
+                </tr>
+                <tr>
+                  <td colspan="4">등록된 게시물이 없습니다.</td>
+                </tr>
+              </tbody>
+            </table>"#;
+        let mut board = test_board(None);
+        board.selectors.author_selector = Some("td.writer".to_string());
+        let base_url = url::Url::parse(BOARD_URL).unwrap();
+        let dept = test_department();
+        let context = DepartmentRef {
+            campus: "신촌캠퍼스",
+            college: Some("공과대학"),
+            dept: &dept,
+        };
+        let config = Config::default();
+
+        let extraction = extract_notices(
+            html,
+            &board,
+            &base_url,
+            &config.cleaning,
+            &config.discovery,
+            context,
+            &SystemClock,
+        )
+        .unwrap();
+        let notices = &extraction.notices;
+
+        assert_eq!(extraction.rows_matched, 3);
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].title, "[필독] 2024-1학기 수강신청 안내");
+        assert_eq!(notices[0].author, "학사팀");
+        assert_eq!(
+            notices[0].link,
+            "https://dept.yonsei.ac.kr/board/view.do?articleNo=310"
+        );
+        assert_eq!(notices[0].source_id.as_deref(), Some("310"));
+        assert_eq!(
+            notices[1].link,
+            "https://dept.yonsei.ac.kr/board/view.do?articleNo=309"
+        );
+        assert_eq!(notices[1].board_id, "scholarship");
+
+        board.selectors.title_selector = "[[invalid".to_string();
+        assert!(
+            extract_notices(
+                html,
+                &board,
+                &base_url,
+                &config.cleaning,
+                &config.discovery,
+                context,
+                &SystemClock,
+            )
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capture_row_html_keeps_source_row() {
+        let mut config = Config::default();
+        config.crawler.request_delay_ms = 0;
+        config.crawler.capture_row_html = true;
+        let fetcher = MockFetcher {
+            pages: HashMap::from([(BOARD_URL.to_string(), LIST_PAGE.to_string())]),
+            ..MockFetcher::default()
+        };
+        let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+        let dept = test_department();
+        let context = dept_ref(&dept);
+
+        let notices = crawler
+            .fetch_list(&test_board(None), context)
+            .await
+            .unwrap()
+            .notices;
+        assert_eq!(
+            notices[0].raw_html.as_deref(),
+            Some(
+                r#"<tr><td class="subject"><a href="view.do?articleNo=2">장학금 신청 안내</a></td><td class="date">2024-03-02</td></tr>"#
+            )
+        );
+
+        // Off by default
+        let extraction = extract_with(&test_board(None), LIST_PAGE);
+        assert!(extraction.notices.iter().all(|n| n.raw_html.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_bad_selector_surfaces_as_selector_error() {
+        let mut board = test_board(None);
+        board.selectors.date_selector = "td[".to_string();
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let config = Config::default();
+
+        let err = extract_notices(
+            LIST_PAGE,
+            &board,
+            &url::Url::parse(BOARD_URL).unwrap(),
+            &config.cleaning,
+            &config.discovery,
+            context,
+            &SystemClock,
+        )
+        .unwrap_err();
+        let AppError::Selector { selector, message } = &err else {
+            panic!("expected a selector error, got {err:?}");
+        };
+        assert_eq!(selector, "td[");
+        assert_eq!(message, "Unexpected EOL");
+
+        // The crawl path wraps it as a board failure carrying the same text
+        let crawler = test_crawler(&[(BOARD_URL, LIST_PAGE)]);
+        let err = crawler.fetch(&board, context).await.unwrap_err();
+        let AppError::Board { kind, message, .. } = err else {
+            panic!("expected a board error");
+        };
+        assert_eq!(kind, BoardErrorKind::BadSelector);
+        assert!(message.starts_with("Invalid selector 'td['"));
+    }
+
+    fn extract_with(board: &Board, html: &str) -> BoardExtraction {
+        let config = Config::default();
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        extract_notices(
+            html,
+            board,
+            &url::Url::parse(BOARD_URL).unwrap(),
+            &config.cleaning,
+            &config.discovery,
+            context,
+            &SystemClock,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_implausible_dates_are_flagged() {
+        let html = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a href="view.do?articleNo=1">조회수를 날짜로</a></td><td class="date">1523</td></tr>
+            <tr><td class="subject"><a href="view.do?articleNo=2">오래된 공지</a></td><td class="date">1999.12.31</td></tr>
+            <tr><td class="subject"><a href="view.do?articleNo=3">정상 공지</a></td><td class="date">2024-03-01</td></tr>
+            <tr><td class="subject"><a href="view.do?articleNo=4">상시 모집</a></td><td class="date">상시</td></tr>
+        </tbody></table>"#;
+        let extraction = extract_with(&test_board(None), html);
+
+        let dates: Vec<&str> = extraction.notices.iter().map(|n| n.date.as_str()).collect();
+        assert_eq!(dates, ["", "", "2024-03-01", "상시"]);
+        assert_eq!(extraction.implausible_dates, 2);
+        assert_eq!(
+            extraction.diagnostic().as_deref(),
+            Some("2 implausible dates cleared — check date_selector")
+        );
+        assert_eq!(extraction.empty_kind(), None);
+    }
+
+    #[test]
+    fn test_link_from_anchor_inside_title_cell() {
+        let mut board = test_board(None);
+        board.selectors.title_selector = "td.subject".to_string();
+        let html = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><span class="cate">[학사]</span> <a href="view.do?articleNo=5">졸업 안내</a></td><td class="date">2024-03-05</td></tr>
+        </tbody></table>"#;
+
+        let notices = extract_with(&board, html).notices;
+        assert_eq!(notices.len(), 1);
+        assert_eq!(
+            notices[0].link,
+            "https://dept.yonsei.ac.kr/board/view.do?articleNo=5"
+        );
+    }
+
+    #[test]
+    fn test_board_cleaning_patterns_apply_to_that_board_only() {
+        let html = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a href="view.do?articleNo=1">[CS] 졸업 안내 공지</a></td><td class="date">등록 2024-03-01</td></tr>
+        </tbody></table>"#;
+        let mut config = Config::default();
+        config.cleaning.title_remove_patterns = vec!["공지".to_string()];
+        let extract = |board: &Board| {
+            let dept = test_department();
+            let context = dept_ref(&dept);
+            let url = url::Url::parse(BOARD_URL).unwrap();
+            let notices = extract_notices(
+                html,
+                board,
+                &url,
+                &config.cleaning,
+                &config.discovery,
+                context,
+                &SystemClock,
+            )
+            .unwrap()
+            .notices;
+            (notices[0].title.clone(), notices[0].date.clone())
+        };
+
+        let plain = test_board(None);
+        let mut custom = test_board(None);
+        custom.title_remove_patterns = vec!["[CS]".to_string()];
+        custom.date_remove_patterns = vec!["등록".to_string()];
+        assert_eq!(
+            extract(&custom),
+            ("졸업 안내".to_string(), "2024-03-01".to_string())
+        );
+        assert_eq!(
+            extract(&plain),
+            ("[CS] 졸업 안내".to_string(), "등록 2024-03-01".to_string())
+        );
+
+        custom.replace_cleaning_patterns = true;
+        assert_eq!(extract(&custom).0, "졸업 안내 공지");
+    }
+
+    #[test]
+    fn test_korean_href_is_percent_encoded() {
+        let html = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a href="/게시판/view.do?title=공지&amp;page=%EA%B3%B5">수강 안내</a></td><td class="date">2024-03-01</td></tr>
+        </tbody></table>"#;
+        let notices = extract_with(&test_board(None), html).notices;
+        assert_eq!(
+            notices[0].link,
+            "https://dept.yonsei.ac.kr/%EA%B2%8C%EC%8B%9C%ED%8C%90/view.do?title=%EA%B3%B5%EC%A7%80&page=%EA%B3%B5"
+        );
+    }
+
+    #[test]
+    fn test_link_from_fallback_attr() {
+        let mut board = test_board(None);
+        board.attr_fallbacks = vec!["data-url".to_string(), "data-href".to_string()];
+        let html = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a data-href="view.do?articleNo=9" data-url="view.do?articleNo=8">휴학 안내</a></td><td class="date">2024-03-08</td></tr>
+            <tr><td class="subject"><a href="" data-href="view.do?articleNo=7">복학 안내</a></td><td class="date">2024-03-07</td></tr>
+        </tbody></table>"#;
+
+        let links: Vec<String> = extract_with(&board, html)
+            .notices
+            .into_iter()
+            .map(|notice| notice.link)
+            .collect();
+        assert_eq!(
+            links,
+            [
+                "https://dept.yonsei.ac.kr/board/view.do?articleNo=8",
+                "https://dept.yonsei.ac.kr/board/view.do?articleNo=7",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links_resolve_against_base_href() {
+        let board = test_board(None);
+        let html = r#"<html><head><base href="/cms/notice/"></head><body>
+            <table class="board_list"><tbody>
+            <tr><td class="subject"><a href="view.do?articleNo=7">수강신청 안내</a></td><td class="date">2024-03-07</td></tr>
+            </tbody></table></body></html>"#;
+
+        let notices = extract_with(&board, html).notices;
+        assert_eq!(notices.len(), 1);
+        assert_eq!(
+            notices[0].link,
+            "https://dept.yonsei.ac.kr/cms/notice/view.do?articleNo=7"
+        );
+    }
+
+    #[test]
+    fn test_file_base_href_is_ignored_on_https_board() {
+        let board = test_board(None);
+        let html = r#"<html><head><base href="file:///etc/"></head><body>
+            <table class="board_list"><tbody>
+            <tr><td class="subject"><a href="passwd">수강신청 안내</a></td><td class="date">2024-03-07</td></tr>
+            </tbody></table></body></html>"#;
+
+        let notices = extract_with(&board, html).notices;
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].link, "https://dept.yonsei.ac.kr/board/passwd");
+    }
+
+    #[test]
+    fn test_link_from_title_anchor_itself() {
+        let board = test_board(None);
+        let html = r#"<table class="board_list"><tbody>
+            <tr><td class="subject"><a href="view.do?articleNo=6"><b>휴강</b> 안내</a></td><td class="date">2024-03-06</td></tr>
+        </tbody></table>"#;
+
+        let notices = extract_with(&board, html).notices;
+        assert_eq!(notices.len(), 1);
+        assert_eq!(
+            notices[0].link,
+            "https://dept.yonsei.ac.kr/board/view.do?articleNo=6"
+        );
+    }
+
+    #[test]
+    fn test_skip_rows_drops_header_row() {
+        let mut board = test_board(None);
+        board.selectors.row_selector = "table.board_list tr".into();
+        board.selectors.title_selector = "td.subject".to_string();
+        let html = r#"<table class="board_list">
+            <tr><td class="subject">제목</td><td class="date">날짜</td></tr>
+            <tr><td class="subject"><a href="view.do?articleNo=7">등록금 납부 안내</a></td><td class="date">2024-03-07</td></tr>
+        </table>"#;
+
+        let mut config = Config::default();
+        config.discovery.require_valid_link = false;
+        let dept = test_department();
+        let context = dept_ref(&dept);
+        let extract = |board: &Board| {
+            extract_notices(
+                html,
+                board,
+                &url::Url::parse(BOARD_URL).unwrap(),
+                &config.cleaning,
+                &config.discovery,
+                context,
+                &SystemClock,
+            )
+            .unwrap()
+            .notices
+        };
+
+        let notices = extract(&board);
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].title, "제목");
+
+        board.skip_rows = 1;
+        let notices = extract(&board);
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].title, "등록금 납부 안내");
+    }
+
+    #[test]
+    fn test_row_selector_list_combines_tables() {
+        let mut board = test_board(None);
+        board.selectors.row_selector = RowSelector::List(vec![
+            "table.general tbody tr".to_string(),
+            "table.notice tbody tr".to_string(),
+        ]);
+        board.selectors.title_selector = "td.subject a".to_string();
+        let html = r#"
+            <table class="notice"><tbody>
+              <tr><td class="subject"><a href="view.do?articleNo=1">학사 공지</a></td><td class="date">2024-03-01</td></tr>
+            </tbody></table>
+            <table class="general"><tbody>
+              <tr><td class="subject"><a href="view.do?articleNo=2">일반 게시글</a></td><td class="date">2024-03-02</td></tr>
+              <tr><td class="subject"><a href="view.do?articleNo=3">동아리 모집</a></td><td class="date">2024-03-03</td></tr>
+            </tbody></table>"#;
+
+        let extraction = extract_with(&board, html);
+        let titles: Vec<_> = extraction
+            .notices
+            .iter()
+            .map(|n| n.title.as_str())
+            .collect();
+        assert_eq!(extraction.rows_matched, 3);
+        assert_eq!(titles, ["일반 게시글", "동아리 모집", "학사 공지"]);
+
+        let parsed: CmsSelectors = serde_json::from_str(
+            r#"{"row_selector": ["table.a tr", "table.b tr"], "title_selector": "a", "date_selector": "td"}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.row_selector.iter().count(), 2);
+        let parsed: CmsSelectors = serde_json::from_str(
+            r#"{"row_selector": "tr", "title_selector": "a", "date_selector": "td"}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.row_selector, "tr");
+    }
+
+    #[test]
+    fn test_overlapping_row_selectors_extract_each_row_once() {
+        let mut board = test_board(None);
+        board.dedup_within_board = false;
+        board.selectors.row_selector = RowSelector::List(vec![
+            "table.board_list tbody tr".to_string(),
+            "tr".to_string(),
+        ]);
+
+        let extraction = extract_with(&board, LIST_PAGE);
+        assert_eq!(extraction.rows_matched, 2);
+        assert_eq!(extraction.notices.len(), 2);
+        assert_eq!(extraction.rows_failed(), 0);
+    }
+
+    #[test]
+    fn test_pinned_duplicate_is_dropped_within_board() {
+        let mut board = test_board(None);
+        let html = r#"<table class="board_list"><tbody>
+            <tr class="pinned"><td class="subject"><a href="view.do?articleNo=9">[공지] 수강신청 안내</a></td><td class="date">2024-03-01</td></tr>
+            <tr><td class="subject"><a href="view.do?articleNo=10">장학 안내</a></td><td class="date">2024-03-03</td></tr>
+            <tr><td class="subject"><a href="view.do?articleNo=9">[공지] 수강신청 안내</a></td><td class="date">2024-03-01</td></tr>
+        </tbody></table>"#;
+
+        let extraction = extract_with(&board, html);
+        assert_eq!(extraction.duplicate_rows, 1);
+        assert_eq!(extraction.rows_failed(), 0);
+        let notices = extraction.notices;
+        assert_eq!(notices.len(), 2);
+        assert!(notices[0].link.ends_with("articleNo=9"));
+        assert!(notices[1].link.ends_with("articleNo=10"));
+
+        board.dedup_within_board = false;
+        assert_eq!(extract_with(&board, html).notices.len(), 3);
+    }
+
+    #[test]
+    fn test_extraction_diagnostic_zero_rows() {
+        let mut board = test_board(None);
+        board.selectors.row_selector = "ul.board-list li".into();
+
+        let extraction = extract_with(&board, LIST_PAGE);
+        assert_eq!(extraction.rows_matched, 0);
+        assert_eq!(extraction.notices_extracted(), 0);
+        assert!(extraction.diagnostic().unwrap().contains("row_selector"));
+        assert_eq!(extraction.empty_kind(), Some(BoardErrorKind::EmptyRows));
+    }
+
+    #[test]
+    fn test_extraction_diagnostic_rows_without_notices() {
+        let mut board = test_board(None);
+        board.selectors.date_selector = "td.regdate".to_string();
+
+        let extraction = extract_with(&board, LIST_PAGE);
+        assert_eq!(extraction.rows_matched, 2);
+        assert_eq!(extraction.notices_extracted(), 0);
+        assert_eq!(extraction.rows_failed(), 2);
+        assert_eq!(extraction.empty_kind(), Some(BoardErrorKind::NoNotices));
+        assert_eq!(
+            extraction.diagnostic().as_deref(),
+            Some("2 rows matched but 0 notices extracted — check title/date selectors")
+        );
+
+        assert!(
+            extract_with(&test_board(None), LIST_PAGE)
+                .diagnostic()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_selector_valid() {
+        assert!(NoticeCrawler::parse_selector("div.class").is_ok());
+        assert!(NoticeCrawler::parse_selector("tr:has(a)").is_ok());
+    }
+
+    #[test]
+    fn test_parse_selector_invalid() {
+        assert!(NoticeCrawler::parse_selector("[[invalid").is_err());
+    }
+
+    #[test]
+    fn test_apply_link_regex_from_onclick() {
+        let regex = Regex::new(r"goView\('(\d+)'\)").unwrap();
+        let onclick = "javascript:goView('12345'); return false;";
+
+        assert_eq!(
+            NoticeCrawler::apply_link_regex(&regex, Some("view.do?articleNo={1}"), onclick),
+            Some("view.do?articleNo=12345".to_string())
+        );
+        assert_eq!(
+            NoticeCrawler::apply_link_regex(&regex, None, onclick),
+            Some("12345".to_string())
+        );
+        assert_eq!(
+            NoticeCrawler::apply_link_regex(&regex, None, "location.href='#'"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_link_template_resolves_against_board_url() {
+        let regex = Regex::new(r"fnView\('(\w+)',\s*'(\d+)'\)").unwrap();
+        let href = NoticeCrawler::apply_link_regex(
+            &regex,
+            Some("/{1}/view.do?articleNo={2}"),
+            "fnView('notice', '77')",
+        )
+        .unwrap();
+        let base = url::Url::parse("https://dept.yonsei.ac.kr/board/list.do").unwrap();
+        assert_eq!(
+            resolve_url(&base, &href),
+            "https://dept.yonsei.ac.kr/notice/view.do?articleNo=77"
+        );
     }
 }
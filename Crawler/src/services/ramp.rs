@@ -0,0 +1,94 @@
+// src/services/ramp.rs
+
+//! Concurrency ramp-up.
+//!
+//! Starts a run with a single request slot and adds slots at even intervals
+//! until `max_concurrent` is reached, so rate limiters do not see the whole
+//! burst at once.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::task::JoinHandle;
+
+use crate::models::CrawlerConfig;
+
+/// A semaphore whose permits grow from 1 to `max` over the warm-up period.
+#[derive(Debug)]
+pub struct ConcurrencyRamp {
+    semaphore: Option<Arc<Semaphore>>,
+    ticker: Option<JoinHandle<()>>,
+}
+
+impl ConcurrencyRamp {
+    /// Ramp from 1 to `max` permits over `warm_up` (`None` = no ramp).
+    ///
+    /// Must be called within a Tokio runtime when `warm_up` is set.
+    pub fn new(max: usize, warm_up: Option<Duration>) -> Self {
+        let Some(warm_up) = warm_up.filter(|_| max > 1) else {
+            return Self {
+                semaphore: None,
+                ticker: None,
+            };
+        };
+        let semaphore = Arc::new(Semaphore::new(1));
+        let step = warm_up / u32::try_from(max - 1).unwrap_or(u32::MAX);
+        let ticker = tokio::spawn({
+            let semaphore = Arc::clone(&semaphore);
+            async move {
+                for _ in 1..max {
+                    tokio::time::sleep(step).await;
+                    semaphore.add_permits(1);
+                }
+            }
+        });
+        Self {
+            semaphore: Some(semaphore),
+            ticker: Some(ticker),
+        }
+    }
+
+    /// Create a ramp from the crawler settings.
+    pub fn from_config(config: &CrawlerConfig) -> Self {
+        Self::new(
+            config.max_concurrent.max(1),
+            config.ramp_up_secs.map(Duration::from_secs),
+        )
+    }
+
+    /// Wait for a request slot; the slot is released when the permit drops.
+    ///
+    /// Returns `None` immediately when there is no ramp.
+    pub async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        self.semaphore.as_ref()?.acquire().await.ok()
+    }
+}
+
+impl Drop for ConcurrencyRamp {
+    fn drop(&mut self) {
+        if let Some(ticker) = &self.ticker {
+            ticker.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn permits_grow_over_warm_up() {
+        let ramp = ConcurrencyRamp::new(3, Some(Duration::from_secs(10)));
+        let available =
+            |ramp: &ConcurrencyRamp| ramp.semaphore.as_ref().unwrap().available_permits();
+        assert_eq!(available(&ramp), 1);
+
+        tokio::time::sleep(Duration::from_millis(5_100)).await;
+        assert_eq!(available(&ramp), 2);
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(available(&ramp), 3);
+
+        assert!(ConcurrencyRamp::new(3, None).acquire().await.is_none());
+    }
+}
@@ -3,21 +3,50 @@
 //! CMS selector detection service.
 //!
 //! Detects the CMS type used by a website and returns appropriate CSS selectors.
+//! When no known CMS matches, candidate selectors are inferred from repeated
+//! sibling structures that carry both a link and a date-like cell.
 
-use scraper::Html;
+use std::collections::HashMap;
 
-use crate::models::{CmsPattern, CmsSelectors, Seed};
+use regex::Regex;
+use scraper::{ElementRef, Html};
+
+use crate::models::{Board, CmsPattern, CmsSelectors, Seed};
 use crate::utils::log::debug;
 
+/// Minimum number of matching siblings for a structure to count as a list.
+const MIN_ROWS: usize = 3;
+
+/// Date-like cells longer than this are treated as titles, not dates.
+const MAX_DATE_CELL_CHARS: usize = 24;
+
+/// A row selector candidate inferred from page structure.
+#[derive(Debug, Clone)]
+pub struct ScoredSelectors {
+    /// Proposed selectors
+    pub selectors: CmsSelectors,
+
+    /// Ranking score (higher is better)
+    pub score: f64,
+
+    /// Number of rows containing both a link and a date
+    pub matched_rows: usize,
+}
+
 /// Service for detecting CMS types and returning appropriate selectors.
 pub struct SelectorDetector {
     patterns: Vec<CmsPattern>,
+    date_pattern: Regex,
 }
 
 impl SelectorDetector {
     /// Create a new selector detector with the given patterns.
     pub fn new(patterns: Vec<CmsPattern>) -> Self {
-        Self { patterns }
+        Self {
+            patterns,
+            date_pattern: Regex::new(r"\d{2,4}\s*[.\-/]\s*\d{1,2}\s*[.\-/]\s*\d{1,2}")
+                .expect("valid date pattern"),
+        }
     }
 
     /// Detect CMS type and return appropriate selectors.
@@ -35,7 +64,7 @@ impl SelectorDetector {
                     ));
                 }
                 Some(CmsSelectors::from_pattern(
-                    &pattern.row_selector,
+                    pattern.row_selector.as_str(),
                     &pattern.title_selector,
                     &pattern.date_selector,
                     &pattern.link_attr,
@@ -48,21 +77,193 @@ impl SelectorDetector {
 
     fn matches_pattern(&self, pattern: &CmsPattern, url: &str, html_lower: &str) -> bool {
         // Check URL pattern
-        if let Some(url_pattern) = &pattern.detect_url_contains {
-            if url.contains(url_pattern) {
-                return true;
-            }
+        if let Some(url_pattern) = &pattern.detect_url_contains
+            && url.contains(url_pattern)
+        {
+            return true;
         }
 
         // Check HTML pattern
-        if let Some(html_pattern) = &pattern.detect_html_contains {
-            if html_lower.contains(&html_pattern.to_lowercase()) {
-                return true;
-            }
+        if let Some(html_pattern) = &pattern.detect_html_contains
+            && html_lower.contains(&html_pattern.to_lowercase())
+        {
+            return true;
         }
 
         false
     }
+
+    /// Score repeated sibling structures on a board page and return ranked
+    /// selector candidates (best first).
+    pub fn score_candidates(&self, document: &Html) -> Vec<ScoredSelectors> {
+        let mut candidates: Vec<ScoredSelectors> = Vec::new();
+
+        for parent in document
+            .root_element()
+            .descendants()
+            .filter_map(ElementRef::wrap)
+        {
+            let mut groups: HashMap<String, Vec<ElementRef>> = HashMap::new();
+            for child in parent.children().filter_map(ElementRef::wrap) {
+                groups.entry(signature(&child)).or_default().push(child);
+            }
+
+            for (row_sig, rows) in groups {
+                if rows.len() < MIN_ROWS {
+                    continue;
+                }
+                if let Some(candidate) = self.score_group(&parent, &row_sig, &rows)
+                    && !candidates
+                        .iter()
+                        .any(|c| c.selectors.row_selector == candidate.selectors.row_selector)
+                {
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(b.matched_rows.cmp(&a.matched_rows))
+        });
+        candidates
+    }
+
+    /// Propose ranked `Board` candidates for a board page, for manual review.
+    pub fn propose_boards(&self, document: &Html, id: &str, name: &str, url: &str) -> Vec<Board> {
+        self.score_candidates(document)
+            .into_iter()
+            .map(|candidate| Board {
+                id: id.to_string(),
+                name: name.to_string(),
+                url: url.to_string(),
+                selectors: candidate.selectors,
+                ..Board::default()
+            })
+            .collect()
+    }
+
+    fn score_group(
+        &self,
+        parent: &ElementRef,
+        row_sig: &str,
+        rows: &[ElementRef],
+    ) -> Option<ScoredSelectors> {
+        let mut title_votes: HashMap<String, usize> = HashMap::new();
+        let mut date_votes: HashMap<String, usize> = HashMap::new();
+        let mut matched_rows = 0;
+
+        for row in rows {
+            let Some(anchor) = first_link(row) else {
+                continue;
+            };
+            let Some(date_cell) = self.find_date_cell(row) else {
+                continue;
+            };
+            matched_rows += 1;
+            *title_votes.entry(title_selector(row, &anchor)).or_default() += 1;
+            *date_votes.entry(cell_selector(&date_cell)).or_default() += 1;
+        }
+
+        if matched_rows < MIN_ROWS {
+            return None;
+        }
+
+        let coverage = matched_rows as f64 / rows.len() as f64;
+        let selectors = CmsSelectors {
+            row_selector: format!("{} > {row_sig}", container_selector(parent)).into(),
+            title_selector: top_vote(title_votes)?,
+            date_selector: top_vote(date_votes)?,
+            ..CmsSelectors::default()
+        };
+
+        Some(ScoredSelectors {
+            selectors,
+            score: matched_rows as f64 * coverage,
+            matched_rows,
+        })
+    }
+
+    /// Find the first leaf element in a row whose text looks like a date.
+    fn find_date_cell<'a>(&self, row: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+        row.descendants().filter_map(ElementRef::wrap).find(|el| {
+            if el.children().filter_map(ElementRef::wrap).next().is_some() {
+                return false;
+            }
+            let text: String = el.text().collect();
+            let text = text.trim();
+            text.chars().count() <= MAX_DATE_CELL_CHARS && self.date_pattern.is_match(text)
+        })
+    }
+}
+
+/// Tag name plus sorted class list, e.g. `li.item.notice`.
+fn signature(element: &ElementRef) -> String {
+    let mut classes: Vec<&str> = element.value().classes().collect();
+    classes.sort_unstable();
+    let mut sig = element.value().name().to_string();
+    for class in classes {
+        sig.push('.');
+        sig.push_str(class);
+    }
+    sig
+}
+
+/// Selector for a list container, anchored on its table when it is a `tbody`.
+fn container_selector(parent: &ElementRef) -> String {
+    let own = match parent.value().id() {
+        Some(id) => format!("{}#{id}", parent.value().name()),
+        None => signature(parent),
+    };
+    if parent.value().name() == "tbody"
+        && let Some(table) = parent.parent().and_then(ElementRef::wrap)
+    {
+        return format!("{} > {own}", container_selector(&table));
+    }
+    own
+}
+
+fn first_link<'a>(row: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+    row.descendants().filter_map(ElementRef::wrap).find(|el| {
+        el.value().name() == "a"
+            && (el.value().attr("href").is_some() || el.value().attr("onclick").is_some())
+    })
+}
+
+/// Title selector relative to the row: the anchor, scoped by its cell when
+/// the cell carries a class.
+fn title_selector(row: &ElementRef, anchor: &ElementRef) -> String {
+    let cell = anchor
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|el| el.parent().and_then(ElementRef::wrap).map(|p| p.id()) == Some(row.id()));
+    match cell {
+        Some(cell) if cell.value().classes().next().is_some() => {
+            format!("{} a", signature(&cell))
+        }
+        _ => "a".to_string(),
+    }
+}
+
+/// Selector for a date cell: classed elements by class, bare cells by position.
+fn cell_selector(cell: &ElementRef) -> String {
+    if cell.value().classes().next().is_some() {
+        return signature(cell);
+    }
+    let name = cell.value().name();
+    if name == "td" {
+        let position = cell.prev_siblings().filter_map(ElementRef::wrap).count() + 1;
+        return format!("td:nth-child({position})");
+    }
+    name.to_string()
+}
+
+fn top_vote(votes: HashMap<String, usize>) -> Option<String> {
+    votes
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(selector, _)| selector)
 }
 
 impl Default for SelectorDetector {
@@ -80,4 +281,55 @@ mod tests {
         let detector = SelectorDetector::default();
         assert!(!detector.patterns.is_empty());
     }
+
+    const TABLE_LAYOUT: &str = r#"
+        <table class="board_list">
+          <thead><tr><th>번호</th><th>제목</th><th>작성자</th><th>작성일</th></tr></thead>
+          <tbody>
+            <tr><td>3</td><td class="subject"><a href="view.do?no=3">수강신청 안내</a></td><td>학사팀</td><td>2024-03-02</td></tr>
+            <tr><td>2</td><td class="subject"><a href="view.do?no=2">장학금 신청</a></td><td>학사팀</td><td>2024-03-01</td></tr>
+            <tr><td>1</td><td class="subject"><a href="view.do?no=1">졸업 요건</a></td><td>학사팀</td><td>2024-02-28</td></tr>
+          </tbody>
+        </table>"#;
+
+    const LIST_LAYOUT: &str = r#"
+        <ul class="gnb">
+          <li><a href="/about">학과소개</a></li>
+          <li><a href="/notice">공지사항</a></li>
+          <li><a href="/contact">연락처</a></li>
+        </ul>
+        <ul class="board-list">
+          <li class="item"><a href="/notice/10">행사 안내</a><span class="date">2024.05.10</span></li>
+          <li class="item"><a href="/notice/9">채용 공고</a><span class="date">2024.05.08</span></li>
+          <li class="item"><a href="/notice/8">일반 공지</a><span class="date">2024.05.01</span></li>
+          <li class="item"><a href="/notice/7">학사 일정</a><span class="date">2024.04.29</span></li>
+        </ul>"#;
+
+    #[test]
+    fn test_score_candidates_table_layout() {
+        let detector = SelectorDetector::default();
+        let document = Html::parse_document(TABLE_LAYOUT);
+        let candidates = detector.score_candidates(&document);
+
+        let best = &candidates[0].selectors;
+        assert_eq!(best.row_selector, "table.board_list > tbody > tr");
+        assert_eq!(best.title_selector, "td.subject a");
+        assert_eq!(best.date_selector, "td:nth-child(4)");
+        let rows = scraper::Selector::parse(&best.row_selector.to_string()).unwrap();
+        assert_eq!(document.select(&rows).count(), 3);
+    }
+
+    #[test]
+    fn test_propose_boards_list_layout_ignores_nav() {
+        let detector = SelectorDetector::default();
+        let document = Html::parse_document(LIST_LAYOUT);
+        let boards =
+            detector.propose_boards(&document, "notice", "공지사항", "https://example.com");
+
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].selectors.row_selector, "ul.board-list > li.item");
+        assert_eq!(boards[0].selectors.title_selector, "a");
+        assert_eq!(boards[0].selectors.date_selector, "span.date");
+        assert_eq!(boards[0].url, "https://example.com");
+    }
 }
@@ -0,0 +1,48 @@
+// src/services/stream.rs
+
+//! Streaming access to crawled notices.
+//!
+//! [`NoticeStream`] yields notices board by board as they are fetched, so a
+//! consumer of a very large crawl never holds more than the boards in flight.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{Stream, TryStreamExt};
+
+use crate::error::Result;
+use crate::models::Notice;
+
+/// Notices of a crawl, in board completion order.
+///
+/// A board that fails yields a single `Err` and the stream carries on with
+/// the remaining boards. Created by
+/// [`NoticeCrawler::notice_stream`](crate::services::NoticeCrawler::notice_stream).
+pub struct NoticeStream<'a> {
+    inner: Pin<Box<dyn Stream<Item = Result<Notice>> + Send + 'a>>,
+}
+
+impl<'a> NoticeStream<'a> {
+    pub(crate) fn new(inner: impl Stream<Item = Result<Notice>> + Send + 'a) -> Self {
+        Self {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Collect every notice, stopping at the first board failure.
+    pub async fn collect_all(self) -> Result<Vec<Notice>> {
+        self.try_collect().await
+    }
+}
+
+impl Stream for NoticeStream<'_> {
+    type Item = Result<Notice>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
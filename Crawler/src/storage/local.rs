@@ -368,10 +368,10 @@ impl NoticeStorage for LocalStorage {
             match previous_items_map.get(id) {
                 None => added.push(id.clone()),
                 Some(Some(prev_hash)) => {
-                    if let Some(current_hash) = current_hashes.get(id) {
-                        if current_hash != prev_hash {
-                            updated.push(id.clone());
-                        }
+                    if let Some(current_hash) = current_hashes.get(id)
+                        && current_hash != prev_hash
+                    {
+                        updated.push(id.clone());
                     }
                 }
                 Some(None) => {}
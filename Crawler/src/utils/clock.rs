@@ -0,0 +1,57 @@
+// src/utils/clock.rs
+
+//! Injectable source of the current time.
+
+use std::fmt;
+
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+
+/// Source of "now", so time-dependent behavior can be pinned in tests.
+pub trait Clock: Send + Sync + fmt::Debug {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// The current time in the local timezone, without an offset.
+    fn local_now(&self) -> NaiveDateTime {
+        self.now().with_timezone(&Local).naive_local()
+    }
+}
+
+/// The system wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that always reports the same instant.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock_pins_now_and_local_now() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 0, 30, 0).unwrap();
+        let clock = FixedClock(instant);
+
+        assert_eq!(clock.now(), instant);
+        assert_eq!(clock.now(), clock.now());
+        assert_eq!(
+            clock.local_now(),
+            instant.with_timezone(&Local).naive_local()
+        );
+    }
+}
@@ -0,0 +1,184 @@
+// src/utils/date.rs
+
+//! Date parsing helpers.
+
+use std::sync::LazyLock;
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use regex::Regex;
+
+use crate::models::RelativeDateConfig;
+
+/// Resolve a relative date phrase ("오늘", "3일 전", "2 hours ago", ...) into `YYYY-MM-DD`.
+///
+/// Returns `None` when the text is not a recognized relative phrase or the
+/// offset falls outside the representable date range.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use crawler::models::RelativeDateConfig;
+/// use crawler::utils::date::resolve_relative_date;
+///
+/// let now = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+/// let config = RelativeDateConfig::default();
+/// assert_eq!(
+///     resolve_relative_date("어제", now, &config),
+///     Some("2024-02-29".to_string())
+/// );
+/// ```
+pub fn resolve_relative_date(
+    text: &str,
+    now: NaiveDateTime,
+    config: &RelativeDateConfig,
+) -> Option<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    if let Some(days) = config
+        .phrases
+        .iter()
+        .find(|(phrase, _)| phrase.eq_ignore_ascii_case(text))
+        .map(|(_, days)| *days)
+    {
+        return now
+            .checked_add_signed(TimeDelta::try_days(days)?)
+            .map(format_date);
+    }
+
+    let caps = RELATIVE_PATTERN.captures(text)?;
+    let amount: i64 = caps[1].parse().ok()?;
+    let unit = caps[2].to_lowercase();
+    let suffix = caps[3].to_lowercase();
+
+    if !config
+        .ago_suffixes
+        .iter()
+        .any(|s| s.to_lowercase() == suffix)
+    {
+        return None;
+    }
+
+    let matches = |units: &[String]| units.iter().any(|u| u.to_lowercase() == unit);
+    let offset = if matches(&config.day_units) {
+        TimeDelta::try_days(amount)?
+    } else if matches(&config.hour_units) {
+        TimeDelta::try_hours(amount)?
+    } else if matches(&config.minute_units) {
+        TimeDelta::try_minutes(amount)?
+    } else {
+        return None;
+    };
+
+    now.checked_sub_signed(offset).map(format_date)
+}
+
+/// `<amount> <unit> <suffix>`, e.g. `3일 전` or `2 hours ago`.
+static RELATIVE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d+)\s*(\S+?)\s*(\S+)$").expect("valid regex"));
+
+/// Year-month-day with `-`, `.` or `/` separators, optionally spaced.
+static DATE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\d{4}|\d{2})\s*[-./]\s*(\d{1,2})\s*[-./]\s*(\d{1,2})").expect("valid regex")
+});
+
+/// Eight digits standing alone, read as a compact `YYYYMMDD` date.
+static COMPACT_DATE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|\D)(\d{4})(\d{2})(\d{2})(?:\D|$)").expect("valid regex"));
+
+/// Parse the first calendar date found in a board's date cell.
+///
+/// Accepts `2024-03-02`, `2024.03.02`, `2024. 3. 2.`, `2024/03/02`,
+/// two-digit years (`24.03.02`, read as 20xx) and compact `20240302`.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use crawler::utils::date::parse_date;
+///
+/// assert_eq!(parse_date("2024. 3. 2."), NaiveDate::from_ymd_opt(2024, 3, 2));
+/// assert_eq!(parse_date("20240302"), NaiveDate::from_ymd_opt(2024, 3, 2));
+/// assert_eq!(parse_date("조회수 12"), None);
+/// ```
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    let caps = DATE_PATTERN
+        .captures(text)
+        .or_else(|| COMPACT_DATE_PATTERN.captures(text))?;
+    let mut year: i32 = caps[1].parse().ok()?;
+    if caps[1].len() == 2 {
+        year += 2000;
+    }
+    NaiveDate::from_ymd_opt(year, caps[2].parse().ok()?, caps[3].parse().ok()?)
+}
+
+fn format_date(value: NaiveDateTime) -> String {
+    value.format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn reference() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, 15)
+            .unwrap()
+            .and_hms_opt(1, 30, 0)
+            .unwrap()
+    }
+
+    fn resolve(text: &str) -> Option<String> {
+        resolve_relative_date(text, reference(), &RelativeDateConfig::default())
+    }
+
+    #[test]
+    fn test_resolve_korean_phrases() {
+        assert_eq!(resolve("오늘"), Some("2024-01-15".to_string()));
+        assert_eq!(resolve("어제"), Some("2024-01-14".to_string()));
+        assert_eq!(resolve("3일 전"), Some("2024-01-12".to_string()));
+        assert_eq!(resolve("2시간 전"), Some("2024-01-14".to_string()));
+        assert_eq!(resolve("10분 전"), Some("2024-01-15".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_english_phrases() {
+        assert_eq!(resolve("Today"), Some("2024-01-15".to_string()));
+        assert_eq!(resolve("yesterday"), Some("2024-01-14".to_string()));
+        assert_eq!(resolve("5 days ago"), Some("2024-01-10".to_string()));
+        assert_eq!(resolve("1 hour ago"), Some("2024-01-15".to_string()));
+    }
+
+    #[test]
+    fn test_parse_date_formats() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 2);
+        assert_eq!(parse_date("2024-03-02"), expected);
+        assert_eq!(parse_date("2024.03.02"), expected);
+        assert_eq!(parse_date("2024/3/2"), expected);
+        assert_eq!(parse_date("24.03.02"), expected);
+        assert_eq!(parse_date("2024-13-02"), None);
+        assert_eq!(parse_date("20240302"), expected);
+        assert_eq!(parse_date("등록 20240302"), expected);
+        assert_eq!(parse_date("1523"), None);
+        assert_eq!(parse_date("120240302"), None);
+        assert_eq!(parse_date("20241302"), None);
+    }
+
+    #[test]
+    fn test_unrecognized_text_is_not_resolved() {
+        assert_eq!(resolve("2024.01.01"), None);
+        assert_eq!(resolve("3 weeks ago"), None);
+        assert_eq!(resolve(""), None);
+    }
+
+    #[test]
+    fn test_out_of_range_offsets_are_not_resolved() {
+        assert_eq!(resolve("9223372036854775807일 전"), None);
+        assert_eq!(resolve("99999999999 days ago"), None);
+
+        let mut config = RelativeDateConfig::default();
+        config.phrases.insert("someday".to_string(), i64::MAX);
+        assert_eq!(resolve_relative_date("someday", reference(), &config), None);
+    }
+}
@@ -15,6 +15,25 @@ pub fn save_json<T: serde::Serialize>(path: &Path, data: &T) -> Result<()> {
     Ok(())
 }
 
+/// Append each item as one compact JSON line, creating the file if needed.
+pub fn append_json_lines<T: serde::Serialize>(path: &Path, items: &[T]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+
+    let mut lines = String::new();
+    for item in items {
+        lines.push_str(&serde_json::to_string(item)?);
+        lines.push('\n');
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(lines.as_bytes())?;
+    Ok(())
+}
+
 /// Load and parse TOML configuration from a file.
 pub fn load_toml<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
     let content = fs::read_to_string(path)?;
@@ -22,6 +41,15 @@ pub fn load_toml<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
     Ok(data)
 }
 
+/// Load and parse a JSON file, or `None` if it does not exist.
+pub fn load_json_optional<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    match fs::read(path) {
+        Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
 /// Ensure a directory exists, creating it if necessary.
 pub fn ensure_dir(path: &Path) -> Result<()> {
     create_dir_all(path)
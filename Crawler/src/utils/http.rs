@@ -2,16 +2,28 @@
 
 //! HTTP client utilities.
 
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use async_trait::async_trait;
+use bytes::Bytes;
 use reqwest::{StatusCode, header};
 use scraper::Html;
+use serde::Serialize;
 
 use crate::error::{AppError, Result};
-use crate::models::CrawlerConfig;
+use crate::models::{BasicAuth, CrawlerConfig, HttpMethod};
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::log;
 
 /// Create a configured asynchronous HTTP client.
 pub fn create_async_client(config: &CrawlerConfig) -> Result<reqwest::Client> {
+    Ok(client_builder(config)?.build()?)
+}
+
+/// Client builder with every crawler setting applied.
+fn client_builder(config: &CrawlerConfig) -> Result<reqwest::ClientBuilder> {
     let mut headers = header::HeaderMap::new();
     headers.insert(
         header::ACCEPT,
@@ -24,39 +36,383 @@ pub fn create_async_client(config: &CrawlerConfig) -> Result<reqwest::Client> {
         header::HeaderValue::from_static("ko-KR,ko;q=0.9,en-US;q=0.8,en;q=0.7"),
     );
 
-    let client = reqwest::Client::builder()
+    // reqwest sends `Accept-Encoding` for the enabled decoders and streams the
+    // body through them, so every size check below sees decompressed bytes.
+    let mut builder = reqwest::Client::builder()
         .user_agent(&config.user_agent)
         .default_headers(headers)
+        .gzip(config.accept_compression)
+        .brotli(config.accept_compression)
+        .deflate(config.accept_compression)
         .timeout(Duration::from_secs(config.timeout_secs))
-        .connect_timeout(Duration::from_secs(config.timeout_secs.min(10)))
-        .pool_idle_timeout(Duration::from_secs(60))
-        .tcp_keepalive(Duration::from_secs(30))
-        .redirect(reqwest::redirect::Policy::limited(5))
-        .build()?;
+        .connect_timeout(Duration::from_secs(
+            config.connect_timeout_secs.min(config.timeout_secs),
+        ))
+        .pool_idle_timeout(Duration::from_secs(config.pool_idle_timeout_secs))
+        .tcp_keepalive(
+            (config.tcp_keepalive_secs > 0).then(|| Duration::from_secs(config.tcp_keepalive_secs)),
+        )
+        .redirect(reqwest::redirect::Policy::limited(5));
+    if let Some(max_idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+    if config.danger_accept_invalid_certs {
+        log::warn(
+            "TLS certificate validation is DISABLED (crawler.danger_accept_invalid_certs); \
+             responses can be intercepted or forged",
+        );
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+    if let Some(version) = &config.min_tls_version {
+        builder = builder.min_tls_version(parse_tls_version(version)?);
+    }
 
-    Ok(client)
+    Ok(builder)
 }
 
-/// Fetch a page asynchronously and parse it as HTML.
-pub async fn fetch_page_async(client: &reqwest::Client, url: &str) -> Result<Html> {
-    let resp = client.get(url).send().await?;
+/// Parse a `crawler.min_tls_version` value.
+fn parse_tls_version(version: &str) -> Result<reqwest::tls::Version> {
+    use reqwest::tls::Version;
+    match version {
+        "1.0" => Ok(Version::TLS_1_0),
+        "1.1" => Ok(Version::TLS_1_1),
+        "1.2" => Ok(Version::TLS_1_2),
+        "1.3" => Ok(Version::TLS_1_3),
+        other => Err(AppError::config(format!(
+            "Unsupported min_tls_version '{other}'"
+        ))),
+    }
+}
 
-    // Process http response
-    let status = resp.status();
-    if status == StatusCode::NOT_MODIFIED {
-        return Err(AppError::UpstreamNotModified {
+/// An HTML response with the metadata useful for debugging a board.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HtmlResponse {
+    pub url: String,
+    pub status: u16,
+    /// Response headers (lowercase names; repeated headers joined by ", ")
+    pub headers: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub body: String,
+}
+
+/// Per-request settings beyond the URL, such as a board's credentials.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    pub basic_auth: Option<BasicAuth>,
+    pub method: HttpMethod,
+    /// URL-encoded form body (sent with POST)
+    pub form: Option<BTreeMap<String, String>>,
+    /// User-Agent replacing the client default for this request
+    pub user_agent: Option<String>,
+    /// Overrides `crawler.danger_accept_invalid_certs` for this request
+    pub accept_invalid_certs: Option<bool>,
+    /// `Some(false)` returns redirects as-is instead of following them
+    pub follow_redirects: Option<bool>,
+    /// Abort reading the body past this many bytes
+    pub max_body_bytes: Option<usize>,
+}
+
+impl RequestOptions {
+    /// Client variant these options need, or `None` for the shared client.
+    fn client_key(&self, config: &CrawlerConfig) -> Option<ClientKey> {
+        let key = ClientKey {
+            accept_invalid_certs: self
+                .accept_invalid_certs
+                .unwrap_or(config.danger_accept_invalid_certs),
+            follow_redirects: self.follow_redirects.unwrap_or(true),
+        };
+        let shared = ClientKey {
+            accept_invalid_certs: config.danger_accept_invalid_certs,
+            follow_redirects: true,
+        };
+        (key != shared).then_some(key)
+    }
+}
+
+/// Connection settings that need a client of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ClientKey {
+    accept_invalid_certs: bool,
+    follow_redirects: bool,
+}
+
+/// Source of HTML page bodies.
+///
+/// Abstracted so notice extraction can run against fixtures instead of the
+/// network.
+#[async_trait]
+pub trait HtmlFetcher: Send + Sync {
+    /// Fetch the raw HTML body at `url`.
+    async fn fetch_html(&self, url: &str) -> Result<String>;
+
+    /// Fetch the body at `url` as raw bytes, before any text decoding.
+    ///
+    /// The default re-encodes [`HtmlFetcher::fetch_html`] as UTF-8, which
+    /// suits fixture fetchers that already hold text.
+    async fn fetch_bytes(&self, url: &str) -> Result<Bytes> {
+        Ok(Bytes::from(self.fetch_html(url).await?))
+    }
+
+    /// Fetch a plain-text document such as `robots.txt`.
+    ///
+    /// The default defers to [`HtmlFetcher::fetch_html`].
+    async fn fetch_text(&self, url: &str) -> Result<String> {
+        self.fetch_html(url).await
+    }
+
+    /// Fetch `url` with status and headers.
+    ///
+    /// The default wraps [`HtmlFetcher::fetch_html`] with no headers.
+    async fn fetch_response(&self, url: &str) -> Result<HtmlResponse> {
+        Ok(HtmlResponse {
             url: url.to_string(),
+            status: 200,
+            headers: BTreeMap::new(),
+            body: self.fetch_html(url).await?,
+        })
+    }
+
+    /// Fetch `url` applying per-request `options`.
+    ///
+    /// The default ignores the options, which suits fixture fetchers.
+    async fn fetch_response_with(
+        &self,
+        url: &str,
+        _options: &RequestOptions,
+    ) -> Result<HtmlResponse> {
+        self.fetch_response(url).await
+    }
+
+    /// Fetch `url` applying per-request `options`, keeping the body as bytes.
+    ///
+    /// The default re-encodes [`HtmlFetcher::fetch_response_with`] as UTF-8.
+    async fn fetch_bytes_with(&self, url: &str, options: &RequestOptions) -> Result<RawResponse> {
+        let response = self.fetch_response_with(url, options).await?;
+        Ok(RawResponse {
+            url: response.url,
+            status: response.status,
+            headers: response.headers,
+            body: Bytes::from(response.body),
+        })
+    }
+}
+
+/// [`HtmlFetcher`] backed by a shared reqwest client.
+///
+/// The client pools connections internally, so create the fetcher once and
+/// share it (e.g. behind an `Arc`) across crawl cycles to keep the pool warm.
+///
+/// Requests whose [`RequestOptions`] override certificate checks or redirects
+/// go through a separate client per option combination, built on first use
+/// from the fetcher's [`CrawlerConfig`].
+#[derive(Debug, Clone)]
+pub struct ReqwestHtmlFetcher {
+    client: reqwest::Client,
+    config: CrawlerConfig,
+    /// Shared between clones, like the pooled client
+    variants: Arc<Mutex<HashMap<ClientKey, reqwest::Client>>>,
+    /// Resolves HTTP-date `Retry-After` values into a wait
+    clock: Arc<dyn Clock>,
+}
+
+impl ReqwestHtmlFetcher {
+    /// Wrap `client`, which should have been built from `config`; per-board
+    /// client variants and file URL handling follow `config`.
+    pub fn new(client: reqwest::Client, config: CrawlerConfig) -> Self {
+        Self {
+            client,
+            config,
+            variants: Arc::new(Mutex::new(HashMap::new())),
+            clock: Arc::new(SystemClock),
         }
-        .into());
     }
 
-    if !status.is_success() {
-        return Err(AppError::UpstreamHttp {
-            url: url.to_string(),
-            status: status.as_u16(),
+    /// Use `clock` instead of the system clock for `Retry-After` dates.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Build a fetcher with a client configured from the crawler settings.
+    pub fn from_config(config: &CrawlerConfig) -> Result<Self> {
+        Ok(Self::new(create_async_client(config)?, config.clone()))
+    }
+
+    /// The underlying pooled client.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Whether `url` should be read from disk; `file:` URLs are an error
+    /// unless `crawler.allow_file_urls` is set.
+    fn is_file_url(&self, url: &str) -> Result<bool> {
+        if !url.starts_with("file:") {
+            return Ok(false);
+        }
+        if !self.config.allow_file_urls {
+            return Err(AppError::crawl(
+                url,
+                "file:// URLs are disabled (set crawler.allow_file_urls)",
+            ));
+        }
+        Ok(true)
+    }
+
+    /// The client to send a request with `options` through.
+    fn client_for(&self, options: &RequestOptions) -> Result<reqwest::Client> {
+        let Some(key) = options.client_key(&self.config) else {
+            return Ok(self.client.clone());
+        };
+        let mut variants = self.variants.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(client) = variants.get(&key) {
+            return Ok(client.clone());
+        }
+        let config = CrawlerConfig {
+            danger_accept_invalid_certs: key.accept_invalid_certs,
+            ..self.config.clone()
+        };
+        let mut builder = client_builder(&config)?;
+        if !key.follow_redirects {
+            builder = builder.redirect(reqwest::redirect::Policy::none());
+        }
+        let client = builder.build()?;
+        variants.insert(key, client.clone());
+        Ok(client)
+    }
+}
+
+/// With `crawler.allow_file_urls`, `file://` URLs are read from disk so
+/// boards can point at saved HTML for offline runs and tests; otherwise they
+/// are refused.
+#[async_trait]
+impl HtmlFetcher for ReqwestHtmlFetcher {
+    async fn fetch_html(&self, url: &str) -> Result<String> {
+        Ok(self.fetch_response(url).await?.body)
+    }
+
+    async fn fetch_bytes(&self, url: &str) -> Result<Bytes> {
+        Ok(self
+            .fetch_bytes_with(url, &RequestOptions::default())
+            .await?
+            .body)
+    }
+
+    async fn fetch_text(&self, url: &str) -> Result<String> {
+        if self.is_file_url(url)? {
+            return Ok(decode_text(&fetch_file_bytes(url).await?));
+        }
+        let resp = check_response(self.client.get(url).send().await?, url, &*self.clock)?;
+        read_body(resp, url).await
+    }
+
+    async fn fetch_response(&self, url: &str) -> Result<HtmlResponse> {
+        self.fetch_response_with(url, &RequestOptions::default())
+            .await
+    }
+
+    async fn fetch_response_with(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+    ) -> Result<HtmlResponse> {
+        Ok(self.fetch_bytes_with(url, options).await?.into_text())
+    }
+
+    async fn fetch_bytes_with(&self, url: &str, options: &RequestOptions) -> Result<RawResponse> {
+        if self.is_file_url(url)? {
+            return fetch_file(url).await;
         }
-        .into());
+        let client = self.client_for(options)?;
+        if options.max_body_bytes.is_none() && self.config.max_body_bytes.is_some() {
+            let options = RequestOptions {
+                max_body_bytes: self.config.max_body_bytes,
+                ..options.clone()
+            };
+            return fetch_raw_with_async(&client, url, &options, &*self.clock).await;
+        }
+        fetch_raw_with_async(&client, url, options, &*self.clock).await
     }
+}
+
+/// Read a `file://` URL as a 200 response with no headers.
+async fn fetch_file(url: &str) -> Result<RawResponse> {
+    Ok(RawResponse {
+        url: url.to_string(),
+        status: 200,
+        headers: BTreeMap::new(),
+        body: fetch_file_bytes(url).await?.into(),
+    })
+}
+
+/// Read the file a `file://` URL points at.
+async fn fetch_file_bytes(url: &str) -> Result<Vec<u8>> {
+    let path = url::Url::parse(url)?
+        .to_file_path()
+        .map_err(|_| AppError::crawl(url, "not a local file path"))?;
+    Ok(tokio::fs::read(&path).await?)
+}
+
+/// Fetch a page asynchronously and parse it as HTML.
+pub async fn fetch_page_async(client: &reqwest::Client, url: &str) -> Result<Html> {
+    let text = fetch_html_async(client, url).await?;
+    Ok(Html::parse_document(&text))
+}
+
+/// Fetch a page asynchronously, rejecting non-HTML responses.
+pub async fn fetch_html_async(client: &reqwest::Client, url: &str) -> Result<String> {
+    Ok(fetch_response_async(client, url).await?.body)
+}
+
+/// Fetch a page with its status and headers, rejecting non-HTML responses.
+pub async fn fetch_response_async(client: &reqwest::Client, url: &str) -> Result<HtmlResponse> {
+    fetch_response_with_async(client, url, &RequestOptions::default()).await
+}
+
+/// Like [`fetch_response_async`], applying per-request `options`.
+pub async fn fetch_response_with_async(
+    client: &reqwest::Client,
+    url: &str,
+    options: &RequestOptions,
+) -> Result<HtmlResponse> {
+    Ok(fetch_raw_with_async(client, url, options, &SystemClock)
+        .await?
+        .into_text())
+}
+
+/// An HTML response whose body has been decompressed but not decoded.
+#[derive(Debug, Clone, Default)]
+pub struct RawResponse {
+    pub url: String,
+    pub status: u16,
+    /// Response headers (lowercase names; repeated headers joined by ", ")
+    pub headers: BTreeMap<String, String>,
+    pub body: Bytes,
+}
+
+impl RawResponse {
+    /// Decode the body with [`decode_text`].
+    pub fn into_text(self) -> HtmlResponse {
+        HtmlResponse {
+            body: decode_text(&self.body),
+            url: self.url,
+            status: self.status,
+            headers: self.headers,
+        }
+    }
+}
+
+/// Fetch an HTML page applying `options`, keeping the body as bytes.
+async fn fetch_raw_with_async(
+    client: &reqwest::Client,
+    url: &str,
+    options: &RequestOptions,
+    clock: &dyn Clock,
+) -> Result<RawResponse> {
+    let resp = check_response(
+        build_request(client, url, options).send().await?,
+        url,
+        clock,
+    )?;
 
     // Check Content-Type (prevent non-HTML responses)
     if let Some(ct) = resp.headers().get(header::CONTENT_TYPE) {
@@ -65,9 +421,176 @@ pub async fn fetch_page_async(client: &reqwest::Client, url: &str) -> Result<Htm
             return Err(AppError::UpstreamUnexpectedContentType {
                 url: url.to_string(),
                 content_type: ct.to_string(),
-            }
-            .into());
+            });
+        }
+    }
+
+    let status = resp.status().as_u16();
+    let mut headers = BTreeMap::<String, String>::new();
+    for (name, value) in resp.headers() {
+        let value = String::from_utf8_lossy(value.as_bytes());
+        headers
+            .entry(name.as_str().to_string())
+            .and_modify(|existing| {
+                existing.push_str(", ");
+                existing.push_str(&value);
+            })
+            .or_insert_with(|| value.into_owned());
+    }
+    Ok(RawResponse {
+        url: url.to_string(),
+        status,
+        headers,
+        body: read_bytes_capped(resp, url, options.max_body_bytes)
+            .await?
+            .into(),
+    })
+}
+
+/// Build a request for `url` with `options` applied.
+fn build_request(
+    client: &reqwest::Client,
+    url: &str,
+    options: &RequestOptions,
+) -> reqwest::RequestBuilder {
+    let mut request = match options.method {
+        HttpMethod::Get => client.get(url),
+        HttpMethod::Post => client.post(url),
+    };
+    if let Some(form) = &options.form {
+        request = request.form(form);
+    }
+    if let Some(auth) = &options.basic_auth {
+        request = request.basic_auth(&auth.username, Some(&auth.password));
+    }
+    if let Some(user_agent) = &options.user_agent {
+        request = request.header(header::USER_AGENT, user_agent);
+    }
+    request
+}
+
+/// Fetch a document as text regardless of content type (e.g. `sitemap.xml`).
+pub async fn fetch_text_async(client: &reqwest::Client, url: &str) -> Result<String> {
+    let resp = check_response(client.get(url).send().await?, url, &SystemClock)?;
+    read_body(resp, url).await
+}
+
+/// Read a (decompressed) response body and decode it to text.
+async fn read_body(resp: reqwest::Response, url: &str) -> Result<String> {
+    Ok(decode_text(&read_bytes(resp, url).await?))
+}
+
+/// Body cap for reads without a configured `max_body_bytes`.
+pub(crate) const DEFAULT_MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Read a (decompressed) response body, up to [`DEFAULT_MAX_BODY_BYTES`].
+pub(crate) async fn read_bytes(resp: reqwest::Response, url: &str) -> Result<Vec<u8>> {
+    read_bytes_capped(resp, url, None).await
+}
+
+/// Like [`read_bytes`], failing as soon as the body passes `max` bytes
+/// (default [`DEFAULT_MAX_BODY_BYTES`]).
+async fn read_bytes_capped(
+    mut resp: reqwest::Response,
+    url: &str,
+    max: Option<usize>,
+) -> Result<Vec<u8>> {
+    let too_large = |bytes: usize, max: usize| AppError::UpstreamBodyTooLarge {
+        url: url.to_string(),
+        bytes: bytes as u64,
+        max_bytes: max as u64,
+    };
+    let max = max.unwrap_or(DEFAULT_MAX_BODY_BYTES);
+    // Only set for identity responses; decoded ones lose their Content-Length
+    if let Some(len) = resp.content_length()
+        && len > max as u64
+    {
+        return Err(too_large(len as usize, max));
+    }
+    // Chunks arrive already decompressed, so this bounds the decoded size
+    let mut body = Vec::new();
+    while let Some(chunk) = resp.chunk().await? {
+        if body.len() + chunk.len() > max {
+            return Err(too_large(body.len() + chunk.len(), max));
         }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Decode a (decompressed) body to text.
+///
+/// A byte-order mark selects UTF-8, UTF-16LE or UTF-16BE and is dropped, so
+/// it cannot end up as a stray `\u{FEFF}` in the first extracted field.
+/// Without one the body is read as UTF-8.
+pub(crate) fn decode_text(body: &[u8]) -> String {
+    if let Some(rest) = body.strip_prefix(b"\xEF\xBB\xBF") {
+        String::from_utf8_lossy(rest).into_owned()
+    } else if let Some(rest) = body.strip_prefix(b"\xFF\xFE") {
+        decode_utf16(rest, u16::from_le_bytes)
+    } else if let Some(rest) = body.strip_prefix(b"\xFE\xFF") {
+        decode_utf16(rest, u16::from_be_bytes)
+    } else {
+        String::from_utf8_lossy(body).into_owned()
+    }
+}
+
+/// Decode UTF-16 code units read with `unit`; a trailing odd byte is dropped.
+fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> String {
+    let units = bytes.chunks_exact(2).map(|pair| unit([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Parse a `Retry-After` value: delay seconds or an HTTP date.
+///
+/// Dates in the past yield a zero wait.
+pub fn parse_retry_after(value: &str, now: chrono::DateTime<chrono::Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (at.with_timezone(&chrono::Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Reject non-success statuses and oversized bodies; an HTTP-date
+/// `Retry-After` on a 429 is measured from `clock`.
+pub(crate) fn check_response(
+    resp: reqwest::Response,
+    url: &str,
+    clock: &dyn Clock,
+) -> Result<reqwest::Response> {
+    // Process http response
+    let status = resp.status();
+    if status == StatusCode::NOT_MODIFIED {
+        return Err(AppError::UpstreamNotModified {
+            url: url.to_string(),
+        });
+    }
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = resp
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, clock.now()));
+        return Err(AppError::UpstreamRateLimited {
+            url: url.to_string(),
+            retry_after,
+        });
+    }
+
+    if !status.is_success() {
+        return Err(AppError::UpstreamHttp {
+            url: url.to_string(),
+            status: status.as_u16(),
+        });
     }
 
     // Size limit (operational stability) - consider moving to config if needed
@@ -80,11 +603,457 @@ pub async fn fetch_page_async(client: &reqwest::Client, url: &str) -> Result<Htm
                 url: url.to_string(),
                 bytes: len,
                 max_bytes: max,
+            });
+        }
+    }
+
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Serve a fixed keep-alive HTML response, counting accepted connections.
+    async fn serve_html(body: &'static str) -> (String, Arc<AtomicUsize>) {
+        serve(body.as_bytes().to_vec(), None).await
+    }
+
+    /// Serve `body` with an optional `Content-Encoding` on every request.
+    async fn serve(
+        body: Vec<u8>,
+        content_encoding: Option<&'static str>,
+    ) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+        let mut response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n",
+            body.len()
+        );
+        if let Some(encoding) = content_encoding {
+            response.push_str(&format!("Content-Encoding: {encoding}\r\n"));
+        }
+        response.push_str("\r\n");
+        let response: Arc<[u8]> = [response.into_bytes(), body].concat().into();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = Arc::clone(&response);
+                tokio::spawn(async move {
+                    let mut buf = vec![0u8; 4096];
+                    let mut request = Vec::new();
+                    loop {
+                        let Ok(n) = socket.read(&mut buf).await else {
+                            return;
+                        };
+                        if n == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                        while let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                            request.drain(..end + 4);
+                            if socket.write_all(&response).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                });
             }
-            .into());
+        });
+
+        (format!("http://{addr}/"), connections)
+    }
+
+    #[tokio::test]
+    async fn shared_fetcher_reuses_pooled_connection_across_cycles() {
+        let (url, connections) = serve_html("<p>ok</p>").await;
+        let fetcher: Arc<dyn HtmlFetcher> =
+            Arc::new(ReqwestHtmlFetcher::from_config(&CrawlerConfig::default()).unwrap());
+
+        for _cycle in 0..2 {
+            let cycle_fetcher = Arc::clone(&fetcher);
+            assert_eq!(cycle_fetcher.fetch_html(&url).await.unwrap(), "<p>ok</p>");
         }
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
     }
 
-    let text = resp.text().await?;
-    Ok(Html::parse_document(&text))
+    #[tokio::test]
+    async fn pool_max_idle_zero_disables_reuse() {
+        let (url, connections) = serve_html("<p>ok</p>").await;
+        let config = CrawlerConfig {
+            pool_max_idle_per_host: Some(0),
+            ..CrawlerConfig::default()
+        };
+        let fetcher = ReqwestHtmlFetcher::from_config(&config).unwrap();
+
+        fetcher.fetch_html(&url).await.unwrap();
+        fetcher.fetch_html(&url).await.unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn gzip_body_is_transparently_decompressed() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let html = "<table><tr><td>공지사항</td></tr></table>".repeat(50);
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(html.as_bytes()).unwrap();
+        let (url, _) = serve(encoder.finish().unwrap(), Some("gzip")).await;
+
+        let fetcher = ReqwestHtmlFetcher::from_config(&CrawlerConfig::default()).unwrap();
+        assert_eq!(fetcher.fetch_html(&url).await.unwrap(), html);
+    }
+
+    #[test]
+    fn client_builds_with_tls_options() {
+        let config = CrawlerConfig {
+            danger_accept_invalid_certs: true,
+            min_tls_version: Some("1.2".to_string()),
+            ..CrawlerConfig::default()
+        };
+        assert!(create_async_client(&config).is_ok());
+
+        let config = CrawlerConfig {
+            min_tls_version: Some("2.0".to_string()),
+            ..CrawlerConfig::default()
+        };
+        assert!(matches!(
+            create_async_client(&config),
+            Err(AppError::Config(_))
+        ));
+    }
+
+    const BOM_HTML: &str = "<td>공지 안내</td>";
+
+    #[test]
+    fn decode_text_strips_utf8_bom() {
+        let body = [b"\xEF\xBB\xBF".as_slice(), BOM_HTML.as_bytes()].concat();
+        assert_eq!(decode_text(&body), BOM_HTML);
+    }
+
+    #[test]
+    fn decode_text_reads_utf16le_bom() {
+        let mut body = vec![0xFF, 0xFE];
+        body.extend(BOM_HTML.encode_utf16().flat_map(u16::to_le_bytes));
+        assert_eq!(decode_text(&body), BOM_HTML);
+    }
+
+    #[test]
+    fn decode_text_reads_utf16be_bom() {
+        let mut body = vec![0xFE, 0xFF];
+        body.extend(BOM_HTML.encode_utf16().flat_map(u16::to_be_bytes));
+        assert_eq!(decode_text(&body), BOM_HTML);
+    }
+
+    #[tokio::test]
+    async fn unreachable_host_fails_within_connect_timeout() {
+        let config = CrawlerConfig {
+            timeout_secs: 30,
+            connect_timeout_secs: 1,
+            ..CrawlerConfig::default()
+        };
+        let fetcher = ReqwestHtmlFetcher::from_config(&config).unwrap();
+
+        // Non-routable: the SYN is never answered
+        let started = std::time::Instant::now();
+        let result = fetcher.fetch_html("http://10.255.255.1/").await;
+
+        assert!(result.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn redirect_override_uses_separate_client() {
+        let (target, _) = serve_html("<p>moved</p>").await;
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 302 Found\r\nLocation: {target}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        );
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        let fetcher = ReqwestHtmlFetcher::from_config(&CrawlerConfig::default()).unwrap();
+
+        let followed = fetcher
+            .fetch_response_with(&url, &RequestOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(followed.body, "<p>moved</p>");
+        assert!(fetcher.variants.lock().unwrap().is_empty());
+
+        let no_redirect = RequestOptions {
+            follow_redirects: Some(false),
+            ..RequestOptions::default()
+        };
+        for _ in 0..2 {
+            assert!(
+                fetcher
+                    .fetch_response_with(&url, &no_redirect)
+                    .await
+                    .is_err()
+            );
+        }
+        let variants = fetcher.variants.lock().unwrap();
+        assert_eq!(variants.len(), 1);
+        assert!(variants.contains_key(&ClientKey {
+            accept_invalid_certs: false,
+            follow_redirects: false,
+        }));
+    }
+
+    #[tokio::test]
+    async fn fetch_bytes_preserves_exact_body() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        // A BOM plus EUC-KR bytes, both of which text decoding would alter
+        let body = b"\xEF\xBB\xBF<p>\xB0\xF8\xC1\xF6</p>".to_vec();
+        let (url, _) = serve(body.clone(), None).await;
+        let fetcher = ReqwestHtmlFetcher::from_config(&CrawlerConfig::default()).unwrap();
+
+        assert_eq!(fetcher.fetch_bytes(&url).await.unwrap(), body);
+        assert!(fetcher.fetch_html(&url).await.unwrap().starts_with("<p>"));
+
+        let mut compressed = GzEncoder::new(Vec::new(), Compression::default());
+        compressed.write_all(&body).unwrap();
+        let (url, _) = serve(compressed.finish().unwrap(), Some("gzip")).await;
+        assert_eq!(fetcher.fetch_bytes(&url).await.unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn file_urls_are_refused_unless_allowed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("list.html");
+        std::fs::write(&path, "<p>saved</p>").unwrap();
+        let url = url::Url::from_file_path(&path).unwrap().to_string();
+
+        let fetcher = ReqwestHtmlFetcher::from_config(&CrawlerConfig::default()).unwrap();
+        assert!(fetcher.fetch_html(&url).await.is_err());
+        assert!(fetcher.fetch_bytes(&url).await.is_err());
+
+        let config = CrawlerConfig {
+            allow_file_urls: true,
+            ..CrawlerConfig::default()
+        };
+        let fetcher = ReqwestHtmlFetcher::from_config(&config).unwrap();
+        assert_eq!(fetcher.fetch_html(&url).await.unwrap(), "<p>saved</p>");
+    }
+
+    #[tokio::test]
+    async fn decompressed_body_over_max_body_bytes_is_aborted() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        // ~1 KB on the wire, 1 MB once inflated
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&vec![b'x'; 1_000_000]).unwrap();
+        let (url, _) = serve(encoder.finish().unwrap(), Some("gzip")).await;
+        let config = CrawlerConfig {
+            max_body_bytes: Some(10_000),
+            ..CrawlerConfig::default()
+        };
+        let fetcher = ReqwestHtmlFetcher::from_config(&config).unwrap();
+
+        let err = fetcher.fetch_bytes(&url).await.unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::UpstreamBodyTooLarge {
+                max_bytes: 10_000,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn body_over_max_body_bytes_is_aborted() {
+        // No Content-Length, so the cap is enforced while streaming
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let head =
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nConnection: close\r\n\r\n";
+                let _ = socket.write_all(head.as_bytes()).await;
+                for _ in 0..64 {
+                    if socket.write_all(&[b'x'; 1024]).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        let config = CrawlerConfig {
+            max_body_bytes: Some(10_000),
+            ..CrawlerConfig::default()
+        };
+        let fetcher = ReqwestHtmlFetcher::from_config(&config).unwrap();
+
+        let err = fetcher.fetch_response(&url).await.unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::UpstreamBodyTooLarge {
+                max_bytes: 10_000,
+                ..
+            }
+        ));
+
+        let (small, _) = serve_html("<p>ok</p>").await;
+        assert_eq!(fetcher.fetch_html(&small).await.unwrap(), "<p>ok</p>");
+    }
+
+    #[tokio::test]
+    async fn fetch_response_exposes_headers() {
+        let (url, _) = serve_html("<p>ok</p>").await;
+        let fetcher = ReqwestHtmlFetcher::from_config(&CrawlerConfig::default()).unwrap();
+
+        let response = fetcher.fetch_response(&url).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.headers["content-type"], "text/html");
+        assert_eq!(response.body, "<p>ok</p>");
+    }
+
+    #[test]
+    fn basic_auth_sets_authorization_header() {
+        let client = reqwest::Client::new();
+        let options = RequestOptions {
+            basic_auth: Some(BasicAuth {
+                username: "staff".to_string(),
+                password: "s3cret".to_string(),
+            }),
+            ..RequestOptions::default()
+        };
+
+        let request = build_request(&client, "https://intra.yonsei.ac.kr/board", &options)
+            .build()
+            .unwrap();
+        // base64("staff:s3cret")
+        assert_eq!(
+            request.headers()[header::AUTHORIZATION],
+            "Basic c3RhZmY6czNjcmV0"
+        );
+        assert!(!format!("{options:?}").contains("s3cret"));
+
+        let request = build_request(
+            &client,
+            "https://intra.yonsei.ac.kr/board",
+            &Default::default(),
+        )
+        .build()
+        .unwrap();
+        assert!(request.headers().get(header::AUTHORIZATION).is_none());
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_date() {
+        let now = chrono::DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[tokio::test]
+    async fn rate_limited_http_date_is_measured_from_fetcher_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let response = "HTTP/1.1 429 Too Many Requests\r\nRetry-After: Wed, 21 Oct 2015 07:28:45 GMT\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        let now = chrono::DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let fetcher = ReqwestHtmlFetcher::from_config(&CrawlerConfig::default())
+            .unwrap()
+            .with_clock(Arc::new(FixedClock(now)));
+
+        for result in [
+            fetcher.fetch_html(&url).await.map(drop),
+            fetcher.fetch_text(&url).await.map(drop),
+        ] {
+            match result {
+                Err(AppError::UpstreamRateLimited { retry_after, .. }) => {
+                    assert_eq!(retry_after, Some(Duration::from_secs(45)));
+                }
+                other => panic!("expected a rate-limit error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn user_agent_option_replaces_default() {
+        let client = reqwest::Client::new();
+        let options = RequestOptions {
+            user_agent: Some("Mozilla/5.0 (X11; Linux x86_64)".to_string()),
+            ..RequestOptions::default()
+        };
+
+        let request = build_request(&client, "https://dept.yonsei.ac.kr/list.do", &options)
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers()[header::USER_AGENT],
+            "Mozilla/5.0 (X11; Linux x86_64)"
+        );
+    }
+
+    #[test]
+    fn post_request_sends_form_body() {
+        let client = reqwest::Client::new();
+        let options = RequestOptions {
+            method: HttpMethod::Post,
+            form: Some(BTreeMap::from([
+                ("bbsId".to_string(), "notice".to_string()),
+                ("searchWrd".to_string(), "장학 금".to_string()),
+            ])),
+            ..RequestOptions::default()
+        };
+
+        let request = build_request(&client, "https://dept.yonsei.ac.kr/list.do", &options)
+            .build()
+            .unwrap();
+        assert_eq!(request.method(), reqwest::Method::POST);
+        assert_eq!(
+            request.headers()[header::CONTENT_TYPE],
+            "application/x-www-form-urlencoded"
+        );
+        let body = request.body().unwrap().as_bytes().unwrap();
+        assert_eq!(
+            std::str::from_utf8(body).unwrap(),
+            "bbsId=notice&searchWrd=%EC%9E%A5%ED%95%99+%EA%B8%88"
+        );
+    }
 }
@@ -0,0 +1,63 @@
+// src/utils/json_path.rs
+
+//! A small JSONPath-like lookup for board APIs that return JSON.
+//!
+//! Paths are dot-separated keys with optional array indices, optionally
+//! prefixed by `$`: `$.data.list`, `items[0].title`, `[2]`.
+
+use serde_json::Value;
+
+/// The value at `path` under `value`, if every step exists.
+pub fn select<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.trim();
+    let path = path.strip_prefix('$').unwrap_or(path);
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |current, segment| {
+            let (key, mut indices) = match segment.find('[') {
+                Some(pos) => segment.split_at(pos),
+                None => (segment, ""),
+            };
+            let mut current = if key.is_empty() {
+                current
+            } else {
+                current.get(key)?
+            };
+            while let Some(rest) = indices.strip_prefix('[') {
+                let (index, tail) = rest.split_once(']')?;
+                current = current.get(index.trim().parse::<usize>().ok()?)?;
+                indices = tail;
+            }
+            indices.is_empty().then_some(current)
+        })
+}
+
+/// Text of the value at `path`: strings as-is, numbers and booleans
+/// formatted; `None` for missing, null, array and object values.
+pub fn select_text(value: &Value, path: &str) -> Option<String> {
+    match select(value, path)? {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn select_follows_keys_and_indices() {
+        let value = json!({"data": {"list": [{"title": "a", "no": 7}, {"title": "b"}]}});
+        assert_eq!(select(&value, "$.data.list[1].title"), Some(&json!("b")));
+        assert_eq!(select(&value, "data.list[0]").unwrap()["no"], 7);
+        assert_eq!(select(&value, "$"), Some(&value));
+        assert_eq!(select_text(&value, "data.list[0].no").as_deref(), Some("7"));
+        assert_eq!(select(&value, "data.missing"), None);
+        assert_eq!(select(&value, "data.list[5]"), None);
+        assert_eq!(select(&value, "data.list[x]"), None);
+        assert_eq!(select_text(&value, "data.list"), None);
+    }
+}
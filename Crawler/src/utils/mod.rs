@@ -2,9 +2,15 @@
 
 //! Utility functions and helpers.
 
+pub mod clock;
+pub mod date;
 pub mod fs;
 pub mod http;
+pub mod json_path;
 pub mod log;
+pub mod progress;
+pub mod robots;
+pub mod sitemap;
 pub mod url;
 
 /// Resolve a potentially relative URL against a base URL.
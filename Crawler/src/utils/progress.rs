@@ -0,0 +1,120 @@
+// src/utils/progress.rs
+
+//! Completion counter with an ETA for long concurrent crawls.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of recent completion intervals averaged for the ETA.
+const ETA_WINDOW: usize = 20;
+
+/// Shared progress counter, safe to update from concurrent tasks.
+///
+/// Completions are counted under one lock, so the reported counts are
+/// strictly increasing even when tasks finish out of order.
+pub struct Progress {
+    total: usize,
+    state: Mutex<ProgressState>,
+}
+
+struct ProgressState {
+    completed: usize,
+    last_completion: Instant,
+    intervals: VecDeque<Duration>,
+}
+
+/// Progress at the moment one item finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressSnapshot {
+    pub completed: usize,
+    pub total: usize,
+    pub eta: Option<Duration>,
+}
+
+impl Progress {
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            state: Mutex::new(ProgressState {
+                completed: 0,
+                last_completion: Instant::now(),
+                intervals: VecDeque::with_capacity(ETA_WINDOW),
+            }),
+        }
+    }
+
+    /// Record one finished item.
+    pub fn complete(&self) -> ProgressSnapshot {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let interval = now - state.last_completion;
+        state.last_completion = now;
+        if state.intervals.len() == ETA_WINDOW {
+            state.intervals.pop_front();
+        }
+        state.intervals.push_back(interval);
+        state.completed = (state.completed + 1).min(self.total);
+
+        let remaining = self.total - state.completed;
+        ProgressSnapshot {
+            completed: state.completed,
+            total: self.total,
+            eta: estimate_eta(state.intervals.make_contiguous(), remaining),
+        }
+    }
+}
+
+/// Estimate the time left from the average of recent completion intervals.
+///
+/// Intervals between completions (rather than per-item durations) already
+/// reflect how many items run concurrently.
+pub fn estimate_eta(intervals: &[Duration], remaining: usize) -> Option<Duration> {
+    if intervals.is_empty() {
+        return None;
+    }
+    let average = intervals.iter().sum::<Duration>() / intervals.len() as u32;
+    Some(average * remaining as u32)
+}
+
+impl fmt::Display for ProgressSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}/{}]", self.completed, self.total)?;
+        match self.eta {
+            Some(eta) if self.completed < self.total => {
+                write!(f, " ETA ~{}s", eta.as_secs_f64().ceil() as u64)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_eta_from_fixed_intervals() {
+        let intervals = [
+            Duration::from_millis(1000),
+            Duration::from_millis(3000),
+            Duration::from_millis(2000),
+        ];
+        assert_eq!(estimate_eta(&intervals, 5), Some(Duration::from_secs(10)));
+        assert_eq!(estimate_eta(&intervals, 0), Some(Duration::ZERO));
+        assert_eq!(estimate_eta(&[], 5), None);
+    }
+
+    #[test]
+    fn test_snapshot_display() {
+        let mut snapshot = ProgressSnapshot {
+            completed: 3,
+            total: 10,
+            eta: Some(Duration::from_millis(11_200)),
+        };
+        assert_eq!(snapshot.to_string(), "[3/10] ETA ~12s");
+        snapshot.completed = 10;
+        assert_eq!(snapshot.to_string(), "[10/10]");
+    }
+}
@@ -0,0 +1,136 @@
+// src/utils/robots.rs
+
+//! `robots.txt` parsing.
+
+use std::time::Duration;
+
+/// URL of the `robots.txt` governing `url`, if it has an http(s) origin.
+pub fn robots_url(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    Some(format!(
+        "{}/robots.txt",
+        parsed.origin().ascii_serialization()
+    ))
+}
+
+/// The `Crawl-delay` that applies to `user_agent`.
+///
+/// A group whose `User-agent` token appears in `user_agent` wins over the
+/// `*` group; empty tokens match nothing. Fractional seconds are accepted;
+/// invalid values are ignored.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use crawler::utils::robots::parse_crawl_delay;
+///
+/// let robots = "User-agent: *\nCrawl-delay: 2\n";
+/// assert_eq!(parse_crawl_delay(robots, "uRing Crawler/0.1"), Some(Duration::from_secs(2)));
+/// ```
+pub fn parse_crawl_delay(robots: &str, user_agent: &str) -> Option<Duration> {
+    let user_agent = user_agent.to_lowercase();
+    let mut specific = None;
+    let mut wildcard = None;
+    // Agents of the current group; a non-agent line closes the agent list
+    let mut agents: Vec<String> = Vec::new();
+    let mut in_rules = false;
+
+    for line in robots.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (key.trim().to_lowercase(), value.trim());
+        match key.as_str() {
+            "user-agent" => {
+                if in_rules {
+                    agents.clear();
+                    in_rules = false;
+                }
+                if !value.is_empty() {
+                    agents.push(value.to_lowercase());
+                }
+            }
+            "crawl-delay" => {
+                in_rules = true;
+                let Some(delay) = value
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                else {
+                    continue;
+                };
+                for agent in &agents {
+                    if agent == "*" {
+                        wildcard.get_or_insert(delay);
+                    } else if user_agent.contains(agent.as_str()) {
+                        specific.get_or_insert(delay);
+                    }
+                }
+            }
+            _ => in_rules = true,
+        }
+    }
+
+    specific.or(wildcard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specific_agent_group_wins_over_wildcard() {
+        let robots = "\
+User-agent: *
+Disallow: /admin
+Crawl-delay: 1
+
+User-agent: BadBot
+Crawl-delay: 60
+
+User-agent: uRing
+Crawl-delay: 2.5
+";
+        assert_eq!(
+            parse_crawl_delay(robots, "Mozilla/5.0 (compatible; uRing Crawler/0.1)"),
+            Some(Duration::from_millis(2500))
+        );
+        assert_eq!(
+            parse_crawl_delay(robots, "OtherBot/1.0"),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(parse_crawl_delay("User-agent: *\nDisallow:\n", "x"), None);
+    }
+
+    #[test]
+    fn empty_user_agent_matches_nothing() {
+        let robots = "\
+User-agent:
+Crawl-delay: 60
+
+User-agent: *
+Crawl-delay: 1
+";
+        assert_eq!(
+            parse_crawl_delay(robots, "uRing Crawler/0.1"),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            parse_crawl_delay("User-agent:\nCrawl-delay: 60\n", "x"),
+            None
+        );
+    }
+
+    #[test]
+    fn robots_url_uses_origin() {
+        assert_eq!(
+            robots_url("https://cs.yonsei.ac.kr:8443/board/list.do?page=2").as_deref(),
+            Some("https://cs.yonsei.ac.kr:8443/robots.txt")
+        );
+        assert_eq!(robots_url("file:///tmp/list.html"), None);
+    }
+}
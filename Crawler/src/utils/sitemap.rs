@@ -0,0 +1,146 @@
+// src/utils/sitemap.rs
+
+//! XML sitemap parsing.
+//!
+//! Handles plain `<urlset>` sitemaps, `<sitemapindex>` files (whose `<loc>`
+//! entries are child sitemaps), namespace-prefixed tags and gzipped
+//! `.xml.gz` files.
+
+use std::io::Read;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::Result;
+use crate::utils::clock::SystemClock;
+use crate::utils::http::{DEFAULT_MAX_BODY_BYTES, check_response, decode_text, read_bytes};
+
+/// `<loc>` elements, optionally namespace-prefixed (`<sm:loc>`) and CDATA-wrapped.
+static LOC_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?s)<(?:[\w.-]+:)?loc\b[^>]*>\s*(?:<!\[CDATA\[)?\s*(.*?)\s*(?:\]\]>)?\s*</(?:[\w.-]+:)?loc>",
+    )
+    .unwrap()
+});
+
+/// Root element of a sitemap index.
+static INDEX_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<(?:[\w.-]+:)?sitemapindex\b").unwrap());
+
+/// Gzip magic bytes.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Extract every `<loc>` URL from a sitemap, in document order.
+///
+/// For a sitemap index these are the child sitemap URLs; see
+/// [`is_sitemap_index`] to tell the two apart.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::sitemap::parse_sitemap;
+///
+/// let xml = r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+///   <url><loc>https://cs.yonsei.ac.kr/?a=1&amp;b=2</loc></url>
+/// </urlset>"#;
+/// assert_eq!(parse_sitemap(xml), vec!["https://cs.yonsei.ac.kr/?a=1&b=2"]);
+/// ```
+pub fn parse_sitemap(xml: &str) -> Vec<String> {
+    LOC_PATTERN
+        .captures_iter(xml)
+        .map(|caps| unescape(&caps[1]))
+        .filter(|loc| !loc.is_empty())
+        .collect()
+}
+
+/// Whether `xml` is a sitemap index (a list of sitemaps, not pages).
+pub fn is_sitemap_index(xml: &str) -> bool {
+    INDEX_PATTERN.is_match(xml)
+}
+
+/// Decode a sitemap body, gunzipping `.xml.gz` content.
+pub fn decode_sitemap(bytes: &[u8]) -> Result<String> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut out = Vec::new();
+        flate2::read::GzDecoder::new(bytes)
+            .take(DEFAULT_MAX_BODY_BYTES as u64 + 1)
+            .read_to_end(&mut out)?;
+        if out.len() > DEFAULT_MAX_BODY_BYTES {
+            return Err(std::io::Error::other(format!(
+                "gunzipped sitemap exceeds {DEFAULT_MAX_BODY_BYTES} bytes"
+            ))
+            .into());
+        }
+        return Ok(decode_text(&out));
+    }
+    Ok(decode_text(bytes))
+}
+
+/// Fetch a sitemap (or HTML index page) as text, gunzipping it if needed.
+pub async fn fetch_sitemap_async(client: &reqwest::Client, url: &str) -> Result<String> {
+    let resp = check_response(client.get(url).send().await?, url, &SystemClock)?;
+    decode_sitemap(&read_bytes(resp, url).await?)
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_sitemap() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+              <url><loc>https://cs.yonsei.ac.kr/</loc><lastmod>2024-03-01</lastmod></url>
+              <url><loc> https://math.yonsei.ac.kr/index.do </loc></url>
+              <url><loc><![CDATA[https://cs.yonsei.ac.kr/list.do?id=1&page=2]]></loc></url>
+            </urlset>"#;
+
+        assert!(!is_sitemap_index(xml));
+        assert_eq!(
+            parse_sitemap(xml),
+            vec![
+                "https://cs.yonsei.ac.kr/",
+                "https://math.yonsei.ac.kr/index.do",
+                "https://cs.yonsei.ac.kr/list.do?id=1&page=2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_namespaced_sitemap_index() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <sm:sitemapindex xmlns:sm="http://www.sitemaps.org/schemas/sitemap/0.9">
+              <sm:sitemap><sm:loc>https://www.yonsei.ac.kr/sitemap-1.xml</sm:loc></sm:sitemap>
+              <sm:sitemap><sm:loc>https://www.yonsei.ac.kr/sitemap-2.xml.gz</sm:loc></sm:sitemap>
+            </sm:sitemapindex>"#;
+
+        assert!(is_sitemap_index(xml));
+        assert_eq!(
+            parse_sitemap(xml),
+            vec![
+                "https://www.yonsei.ac.kr/sitemap-1.xml",
+                "https://www.yonsei.ac.kr/sitemap-2.xml.gz",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_gzipped_sitemap() {
+        let xml = "<urlset><url><loc>https://cs.yonsei.ac.kr/</loc></url></urlset>";
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(xml.as_bytes()).unwrap();
+        let gz = encoder.finish().unwrap();
+
+        assert_eq!(decode_sitemap(&gz).unwrap(), xml);
+        assert_eq!(decode_sitemap(xml.as_bytes()).unwrap(), xml);
+    }
+}
@@ -2,6 +2,10 @@
 
 //! URL manipulation utilities.
 
+use std::collections::HashMap;
+
+use crate::models::Board;
+
 /// Resolve a potentially relative URL against a base URL.
 ///
 /// # Examples
@@ -70,6 +74,368 @@ pub fn get_domain(url: &str) -> Option<String> {
     Some(domain.to_lowercase())
 }
 
+/// Group boards by the domain of their URL (see [`get_domain`]).
+///
+/// Shows how much of a crawl lands on each host, for tuning per-domain delays
+/// and concurrency. Boards with unparseable URLs are grouped under `""`.
+pub fn group_boards_by_domain(boards: &[Board]) -> HashMap<String, Vec<&Board>> {
+    let mut groups: HashMap<String, Vec<&Board>> = HashMap::new();
+    for board in boards {
+        groups
+            .entry(get_domain(&board.url).unwrap_or_default())
+            .or_default()
+            .push(board);
+    }
+    groups
+}
+
+/// Remove query parameters whose names match any of `patterns`.
+///
+/// Patterns are matched case-insensitively against the decoded name; a
+/// trailing `*` matches any suffix (e.g. `utm_*`). The remaining parameters
+/// are kept byte-for-byte, so legacy (e.g. EUC-KR) escapes survive.
+/// Unparseable URLs, and URLs with nothing to strip, are returned unchanged.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::url::strip_query_params;
+///
+/// assert_eq!(
+///     strip_query_params(
+///         "https://example.com/view?articleNo=1&utm_source=x",
+///         &["utm_*".to_string()]
+///     ),
+///     "https://example.com/view?articleNo=1"
+/// );
+/// ```
+pub fn strip_query_params(url: &str, patterns: &[String]) -> String {
+    if patterns.is_empty() {
+        return url.to_string();
+    }
+    if url::Url::parse(url).is_err() {
+        return url.to_string();
+    }
+    let (head, Some(query), fragment) = split_query(url) else {
+        return url.to_string();
+    };
+
+    let segments: Vec<&str> = query.split('&').collect();
+    let kept: Vec<&str> = segments
+        .iter()
+        .copied()
+        .filter(|segment| {
+            segment_name(segment).is_none_or(|key| !patterns.iter().any(|p| param_matches(p, &key)))
+        })
+        .collect();
+    if kept.len() == segments.len() {
+        return url.to_string();
+    }
+
+    let mut stripped = head.to_string();
+    if !kept.is_empty() {
+        stripped.push('?');
+        stripped.push_str(&kept.join("&"));
+    }
+    stripped.push_str(fragment);
+    stripped
+}
+
+/// Split a URL into the part before `?`, the raw query (if any) and the
+/// `#fragment` (empty when absent).
+fn split_query(url: &str) -> (&str, Option<&str>, &str) {
+    let (rest, fragment) = url.split_at(url.find('#').unwrap_or(url.len()));
+    match rest.split_once('?') {
+        Some((head, query)) => (head, Some(query), fragment),
+        None => (rest, None, fragment),
+    }
+}
+
+/// The percent-decoded name of a raw `name=value` query segment.
+fn segment_name(segment: &str) -> Option<String> {
+    url::form_urlencoded::parse(segment.as_bytes())
+        .next()
+        .map(|(key, _)| key.into_owned())
+}
+
+/// Characters RFC 3986 allows unescaped in a path, query or fragment,
+/// besides ASCII alphanumerics.
+const URI_CHARS: &[u8] = b"-._~!$&'()*+,;=:@/?";
+
+/// Characters RFC 3986 allows unescaped in a registered host name or
+/// userinfo, besides ASCII alphanumerics.
+const AUTHORITY_CHARS: &[u8] = b"-._~!$&'()*+,;=";
+
+/// Escape `url` into a valid RFC 3986 URI.
+///
+/// The host is converted to Punycode; the path, query and fragment have
+/// non-ASCII bytes (as UTF-8), spaces and other disallowed characters
+/// percent-encoded. Existing `%XX` escapes are kept, never encoded twice.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::url::percent_encode_non_ascii;
+///
+/// assert_eq!(
+///     percent_encode_non_ascii("https://example.com/공지?q=%EA%B3%B5"),
+///     "https://example.com/%EA%B3%B5%EC%A7%80?q=%EA%B3%B5"
+/// );
+/// ```
+pub fn percent_encode_non_ascii(url: &str) -> String {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+
+    let mut encoded = String::with_capacity(url.len() * 3);
+    let path = match split_scheme(rest) {
+        Some((scheme, after)) => {
+            encoded.push_str(scheme);
+            encoded.push(':');
+            match after.strip_prefix("//") {
+                Some(after) => {
+                    let end = after.find('/').unwrap_or(after.len());
+                    encoded.push_str("//");
+                    encoded.push_str(&encode_authority(&after[..end]));
+                    &after[end..]
+                }
+                None => after,
+            }
+        }
+        None => rest,
+    };
+    encoded.push_str(&percent_encode(path, URI_CHARS));
+    if let Some(query) = query {
+        encoded.push('?');
+        encoded.push_str(&percent_encode(query, URI_CHARS));
+    }
+    if let Some(fragment) = fragment {
+        encoded.push('#');
+        encoded.push_str(&percent_encode(fragment, URI_CHARS));
+    }
+    encoded
+}
+
+/// Split `scheme:rest`, if `url` starts with a valid scheme.
+fn split_scheme(url: &str) -> Option<(&str, &str)> {
+    let (scheme, rest) = url.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some((scheme, rest))
+}
+
+/// Escape `[userinfo@]host[:port]`, converting the host to Punycode.
+fn encode_authority(authority: &str) -> String {
+    let (userinfo, host_port) = match authority.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo), host_port),
+        None => (None, authority),
+    };
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port))
+            if !host_port.ends_with(']') && port.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            (host, Some(port))
+        }
+        _ => (host_port, None),
+    };
+
+    let mut encoded = String::new();
+    if let Some(userinfo) = userinfo {
+        encoded.push_str(&percent_encode(userinfo, AUTHORITY_CHARS));
+        encoded.push('@');
+    }
+    match url::Host::parse(host) {
+        Ok(parsed) => encoded.push_str(&parsed.to_string()),
+        Err(_) => encoded.push_str(&percent_encode(host, AUTHORITY_CHARS)),
+    }
+    if let Some(port) = port {
+        encoded.push(':');
+        encoded.push_str(port);
+    }
+    encoded
+}
+
+/// Escape `text` for use as a single URL path segment (or query value):
+/// everything but RFC 3986 unreserved characters is percent-encoded, so `/`,
+/// `?`, `#` and braces in the value cannot change the URL's structure.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::url::encode_path_segment;
+///
+/// assert_eq!(encode_path_segment("신촌 a/b"), "%EC%8B%A0%EC%B4%8C%20a%2Fb");
+/// ```
+pub fn encode_path_segment(text: &str) -> String {
+    percent_encode(text, b"-._~")
+}
+
+/// Percent-encode every byte of `text` that is neither an ASCII
+/// alphanumeric nor in `allowed`, keeping existing `%XX` escapes.
+fn percent_encode(text: &str, allowed: &[u8]) -> String {
+    let bytes = text.as_bytes();
+    let mut encoded = String::with_capacity(text.len());
+    for (i, &byte) in bytes.iter().enumerate() {
+        let escape = byte == b'%'
+            && bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+        if escape || byte.is_ascii_alphanumeric() || allowed.contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Set (or replace) a query parameter, e.g. to address a board's Nth page.
+///
+/// Only the `name=value` segment is rewritten; the rest of the query is kept
+/// byte-for-byte. Returns the input unchanged if it is not an absolute URL.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::url::set_query_param;
+///
+/// assert_eq!(
+///     set_query_param("https://example.com/list.do?bbs=1&page=1", "page", "3"),
+///     "https://example.com/list.do?bbs=1&page=3"
+/// );
+/// ```
+pub fn set_query_param(url: &str, name: &str, value: &str) -> String {
+    if url::Url::parse(url).is_err() {
+        return url.to_string();
+    }
+    let (head, query, fragment) = split_query(url);
+    let param: String = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair(name, value)
+        .finish();
+
+    let mut segments: Vec<&str> = query
+        .filter(|query| !query.is_empty())
+        .map(|query| query.split('&').collect())
+        .unwrap_or_default();
+    match segments
+        .iter_mut()
+        .find(|segment| segment_name(segment).as_deref() == Some(name))
+    {
+        Some(segment) => *segment = &param,
+        None => segments.push(&param),
+    }
+    format!("{head}?{}{fragment}", segments.join("&"))
+}
+
+fn param_matches(pattern: &str, key: &str) -> bool {
+    let key = key.to_lowercase();
+    let pattern = pattern.to_lowercase();
+    match pattern.strip_suffix('*') {
+        Some(prefix) => key.starts_with(prefix),
+        None => key == pattern,
+    }
+}
+
+/// Check that a raw href resolved into a usable absolute http(s) link.
+///
+/// Empty or fragment-only hrefs (which resolve back to the page itself) and
+/// non-http schemes such as `javascript:` or `mailto:` are rejected.
+pub fn is_valid_link(raw_href: &str, resolved: &str) -> bool {
+    let raw = raw_href.trim();
+    if raw.is_empty() || raw.starts_with('#') {
+        return false;
+    }
+    match url::Url::parse(resolved) {
+        Ok(parsed) => matches!(parsed.scheme(), "http" | "https") && parsed.host_str().is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Like [`is_valid_link`], for boards served from `file://` URLs, whose
+/// links resolve to other local files.
+pub fn is_valid_file_link(raw_href: &str, resolved: &str) -> bool {
+    let raw = raw_href.trim();
+    if raw.is_empty() || raw.starts_with('#') {
+        return false;
+    }
+    url::Url::parse(resolved).is_ok_and(|parsed| parsed.scheme() == "file")
+}
+
+/// Normalize a URL for duplicate detection.
+///
+/// Drops the fragment and any trailing slash on the path; scheme and host are
+/// lowercased by parsing. Unparseable URLs are returned trimmed.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::url::normalize_for_dedup;
+///
+/// assert_eq!(
+///     normalize_for_dedup("https://Example.com/notice/#top"),
+///     normalize_for_dedup("https://example.com/notice")
+/// );
+/// ```
+pub fn normalize_for_dedup(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url.trim()) else {
+        return url.trim().to_string();
+    };
+    parsed.set_fragment(None);
+    let path = parsed.path().trim_end_matches('/').to_string();
+    parsed.set_path(&path);
+    parsed.to_string().trim_end_matches('/').to_string()
+}
+
+/// Canonicalize an http(s) URL: lowercase the host, drop the scheme's
+/// default port and collapse repeated slashes in the path.
+///
+/// The rest of the URL is kept verbatim (unlike re-serializing a parsed
+/// [`url::Url`], which would also percent-encode it). Other URLs are
+/// returned unchanged.
+///
+/// # Examples
+/// ```
+/// use crawler::utils::url::canonicalize_url;
+///
+/// assert_eq!(
+///     canonicalize_url("https://CS.Yonsei.ac.kr:443//board//list.do?a=1//2"),
+///     "https://cs.yonsei.ac.kr/board/list.do?a=1//2"
+/// );
+/// ```
+pub fn canonicalize_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let default_port = match scheme.to_ascii_lowercase().as_str() {
+        "http" => ":80",
+        "https" => ":443",
+        _ => return url.to_string(),
+    };
+    let (authority, tail) = rest.split_at(rest.find(['/', '?', '#']).unwrap_or(rest.len()));
+    let (userinfo, host) = match authority.rsplit_once('@') {
+        Some((userinfo, host)) => (Some(userinfo), host),
+        None => (None, authority),
+    };
+    let host = host.to_lowercase();
+    let host = host.strip_suffix(default_port).unwrap_or(&host);
+    let (path, suffix) = tail.split_at(tail.find(['?', '#']).unwrap_or(tail.len()));
+
+    let mut canonical = format!("{scheme}://");
+    if let Some(userinfo) = userinfo {
+        canonical.push_str(userinfo);
+        canonical.push('@');
+    }
+    canonical.push_str(host);
+    for c in path.chars() {
+        if !(c == '/' && canonical.ends_with('/')) {
+            canonical.push(c);
+        }
+    }
+    canonical.push_str(suffix);
+    canonical
+}
+
 /// Extract a stable notice identifier from a URL.
 pub fn extract_notice_id(url: &str) -> Option<String> {
     let parsed = url::Url::parse(url).ok()?;
@@ -121,7 +487,10 @@ pub fn extract_notice_id(url: &str) -> Option<String> {
         return Some(value);
     }
 
-    if let Some(last) = parsed.path_segments().and_then(|segments| segments.last()) {
+    if let Some(last) = parsed
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+    {
         let digits: String = last.chars().filter(|c| c.is_ascii_digit()).collect();
         if !digits.is_empty() {
             return Some(digits);
@@ -135,6 +504,94 @@ pub fn extract_notice_id(url: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_canonicalize_collapses_double_slashes() {
+        assert_eq!(
+            canonicalize_url("https://cs.yonsei.ac.kr//board///notice/"),
+            "https://cs.yonsei.ac.kr/board/notice/"
+        );
+        // Slashes in the query are data, not path separators
+        assert_eq!(
+            canonicalize_url("https://cs.yonsei.ac.kr/view.do?next=//x#a//b"),
+            "https://cs.yonsei.ac.kr/view.do?next=//x#a//b"
+        );
+        assert_eq!(
+            canonicalize_url("file:///tmp//list.html"),
+            "file:///tmp//list.html"
+        );
+    }
+
+    #[test]
+    fn test_percent_encode_non_ascii_keeps_escapes() {
+        assert_eq!(
+            percent_encode_non_ascii("https://cs.yonsei.ac.kr/게시판 1?t=%20공지#끝"),
+            "https://cs.yonsei.ac.kr/%EA%B2%8C%EC%8B%9C%ED%8C%90%201?t=%20%EA%B3%B5%EC%A7%80#%EB%81%9D"
+        );
+        let encoded = "https://cs.yonsei.ac.kr/%EA%B3%B5%EC%A7%80";
+        assert_eq!(percent_encode_non_ascii(encoded), encoded);
+    }
+
+    #[test]
+    fn test_percent_encode_non_ascii_is_valid_uri() {
+        assert_eq!(
+            percent_encode_non_ascii("http://연세.kr:8080/a|b?q=100%&r=\"x\"#c#d"),
+            "http://xn--vj4b99f.kr:8080/a%7Cb?q=100%25&r=%22x%22#c%23d"
+        );
+        assert_eq!(
+            percent_encode_non_ascii("/board/게시판 목록"),
+            "/board/%EA%B2%8C%EC%8B%9C%ED%8C%90%20%EB%AA%A9%EB%A1%9D"
+        );
+        let parsed = url::Url::parse(&percent_encode_non_ascii(
+            "https://학교.kr/공지 사항?제목=장학 금",
+        ))
+        .unwrap();
+        assert_eq!(parsed.path(), "/%EA%B3%B5%EC%A7%80%20%EC%82%AC%ED%95%AD");
+    }
+
+    #[test]
+    fn test_canonicalize_drops_default_port() {
+        assert_eq!(
+            canonicalize_url("https://CS.yonsei.ac.kr:443/board"),
+            "https://cs.yonsei.ac.kr/board"
+        );
+        assert_eq!(
+            canonicalize_url("http://cs.yonsei.ac.kr:80?page=2"),
+            "http://cs.yonsei.ac.kr?page=2"
+        );
+        assert_eq!(
+            canonicalize_url("http://cs.yonsei.ac.kr:443/board"),
+            "http://cs.yonsei.ac.kr:443/board"
+        );
+        assert_eq!(
+            canonicalize_url("https://user@cs.yonsei.ac.kr:8443/board"),
+            "https://user@cs.yonsei.ac.kr:8443/board"
+        );
+    }
+
+    #[test]
+    fn test_group_boards_by_domain() {
+        let board = |id: &str, url: &str| Board {
+            id: id.to_string(),
+            url: url.to_string(),
+            ..Board::default()
+        };
+        let boards = vec![
+            board("notice", "https://cs.yonsei.ac.kr/board/notice"),
+            board("jobs", "https://CS.yonsei.ac.kr/board/jobs"),
+            board("news", "https://math.yonsei.ac.kr/news"),
+        ];
+
+        let groups = group_boards_by_domain(&boards);
+
+        assert_eq!(groups.len(), 2);
+        let ids: Vec<_> = groups["cs.yonsei.ac.kr"]
+            .iter()
+            .map(|b| b.id.as_str())
+            .collect();
+        assert_eq!(ids, ["notice", "jobs"]);
+        assert_eq!(groups["math.yonsei.ac.kr"].len(), 1);
+    }
+
     #[test]
     fn test_resolve_absolute_url() {
         assert_eq!(
@@ -176,6 +633,89 @@ mod tests {
         assert_eq!(get_domain("invalid-url"), None);
     }
 
+    #[test]
+    fn test_strip_query_params_removes_tracking_only() {
+        let patterns = vec!["PHPSESSID".to_string(), "utm_*".to_string()];
+        assert_eq!(
+            strip_query_params(
+                "https://example.com/view.do?articleNo=42&phpsessid=abc&utm_source=mail&utm_medium=x",
+                &patterns
+            ),
+            "https://example.com/view.do?articleNo=42"
+        );
+        assert_eq!(
+            strip_query_params("https://example.com/list?utm_campaign=y", &patterns),
+            "https://example.com/list"
+        );
+        assert_eq!(
+            strip_query_params("https://example.com/view?seq=7&mode=view", &patterns),
+            "https://example.com/view?seq=7&mode=view"
+        );
+    }
+
+    #[test]
+    fn test_strip_query_params_keeps_other_segments_verbatim() {
+        let patterns = vec!["utm_*".to_string()];
+        assert_eq!(
+            strip_query_params(
+                "https://example.com/list.do?q=%B0%F8%C1%F6&utm_source=x&a=b%20c&flag#top",
+                &patterns
+            ),
+            "https://example.com/list.do?q=%B0%F8%C1%F6&a=b%20c&flag#top"
+        );
+        assert_eq!(
+            strip_query_params("https://example.com/list.do?q=%B0%F8&flag", &patterns),
+            "https://example.com/list.do?q=%B0%F8&flag"
+        );
+        assert_eq!(
+            strip_query_params("https://example.com/list.do?utm%5Fsource=x&a=1", &patterns),
+            "https://example.com/list.do?a=1"
+        );
+    }
+
+    #[test]
+    fn test_set_query_param_keeps_other_segments_verbatim() {
+        assert_eq!(
+            set_query_param(
+                "https://example.com/list.do?q=%B0%F8&page=1&flag#top",
+                "page",
+                "2"
+            ),
+            "https://example.com/list.do?q=%B0%F8&page=2&flag#top"
+        );
+        assert_eq!(
+            set_query_param("https://example.com/list.do?a=b%20c", "page", "2"),
+            "https://example.com/list.do?a=b%20c&page=2"
+        );
+        assert_eq!(
+            set_query_param("https://example.com/list.do", "page", "2"),
+            "https://example.com/list.do?page=2"
+        );
+    }
+
+    #[test]
+    fn test_is_valid_link() {
+        let base = ::url::Url::parse("https://example.com/board/list.do").unwrap();
+        let check = |href: &str| is_valid_link(href, &crate::utils::resolve_url(&base, href));
+
+        assert!(!check("javascript:void(0)"));
+        assert!(!check(""));
+        assert!(!check("mailto:office@example.com"));
+        assert!(check("view.do?articleNo=1"));
+    }
+
+    #[test]
+    fn test_is_valid_file_link() {
+        let base = ::url::Url::parse("file:///srv/fixtures/list.html").unwrap();
+        let check = |href: &str| is_valid_file_link(href, &crate::utils::resolve_url(&base, href));
+
+        assert!(check("articles/1.html"));
+        assert!(!check("#top"));
+        assert!(!check("https://example.com/view.do"));
+        // Local links are only accepted from local boards
+        assert!(!is_valid_link("file:///etc/passwd", "file:///etc/passwd"));
+    }
+
     #[test]
     fn test_extract_notice_id_query_key() {
         let url = "https://example.com/view?articleNo=1234&mode=view";
@@ -0,0 +1,224 @@
+// tests/mock_server.rs
+
+//! End-to-end crawls through the real `ReqwestHtmlFetcher` against a local
+//! HTTP server serving fixture pages.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crawler::error::{AppError, BoardErrorKind};
+use crawler::models::{Board, Campus, CmsSelectors, Config, Department};
+use crawler::services::NoticeCrawler;
+use crawler::utils::http::{HtmlFetcher, ReqwestHtmlFetcher};
+
+const BOARD_PAGE: &str = include_str!("fixtures/board.html");
+const DETAIL_PAGE: &str = include_str!("fixtures/detail.html");
+const BOARD_JSON: &str = include_str!("fixtures/board.json");
+
+/// How long `/slow/` requests wait before answering.
+const SLOW_DELAY: Duration = Duration::from_secs(5);
+
+/// Start the fixture server and return its base URL.
+///
+/// - `/board/list.do`: the board fixture, with an `X-Fixture` header
+/// - `/board/view.do?...`: the detail fixture
+/// - `/api/list.json`: the board list as a JSON API response
+/// - `/slow/...`: the board fixture after [`SLOW_DELAY`]
+/// - anything else: 404
+async fn start_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            tokio::spawn(handle(socket));
+        }
+    });
+    base
+}
+
+async fn handle(mut socket: TcpStream) {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        match socket.read(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => request.extend_from_slice(&buf[..n]),
+        }
+    }
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, body) = if path.starts_with("/board/list.do") {
+        ("200 OK", BOARD_PAGE)
+    } else if path.starts_with("/board/view.do") {
+        ("200 OK", DETAIL_PAGE)
+    } else if path.starts_with("/api/list.json") {
+        ("200 OK", BOARD_JSON)
+    } else if path.starts_with("/slow/") {
+        tokio::time::sleep(SLOW_DELAY).await;
+        ("200 OK", BOARD_PAGE)
+    } else {
+        ("404 Not Found", "<h1>Not Found</h1>")
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nX-Fixture: uring\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = socket.write_all(response.as_bytes()).await;
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.crawler.request_delay_ms = 0;
+    config.crawler.timeout_secs = 1;
+    config
+}
+
+fn board(id: &str, url: String) -> Board {
+    Board {
+        id: id.to_string(),
+        name: format!("{id} 공지"),
+        url,
+        selectors: CmsSelectors {
+            row_selector: "table.board_list tbody tr".into(),
+            title_selector: "td.subject a".to_string(),
+            date_selector: "td.date".to_string(),
+            body_selector: Some("div.content".to_string()),
+            ..CmsSelectors::default()
+        },
+        ..Board::default()
+    }
+}
+
+#[tokio::test]
+async fn fetcher_returns_fixture_with_status_and_headers() {
+    let base = start_server().await;
+    let fetcher = ReqwestHtmlFetcher::from_config(&config().crawler).unwrap();
+
+    let response = fetcher
+        .fetch_response(&format!("{base}/board/list.do"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.headers.get("x-fixture").map(String::as_str),
+        Some("uring")
+    );
+    assert!(response.body.contains("장학금 신청 안내"));
+}
+
+#[tokio::test]
+async fn fetcher_reports_404_status() {
+    let base = start_server().await;
+    let fetcher = ReqwestHtmlFetcher::from_config(&config().crawler).unwrap();
+
+    let err = fetcher
+        .fetch_html(&format!("{base}/missing/list.do"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, AppError::UpstreamHttp { status: 404, .. }));
+}
+
+#[tokio::test]
+async fn crawl_classifies_normal_missing_and_slow_boards() {
+    let base = start_server().await;
+    let config = config();
+    let fetcher = ReqwestHtmlFetcher::from_config(&config.crawler).unwrap();
+    let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+    let campus = Campus {
+        campus: "신촌캠퍼스".to_string(),
+        departments: vec![Department {
+            id: "fixture_dept".to_string(),
+            name: "테스트학과".to_string(),
+            url: base.clone(),
+            boards: vec![
+                board("normal", format!("{base}/board/list.do")),
+                board("missing", format!("{base}/missing/list.do")),
+                board("slow", format!("{base}/slow/list.do")),
+            ],
+        }],
+        ..Campus::default()
+    };
+
+    let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+    assert_eq!(outcome.board_total, 3);
+    assert_eq!(outcome.board_failures, 2);
+    assert_eq!(outcome.notices.len(), 2);
+    for notice in &outcome.notices {
+        assert_eq!(notice.board_id, "normal");
+        assert!(notice.link.starts_with(&format!("{base}/board/view.do")));
+        assert_eq!(notice.body, "<p>공지 본문입니다.</p>");
+    }
+
+    let kind = |board_id: &str| {
+        outcome
+            .errors
+            .iter()
+            .find(|error| error.board_id.as_deref() == Some(board_id))
+            .and_then(|error| error.kind)
+    };
+    assert_eq!(kind("missing"), Some(BoardErrorKind::Status(404)));
+    assert_eq!(kind("slow"), Some(BoardErrorKind::Timeout));
+}
+
+#[tokio::test]
+async fn crawl_extracts_notices_from_json_board() {
+    let base = start_server().await;
+    let config = config();
+    let fetcher = ReqwestHtmlFetcher::from_config(&config.crawler).unwrap();
+    let crawler = NoticeCrawler::with_fetcher(Arc::new(config), Arc::new(fetcher));
+    // As written in a site map: no row/title/date CSS selectors
+    let json_board: Board = serde_json::from_value(serde_json::json!({
+        "id": "api",
+        "name": "api 공지",
+        "url": format!("{base}/api/list.json"),
+        "format": "json",
+        "json_paths": {
+            "items_path": "$.data.list",
+            "title_path": "subject",
+            "date_path": "regDate",
+            "link_path": "articleNo",
+            "author_path": "writer",
+        },
+        "link_regex": r"^(\d+)$",
+        "link_template": "/board/view.do?articleNo={1}",
+        "body_selector": "div.content",
+    }))
+    .unwrap();
+    let campus = Campus {
+        campus: "신촌캠퍼스".to_string(),
+        departments: vec![Department {
+            id: "fixture_dept".to_string(),
+            name: "테스트학과".to_string(),
+            url: base.clone(),
+            boards: vec![json_board],
+        }],
+        ..Campus::default()
+    };
+
+    let outcome = crawler.fetch_all(&[campus]).await.unwrap();
+
+    assert_eq!(outcome.board_failures, 0);
+    let mut notices = outcome.notices;
+    notices.sort_by(|a, b| b.date.cmp(&a.date));
+    let fields: Vec<_> = notices
+        .iter()
+        .map(|n| (n.title.as_str(), n.date.as_str(), n.author.as_str()))
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            ("장학금 신청 안내", "2024-03-02", "학생지원팀"),
+            ("수강신청 일정", "2024-03-01", "학사지원팀"),
+        ]
+    );
+    assert_eq!(notices[0].link, format!("{base}/board/view.do?articleNo=2"));
+    // Detail pages are still HTML, read with body_selector
+    assert_eq!(notices[0].body, "<p>공지 본문입니다.</p>");
+}